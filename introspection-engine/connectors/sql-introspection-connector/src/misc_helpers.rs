@@ -80,6 +80,7 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
     let tpe = match index.tpe {
         IndexType::Unique => datamodel::dml::IndexType::Unique,
         IndexType::Normal => datamodel::dml::IndexType::Normal,
+        IndexType::Fulltext => datamodel::dml::IndexType::Fulltext,
     };
     let index_definition: IndexDefinition = IndexDefinition {
         name: Some(index.name.clone()),
@@ -300,6 +301,11 @@ pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldAri
             _ if is_sequence(column, table) => Some(DefaultValue::Expression(ValueGenerator::new_autoincrement())),
             false => parse_int(d).map(|x| DefaultValue::Single(ScalarValue::Int(x))),
         },
+        (Some(d), ColumnTypeFamily::BigInt) => match column.auto_increment {
+            true => Some(DefaultValue::Expression(ValueGenerator::new_autoincrement())),
+            _ if is_sequence(column, table) => Some(DefaultValue::Expression(ValueGenerator::new_autoincrement())),
+            false => parse_big_int(d).map(|x| DefaultValue::Single(ScalarValue::BigInt(x))),
+        },
         (Some(d), ColumnTypeFamily::Float) => parse_float(d).map(|x| DefaultValue::Single(ScalarValue::Float(x))),
         (Some(d), ColumnTypeFamily::String) => Some(DefaultValue::Single(ScalarValue::String(d.to_string()))),
         (Some(_), ColumnTypeFamily::DateTime) => None, //todo
@@ -396,7 +402,11 @@ pub(crate) fn calculate_field_type(schema: &SqlSchema, column: &Column, table: &
                 ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
                 ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
                 ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
+                ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt),
                 ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
+                ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json),
+                ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes),
+                ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::Uuid),
                 ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
                 // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                 // variants that don't yet have corresponding PrismaType variants
@@ -456,6 +466,26 @@ fn parse_int(value: &str) -> Option<i32> {
     }
 }
 
+fn parse_big_int(value: &str) -> Option<i64> {
+    debug!("Parsing big int '{}'", value);
+    let rslt = RE_NUM.captures(value);
+    if rslt.is_none() {
+        debug!("Couldn't parse big int");
+        return None;
+    }
+
+    let captures = rslt.expect("get captures");
+    let num_str = captures.get(1).expect("get capture").as_str();
+    let num_rslt = num_str.parse::<i64>();
+    match num_rslt {
+        Ok(num) => Some(num),
+        Err(_) => {
+            debug!("Couldn't parse big int '{}'", num_str);
+            None
+        }
+    }
+}
+
 fn parse_bool(value: &str) -> Option<bool> {
     debug!("Parsing bool '{}'", value);
     value.to_lowercase().parse().ok()
@@ -11,6 +11,7 @@ use sql_schema_describer::*;
 fn a_data_model_can_be_generated_from_a_schema() {
     let col_types = &[
         ColumnTypeFamily::Int,
+        ColumnTypeFamily::BigInt,
         ColumnTypeFamily::Float,
         ColumnTypeFamily::Boolean,
         ColumnTypeFamily::String,
@@ -45,7 +46,11 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime),
                         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float),
                         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int),
+                        ColumnTypeFamily::BigInt => FieldType::Base(ScalarType::BigInt),
                         ColumnTypeFamily::String => FieldType::Base(ScalarType::String),
+                        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes),
+                        ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json),
+                        ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::Uuid),
                         // XXX: We made a conscious decision to punt on mapping of ColumnTypeFamily
                         // variants that don't yet have corresponding PrismaType variants
                         _ => FieldType::Base(ScalarType::String),
@@ -338,7 +338,7 @@ async fn introspecting_default_values_should_work(api: &TestApi) {
                 numeric_float8      Float?          @default(123.1234)
                 numeric_int2        Int?            @default(2)
                 numeric_int4        Int?            @default(4)
-                numeric_int8        Int?            @default(8)
+                numeric_int8        BigInt?         @default(8)
                 string_char         String?         @default("abcdefgh")
                 string_text         String?         @default("abcdefgh")
                 string_varchar      String?         @default("abcd")
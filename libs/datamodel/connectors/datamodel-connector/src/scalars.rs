@@ -10,6 +10,10 @@ pub enum ScalarType {
     Boolean,
     String,
     DateTime,
+    Json,
+    Bytes,
+    BigInt,
+    Uuid,
 }
 
 impl ScalarType {
@@ -21,6 +25,10 @@ impl ScalarType {
             "Boolean" => Ok(ScalarType::Boolean),
             "String" => Ok(ScalarType::String),
             "DateTime" => Ok(ScalarType::DateTime),
+            "Json" => Ok(ScalarType::Json),
+            "Bytes" => Ok(ScalarType::Bytes),
+            "BigInt" => Ok(ScalarType::BigInt),
+            "Uuid" => Ok(ScalarType::Uuid),
             _ => Err(format!("type {} is not a known scalar type.", s)),
         }
     }
@@ -35,6 +43,10 @@ impl ToString for ScalarType {
             ScalarType::Boolean => String::from("Boolean"),
             ScalarType::String => String::from("String"),
             ScalarType::DateTime => String::from("DateTime"),
+            ScalarType::Json => String::from("Json"),
+            ScalarType::Bytes => String::from("Bytes"),
+            ScalarType::BigInt => String::from("BigInt"),
+            ScalarType::Uuid => String::from("Uuid"),
         }
     }
 }
@@ -43,11 +55,14 @@ impl ToString for ScalarType {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum ScalarValue {
     Int(i32),
+    BigInt(i64),
     Float(f32),
     Decimal(f32),
     Boolean(bool),
     String(String),
     DateTime(DateTime<Utc>),
+    /// A JSON document, serialized.
+    Json(String),
     ConstantLiteral(String),
 }
 
@@ -55,11 +70,13 @@ impl ScalarValue {
     pub fn get_type(&self) -> ScalarType {
         match self {
             ScalarValue::Int(_) => ScalarType::Int,
+            ScalarValue::BigInt(_) => ScalarType::BigInt,
             ScalarValue::Float(_) => ScalarType::Float,
             ScalarValue::Decimal(_) => ScalarType::Decimal,
             ScalarValue::Boolean(_) => ScalarType::Boolean,
             ScalarValue::String(_) => ScalarType::String,
             ScalarValue::DateTime(_) => ScalarType::DateTime,
+            ScalarValue::Json(_) => ScalarType::Json,
             ScalarValue::ConstantLiteral(_) => {
                 panic!("Constant literal values do not map to a base type and should never surface.")
             }
@@ -71,11 +88,13 @@ impl ToString for ScalarValue {
     fn to_string(&self) -> String {
         match self {
             ScalarValue::Int(val) => val.to_string(),
+            ScalarValue::BigInt(val) => val.to_string(),
             ScalarValue::Float(val) => val.to_string(),
             ScalarValue::Decimal(val) => val.to_string(),
             ScalarValue::Boolean(val) => val.to_string(),
             ScalarValue::String(val) => val.to_string(),
             ScalarValue::DateTime(val) => val.to_string(),
+            ScalarValue::Json(val) => val.to_string(),
             ScalarValue::ConstantLiteral(value) => value.to_string(),
         }
     }
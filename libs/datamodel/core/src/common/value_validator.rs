@@ -53,11 +53,25 @@ impl ValueValidator {
     pub fn as_type(&self, scalar_type: ScalarType) -> Result<dml::ScalarValue, DatamodelError> {
         match scalar_type {
             ScalarType::Int => self.as_int().map(dml::ScalarValue::Int),
+            ScalarType::BigInt => self.as_big_int().map(dml::ScalarValue::BigInt),
             ScalarType::Float => self.as_float().map(dml::ScalarValue::Float),
             ScalarType::Decimal => self.as_decimal().map(dml::ScalarValue::Decimal),
             ScalarType::Boolean => self.as_bool().map(dml::ScalarValue::Boolean),
             ScalarType::DateTime => self.as_date_time().map(dml::ScalarValue::DateTime),
             ScalarType::String => self.as_str().map(dml::ScalarValue::String),
+            ScalarType::Json => self.as_json().map(dml::ScalarValue::Json),
+            ScalarType::Bytes => Err(DatamodelError::new_value_parser_error(
+                "bytes",
+                "default values are not supported for Bytes fields",
+                &self.raw(),
+                self.span(),
+            )),
+            ScalarType::Uuid => Err(DatamodelError::new_value_parser_error(
+                "uuid",
+                "only the uuid() function is supported as a default value for Uuid fields",
+                &self.raw(),
+                self.span(),
+            )),
         }
     }
 
@@ -77,6 +91,14 @@ impl ValueValidator {
         self.value.span()
     }
 
+    /// Returns true if the wrapped value is a function call.
+    pub fn is_function(&self) -> bool {
+        match &self.value {
+            ast::Expression::Function(_, _, _) => true,
+            _ => false,
+        }
+    }
+
     /// Tries to convert the wrapped value to a Prisma String.
     pub fn as_str(&self) -> Result<String, DatamodelError> {
         self.as_str_from_env().map(|tuple| tuple.1)
@@ -114,6 +136,15 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to a Prisma BigInt.
+    pub fn as_big_int(&self) -> Result<i64, DatamodelError> {
+        match &self.value {
+            ast::Expression::NumericValue(value, _) => self.wrap_error_from_result(value.parse::<i64>(), "numeric"),
+            ast::Expression::Any(value, _) => self.wrap_error_from_result(value.parse::<i64>(), "numeric"),
+            _ => Err(self.construct_type_mismatch_error("numeric")),
+        }
+    }
+
     /// Tries to convert the wrapped value to a Prisma Float.
     pub fn as_float(&self) -> Result<f32, DatamodelError> {
         match &self.value {
@@ -178,6 +209,16 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to a Prisma Json, which is a string containing a valid JSON document.
+    pub fn as_json(&self) -> Result<String, DatamodelError> {
+        match &self.value {
+            ast::Expression::StringValue(value, _) | ast::Expression::Any(value, _) => self
+                .wrap_error_from_result(serde_json::from_str::<serde_json::Value>(value), "json")
+                .map(|_| value.clone()),
+            _ => Err(self.construct_type_mismatch_error("json")),
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_constant_literal(&self) -> Result<String, DatamodelError> {
         match &self.value {
@@ -26,9 +26,11 @@ impl DefaultValue {
             .map(|sv| match sv {
                 ScalarValue::Boolean(x) => PrismaValue::Boolean(x),
                 ScalarValue::Int(x) => PrismaValue::Int(i64::from(x)),
+                ScalarValue::BigInt(x) => PrismaValue::BigInt(x),
                 ScalarValue::Float(x) => x.try_into().expect("Can't convert float to decimal"),
                 ScalarValue::String(x) => PrismaValue::String(x.clone()),
                 ScalarValue::DateTime(x) => PrismaValue::DateTime(x),
+                ScalarValue::Json(x) => PrismaValue::Json(x),
                 ScalarValue::Decimal(x) => x.try_into().expect("Can't convert float to decimal"),
                 ScalarValue::ConstantLiteral(value) => PrismaValue::Enum(value.clone()),
             })
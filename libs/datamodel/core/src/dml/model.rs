@@ -43,6 +43,8 @@ impl IndexDefinition {
 pub enum IndexType {
     Unique,
     Normal,
+    /// A full-text index over string fields, required for full-text search on MySQL and SQLite.
+    Fulltext,
 }
 
 impl Model {
@@ -131,8 +131,11 @@ fn value_to_serde(value: &dml::ScalarValue) -> serde_json::Value {
         dml::ScalarValue::ConstantLiteral(name) => serde_json::Value::String(name.clone()),
         dml::ScalarValue::Float(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::ScalarValue::Int(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
+        // Serialized as a string, clients can't represent all 64 bit integers as numbers.
+        dml::ScalarValue::BigInt(val) => serde_json::Value::String(val.to_string()),
         dml::ScalarValue::Decimal(val) => serde_json::Value::Number(serde_json::Number::from_f64(*val as f64).unwrap()),
         dml::ScalarValue::DateTime(val) => serde_json::Value::String(val.to_rfc3339()),
+        dml::ScalarValue::Json(val) => serde_json::Value::String(val.clone()),
     }
 }
 
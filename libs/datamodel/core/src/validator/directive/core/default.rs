@@ -18,8 +18,17 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
         }
 
         if let dml::FieldType::Base(scalar_type) = field.field_type {
-            let dv = args
-                .default_arg("value")?
+            let value = args.default_arg("value")?;
+
+            // Uuid columns are only ever filled by the generator, literal values are rejected up front.
+            if scalar_type == dml::ScalarType::Uuid && !value.is_function() {
+                return self.new_directive_validation_error(
+                    "Only `uuid()` is supported as a default for Uuid fields.",
+                    value.span(),
+                );
+            }
+
+            let dv = value
                 .as_default_value(scalar_type)
                 .map_err(|e| self.wrap_in_directive_validation_error(&e))?;
 
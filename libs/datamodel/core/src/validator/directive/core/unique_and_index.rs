@@ -94,7 +94,58 @@ impl DirectiveValidator<dml::Model> for ModelLevelIndexDirectiveValidator {
     }
 }
 
-/// common logic for `@@unique` and `@@index`
+/// Prismas builtin `@@fulltext` directive.
+pub struct ModelLevelFulltextDirectiveValidator {}
+
+impl IndexDirectiveBase<dml::Model> for ModelLevelFulltextDirectiveValidator {}
+impl DirectiveValidator<dml::Model> for ModelLevelFulltextDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        "fulltext"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let index_def = self.validate_index(args, obj, IndexType::Fulltext)?;
+
+        let non_string_fields: Vec<&str> = index_def
+            .fields
+            .iter()
+            .filter(|field| match obj.find_field(&field).map(|f| &f.field_type) {
+                Some(dml::FieldType::Base(dml::ScalarType::String)) => false,
+                _ => true,
+            })
+            .map(|field| field.as_str())
+            .collect();
+
+        if !non_string_fields.is_empty() {
+            return Err(DatamodelError::new_model_validation_error(
+                &format!(
+                    "The fulltext index definition refers to the fields {}, but only String fields can be part of a fulltext index.",
+                    non_string_fields.join(", ")
+                ),
+                &obj.name,
+                args.span(),
+            ));
+        }
+
+        obj.indices.push(index_def);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        self.serialize_index_definitions(&model, IndexType::Fulltext)
+    }
+}
+
+/// common logic for `@@unique`, `@@index` and `@@fulltext`
 trait IndexDirectiveBase<T>: DirectiveValidator<T> {
     fn validate_index(
         &self,
@@ -137,7 +188,11 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             return Err(DatamodelError::new_model_validation_error(
                 &format!(
                     "The {}index definition refers to the unknown fields {}.",
-                    if index_type == IndexType::Unique { "unique " } else { "" },
+                    match index_type {
+                        IndexType::Unique => "unique ",
+                        IndexType::Fulltext => "fulltext ",
+                        IndexType::Normal => "",
+                    },
                     undefined_fields.join(", ")
                 ),
                 &obj.name,
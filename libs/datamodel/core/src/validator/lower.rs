@@ -137,9 +137,11 @@ impl LowerDmlToAst {
                 ast::Expression::ConstantValue(value.clone(), ast::Span::empty())
             }
             dml::ScalarValue::DateTime(value) => ast::Expression::ConstantValue(value.to_rfc3339(), ast::Span::empty()),
+            dml::ScalarValue::Json(value) => ast::Expression::StringValue(value.clone(), ast::Span::empty()),
             dml::ScalarValue::Decimal(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::ScalarValue::Float(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             dml::ScalarValue::Int(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+            dml::ScalarValue::BigInt(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
         }
     }
 
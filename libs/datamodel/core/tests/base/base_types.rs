@@ -10,9 +10,13 @@ fn parse_scalar_types() {
         id Int @id
         firstName String
         age Int
+        views BigInt
         isPro Boolean
         balance Decimal
         averageGrade Float
+        settings Json
+        avatar Bytes
+        externalId Uuid
     }
     "#;
 
@@ -22,6 +26,9 @@ fn parse_scalar_types() {
         .assert_has_field("firstName")
         .assert_base_type(&ScalarType::String);
     user_model.assert_has_field("age").assert_base_type(&ScalarType::Int);
+    user_model
+        .assert_has_field("views")
+        .assert_base_type(&ScalarType::BigInt);
     user_model
         .assert_has_field("isPro")
         .assert_base_type(&ScalarType::Boolean);
@@ -31,6 +38,15 @@ fn parse_scalar_types() {
     user_model
         .assert_has_field("averageGrade")
         .assert_base_type(&ScalarType::Float);
+    user_model
+        .assert_has_field("settings")
+        .assert_base_type(&ScalarType::Json);
+    user_model
+        .assert_has_field("avatar")
+        .assert_base_type(&ScalarType::Bytes);
+    user_model
+        .assert_has_field("externalId")
+        .assert_base_type(&ScalarType::Uuid);
 }
 
 #[test]
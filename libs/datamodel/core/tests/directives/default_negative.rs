@@ -76,3 +76,21 @@ fn should_error_if_default_value_parser_error() {
         Span::new(68, 74),
     ));
 }
+
+#[test]
+fn should_error_if_literal_default_value_on_uuid_field() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        rel Uuid @default("123e4567-e89b-12d3-a456-426614174000")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Only `uuid()` is supported as a default for Uuid fields.",
+        "default",
+        Span::new(64, 102),
+    ));
+}
@@ -11,11 +11,13 @@ fn should_set_default_for_all_scalar_types() {
     model Model {
         id Int @id
         int Int @default(3)
+        bigInt BigInt @default(9007199254740993)
         float Float @default(3.14)
         decimal Decimal @default(3.15)
         string String @default("String")
         boolean Boolean @default(false)
         dateTime DateTime @default("2019-06-17T14:20:57Z")
+        json Json @default("[1, 2]")
     }
     "#;
 
@@ -25,6 +27,10 @@ fn should_set_default_for_all_scalar_types() {
         .assert_has_field("int")
         .assert_base_type(&ScalarType::Int)
         .assert_default_value(DefaultValue::Single(ScalarValue::Int(3)));
+    user_model
+        .assert_has_field("bigInt")
+        .assert_base_type(&ScalarType::BigInt)
+        .assert_default_value(DefaultValue::Single(ScalarValue::BigInt(9007199254740993)));
     user_model
         .assert_has_field("float")
         .assert_base_type(&ScalarType::Float)
@@ -47,6 +53,10 @@ fn should_set_default_for_all_scalar_types() {
         .assert_default_value(DefaultValue::Single(ScalarValue::DateTime(
             "2019-06-17T14:20:57Z".parse::<DateTime<Utc>>().unwrap(),
         )));
+    user_model
+        .assert_has_field("json")
+        .assert_base_type(&ScalarType::Json)
+        .assert_default_value(DefaultValue::Single(ScalarValue::Json(String::from("[1, 2]"))));
 }
 
 #[test]
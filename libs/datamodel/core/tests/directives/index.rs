@@ -131,3 +131,63 @@ fn index_directives_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn fulltext_index_must_work() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        body  String
+
+        @@fulltext([title, body], name: "PostSearch")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let post_model = schema.assert_has_model("Post");
+    post_model.assert_has_index(IndexDefinition {
+        name: Some("PostSearch".to_string()),
+        fields: vec!["title".to_string(), "body".to_string()],
+        tpe: IndexType::Fulltext,
+    });
+}
+
+#[test]
+fn must_error_when_fulltext_index_refers_to_non_string_fields() {
+    let dml = r#"
+    model Post {
+        id    Int    @id
+        title String
+        views Int
+
+        @@fulltext([title, views])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The fulltext index definition refers to the fields views, but only String fields can be part of a fulltext index.",
+        "Post",
+        Span::new(93, 117),
+    ));
+}
+
+#[test]
+fn fulltext_index_directives_must_serialize_to_valid_dml() {
+    let dml = r#"
+        model Post {
+            id    Int    @id
+            title String
+            body  String
+
+            @@fulltext([title, body])
+        }
+    "#;
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains("@@fulltext([title, body])"));
+    assert!(datamodel::parse_datamodel(&rendered).is_ok());
+}
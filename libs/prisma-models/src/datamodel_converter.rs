@@ -142,6 +142,7 @@ impl<'a> DatamodelConverter<'a> {
                 typ: match i.tpe {
                     dml::IndexType::Unique => IndexType::Unique,
                     dml::IndexType::Normal => IndexType::Normal,
+                    dml::IndexType::Fulltext => IndexType::Fulltext,
                 },
             })
             .collect()
@@ -423,6 +424,10 @@ impl DatamodelFieldExtensions for dml::Field {
                 dml::ScalarType::Float => TypeIdentifier::Float,
                 dml::ScalarType::Int => TypeIdentifier::Int,
                 dml::ScalarType::String => TypeIdentifier::String,
+                dml::ScalarType::Json => TypeIdentifier::Json,
+                dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+                dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+                dml::ScalarType::Uuid => TypeIdentifier::UUID,
             },
             dml::FieldType::ConnectorSpecific { .. } => {
                 unimplemented!("Connector Specific types are not supported here yet")
@@ -448,7 +453,10 @@ impl DatamodelFieldExtensions for dml::Field {
             _ => false,
         };
 
-        let is_an_int = self.type_identifier() == TypeIdentifier::Int;
+        let is_an_int = match self.type_identifier() {
+            TypeIdentifier::Int | TypeIdentifier::BigInt => true,
+            _ => false,
+        };
 
         is_autogenerated_id && is_an_int
     }
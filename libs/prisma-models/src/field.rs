@@ -114,6 +114,8 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    Bytes,
+    BigInt,
 }
 
 impl Field {
@@ -204,6 +206,10 @@ impl From<ScalarType> for TypeIdentifier {
             ScalarType::Boolean => Self::Boolean,
             ScalarType::Decimal => Self::Float,
             ScalarType::DateTime => Self::DateTime,
+            ScalarType::Json => Self::Json,
+            ScalarType::Bytes => Self::Bytes,
+            ScalarType::BigInt => Self::BigInt,
+            ScalarType::Uuid => Self::UUID,
         }
     }
 }
@@ -10,7 +10,7 @@ pub struct IndexTemplate {
 impl IndexTemplate {
     pub fn build(self, fields: &[Field]) -> Index {
         let fields = match self.typ {
-            IndexType::Unique => Self::map_fields(self.fields, fields),
+            IndexType::Unique | IndexType::Fulltext => Self::map_fields(self.fields, fields),
             IndexType::Normal => vec![],
         };
 
@@ -61,4 +61,5 @@ impl Index {
 pub enum IndexType {
     Unique,
     Normal,
+    Fulltext,
 }
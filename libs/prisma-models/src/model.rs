@@ -132,6 +132,23 @@ impl Model {
             .collect()
     }
 
+    pub fn fulltext_indexes(&self) -> Vec<&Index> {
+        self.indexes()
+            .into_iter()
+            .filter(|index| index.typ == IndexType::Fulltext)
+            .collect()
+    }
+
+    /// The fulltext index on exactly the given field, which full-text searches on MySQL and SQLite require.
+    pub fn fulltext_index_on(&self, field: &ScalarField) -> Option<&Index> {
+        self.fulltext_indexes()
+            .into_iter()
+            .find(|index| match index.scalar_fields().as_slice() {
+                [indexed_field] => indexed_field.name == field.name,
+                _ => false,
+            })
+    }
+
     pub fn is_legacy(&self) -> bool {
         self.internal_data_model().is_legacy()
     }
@@ -1,12 +1,58 @@
-use crate::{ModelRef, ScalarField};
-use std::sync::Arc;
+use crate::{ModelRef, RelationFieldRef, ScalarFieldRef};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OrderBy {
-    pub field: Arc<ScalarField>,
+    /// Relations traversed from the ordered model to reach the ordered value, e.g. `author` for `author_name_ASC`.
+    /// All relations are to-one, except for the last one when ordering by a relation count.
+    pub path: Vec<RelationFieldRef>,
+    pub target: OrderByTarget,
     pub sort_order: SortOrder,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum OrderByTarget {
+    /// A scalar field of the model at the end of the path.
+    Field(ScalarFieldRef),
+
+    /// The number of records related via the last (to-many) relation of the path.
+    Count,
+
+    /// The full-text search relevance of a string field of the model itself for the search query.
+    /// The query is taken from the search filter on the field, it is unset until the filter is known.
+    Relevance {
+        field: ScalarFieldRef,
+        search: Option<String>,
+    },
+}
+
+impl OrderBy {
+    /// The ordered scalar field if it belongs to the model itself.
+    pub fn own_field(&self) -> Option<&ScalarFieldRef> {
+        match (self.path.is_empty(), &self.target) {
+            (true, OrderByTarget::Field(field)) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// Returns true if ordering by this guarantees a total order on the records of the model.
+    pub fn is_unique(&self) -> bool {
+        self.own_field().map(|field| field.unique()).unwrap_or(false)
+    }
+
+    /// Returns true if both order bys order by the same value, regardless of the sort order.
+    pub fn same_target(&self, other: &OrderBy) -> bool {
+        self.path == other.path && self.target == other.target
+    }
+
+    /// The field ordered by relevance, if this is a relevance ordering.
+    pub fn relevance_field(&self) -> Option<&ScalarFieldRef> {
+        match &self.target {
+            OrderByTarget::Relevance { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+}
+
 pub trait IntoOrderBy {
     fn into_order_by(self, model: ModelRef) -> OrderBy;
 }
@@ -1,6 +1,7 @@
 use super::{PrismaValue, TypeIdentifier};
 use crate::DomainError;
 use rust_decimal::prelude::ToPrimitive;
+use uuid::Uuid;
 
 // use std::convert::TryFrom;
 
@@ -21,6 +22,11 @@ impl PrismaValueExtensions for PrismaValue {
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::Json(_), TypeIdentifier::Json) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
+            (val @ PrismaValue::BigInt(_), TypeIdentifier::BigInt) => val,
+            (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+            (PrismaValue::BigInt(i), TypeIdentifier::Int) => PrismaValue::Int(i),
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -31,6 +37,18 @@ impl PrismaValueExtensions for PrismaValue {
             }
             (PrismaValue::Enum(e), TypeIdentifier::String) => PrismaValue::String(e),
             (PrismaValue::Uuid(u), TypeIdentifier::String) => PrismaValue::String(u.to_string()),
+            (PrismaValue::Json(j), TypeIdentifier::String) => PrismaValue::String(j),
+
+            // Valid UUID coercions
+            (PrismaValue::String(s), TypeIdentifier::UUID) => match Uuid::parse_str(&s) {
+                Ok(u) => PrismaValue::Uuid(u),
+                Err(_) => {
+                    return Err(DomainError::ConversionFailure(
+                        format!("{:?}", s),
+                        format!("{:?}", to_type),
+                    ))
+                }
+            },
 
             // Valid Int coersions
             (PrismaValue::String(s), TypeIdentifier::Int) => match s.parse() {
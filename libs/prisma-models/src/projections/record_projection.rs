@@ -1,5 +1,5 @@
 use super::ModelProjection;
-use crate::{DataSourceFieldRef, DomainError, PrismaValue};
+use crate::{DataSourceFieldRef, DomainError, PrismaValue, PrismaValueExtensions, TypeIdentifier};
 use std::{collections::HashMap, convert::TryFrom};
 
 /// Represents a (sub)set of fields to value pairs from a single record.
@@ -43,7 +43,11 @@ impl RecordProjection {
     {
         for pair in self.pairs.iter_mut() {
             if pair.1.is_null() {
-                pair.1 = value.into();
+                // Databases report generated values as plain integers, also for `BigInt` fields.
+                let value = value.into();
+                let coerce_to: TypeIdentifier = pair.0.field_type.into();
+
+                pair.1 = value.clone().coerce(&coerce_to).unwrap_or(value);
                 return true;
             }
         }
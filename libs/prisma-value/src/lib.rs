@@ -34,6 +34,17 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+
+    /// A JSON document, serialized. Serialized as a string in responses.
+    Json(String),
+
+    /// Binary data. Serialized as a base64 encoded string in responses.
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
+
+    /// A 64 bit integer. Serialized as a string in responses, clients can't represent all of them as numbers.
+    #[serde(serialize_with = "serialize_big_int")]
+    BigInt(i64),
 }
 
 pub fn stringify_date(date: &DateTime<Utc>) -> String {
@@ -74,6 +85,29 @@ where
     format!("{}", stringify_date(date)).serialize(serializer)
 }
 
+/// Bytes are exchanged as base64 encoded strings with clients.
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+pub fn decode_bytes(s: &str) -> PrismaValueResult<Vec<u8>> {
+    base64::decode(s).map_err(|_| ConversionFailure::new("base64 encoded bytes", "PrismaValue::Bytes"))
+}
+
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    encode_bytes(bytes).serialize(serializer)
+}
+
+fn serialize_big_int<S>(int: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    int.to_string().serialize(serializer)
+}
+
 fn serialize_decimal<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -115,6 +149,9 @@ impl fmt::Display for PrismaValue {
             PrismaValue::Int(x) => x.fmt(f),
             PrismaValue::Null => "null".fmt(f),
             PrismaValue::Uuid(x) => x.fmt(f),
+            PrismaValue::Json(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => encode_bytes(x).fmt(f),
+            PrismaValue::BigInt(x) => x.fmt(f),
             PrismaValue::List(x) => {
                 let as_string = format!("{:?}", x);
                 as_string.fmt(f)
@@ -197,6 +234,7 @@ impl TryFrom<PrismaValue> for i64 {
     fn try_from(value: PrismaValue) -> PrismaValueResult<i64> {
         match value {
             PrismaValue::Int(i) => Ok(i),
+            PrismaValue::BigInt(i) => Ok(i),
             _ => Err(ConversionFailure::new("PrismaValue", "i64")),
         }
     }
@@ -11,10 +11,11 @@ impl<'a> From<ParameterizedValue<'a>> for PrismaValue {
             ParameterizedValue::Enum(s) => PrismaValue::Enum(s.into_owned()),
             ParameterizedValue::Boolean(b) => PrismaValue::Boolean(b),
             ParameterizedValue::Array(v) => PrismaValue::List(v.into_iter().map(PrismaValue::from).collect()),
-            ParameterizedValue::Json(val) => PrismaValue::String(val.to_string()),
+            ParameterizedValue::Json(val) => PrismaValue::Json(val.to_string()),
             ParameterizedValue::Uuid(uuid) => PrismaValue::Uuid(uuid),
             ParameterizedValue::DateTime(dt) => PrismaValue::DateTime(dt),
             ParameterizedValue::Char(c) => PrismaValue::String(c.to_string()),
+            ParameterizedValue::Bytes(bytes) => PrismaValue::Bytes(bytes.into_owned()),
         }
     }
 }
@@ -28,9 +29,17 @@ impl<'a> From<PrismaValue> for ParameterizedValue<'a> {
             PrismaValue::DateTime(d) => d.into(),
             PrismaValue::Enum(e) => ParameterizedValue::Enum(e.into()),
             PrismaValue::Int(i) => (i as i64).into(),
+            PrismaValue::BigInt(i) => i.into(),
             PrismaValue::Null => ParameterizedValue::Null,
-            PrismaValue::Uuid(u) => u.to_string().into(),
+            PrismaValue::Uuid(u) => u.into(),
             PrismaValue::List(l) => ParameterizedValue::Array(l.into_iter().map(|x| x.into()).collect()),
+            // Json values are validated when parsed from requests. Should an invalid document still get here
+            // (e.g. a default value of the data model), it's passed on as text for the database to reject.
+            PrismaValue::Json(s) => match serde_json::from_str(&s) {
+                Ok(json) => ParameterizedValue::Json(json),
+                Err(_) => ParameterizedValue::Text(s.into()),
+            },
+            PrismaValue::Bytes(b) => ParameterizedValue::Bytes(b.into()),
         }
     }
 }
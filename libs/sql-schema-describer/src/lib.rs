@@ -158,6 +158,8 @@ pub enum IndexType {
     Unique,
     /// Normal type.
     Normal,
+    /// Full-text type: a FULLTEXT index on MySQL, an FTS5 table on SQLite.
+    Fulltext,
 }
 
 impl IndexType {
@@ -253,6 +255,8 @@ impl ColumnType {
 pub enum ColumnTypeFamily {
     /// Integer types.
     Int,
+    /// 64 bit integer types.
+    BigInt,
     /// Floating point types.
     Float,
     /// Boolean types.
@@ -285,6 +289,7 @@ impl fmt::Display for ColumnTypeFamily {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
             Self::Int => "int".to_string(),
+            Self::BigInt => "bigInt".to_string(),
             Self::Float => "float".to_string(),
             Self::Boolean => "boolean".to_string(),
             Self::String => "string".to_string(),
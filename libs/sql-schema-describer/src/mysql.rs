@@ -246,7 +246,8 @@ async fn get_all_indexes(
                 non_unique AS non_unique,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
-                table_name AS table_name
+                table_name AS table_name,
+                index_type AS index_type
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
             ORDER BY index_name, seq_in_index
@@ -265,6 +266,11 @@ async fn get_all_indexes(
         let index_name = row.get("index_name").and_then(|x| x.to_string()).expect("index_name");
         let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
         let column_name = row.get("column_name").and_then(|x| x.to_string()).expect("column_name");
+        let is_fulltext = row
+            .get("index_type")
+            .and_then(|x| x.to_string())
+            .map(|index_type| index_type == "FULLTEXT")
+            .unwrap_or(false);
 
         // Multi-column indices will return more than one row (with different column_name values).
         // We cannot assume that one row corresponds to one index.
@@ -307,9 +313,10 @@ async fn get_all_indexes(
                     Index {
                         name: index_name,
                         columns: vec![column_name],
-                        tpe: match is_unique {
-                            true => IndexType::Unique,
-                            false => IndexType::Normal,
+                        tpe: match (is_unique, is_fulltext) {
+                            (_, true) => IndexType::Fulltext,
+                            (true, false) => IndexType::Unique,
+                            (false, false) => IndexType::Normal,
                         },
                     },
                 );
@@ -451,7 +458,7 @@ fn get_column_type_and_enum(
         ("tinyint", "tinyint(1)") => ColumnTypeFamily::Boolean,
         ("tinyint", _) => ColumnTypeFamily::Int,
         ("mediumint", _) => ColumnTypeFamily::Int,
-        ("bigint", _) => ColumnTypeFamily::Int,
+        ("bigint", _) => ColumnTypeFamily::BigInt,
         ("decimal", _) => ColumnTypeFamily::Float,
         ("numeric", _) => ColumnTypeFamily::Float,
         ("float", _) => ColumnTypeFamily::Float,
@@ -461,6 +468,8 @@ fn get_column_type_and_enum(
         ("datetime", _) => ColumnTypeFamily::DateTime,
         ("timestamp", _) => ColumnTypeFamily::DateTime,
         ("year", _) => ColumnTypeFamily::DateTime,
+        // MySQL has no UUID type, UUIDs are stored as their hyphenated text representation.
+        ("char", "char(36)") => ColumnTypeFamily::Uuid,
         ("char", _) => ColumnTypeFamily::String,
         ("varchar", _) => ColumnTypeFamily::String,
         ("text", _) => ColumnTypeFamily::String,
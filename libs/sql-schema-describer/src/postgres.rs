@@ -572,7 +572,7 @@ fn get_column_type<'a>(data_type: &str, full_data_type: &'a str, arity: ColumnAr
         x if data_type == "ARRAY" && x.starts_with("_") && enum_exists(trim(x)) => Enum(trim(x).to_owned()),
         "int2" | "_int2" => Int,
         "int4" | "_int4" => Int,
-        "int8" | "_int8" => Int,
+        "int8" | "_int8" => BigInt,
         "float4" | "_float4" => Float,
         "float8" | "_float8" => Float,
         "bool" | "_bool" => Boolean,
@@ -2,7 +2,9 @@
 use super::*;
 use failure::_core::convert::TryInto;
 use log::debug;
+use once_cell::sync::Lazy;
 use quaint::{ast::ParameterizedValue, prelude::Queryable};
+use regex::Regex;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -29,11 +31,24 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema> {
         debug!("describing schema '{}'", schema);
         let table_names: Vec<String> = self.get_table_names(schema).await;
+        let mut fulltext_indices = self.get_fulltext_indices(schema).await;
+        let fulltext_tables: Vec<String> = fulltext_indices
+            .values()
+            .flatten()
+            .flat_map(|index| fts5_table_names(&index.name))
+            .collect();
 
         let mut tables = Vec::with_capacity(table_names.len());
 
-        for table_name in table_names.iter().filter(|table| !is_system_table(&table)) {
-            tables.push(self.get_table(schema, table_name).await)
+        for table_name in table_names
+            .iter()
+            .filter(|table| !is_system_table(&table) && !fulltext_tables.contains(table))
+        {
+            let mut table = self.get_table(schema, table_name).await;
+            table
+                .indices
+                .extend(fulltext_indices.remove(table_name).unwrap_or_default());
+            tables.push(table)
         }
 
         Ok(SqlSchema {
@@ -334,6 +349,70 @@ impl SqlSchemaDescriber {
 
         indices
     }
+
+    /// Fulltext indices are FTS5 tables over the content of the indexed table, see
+    /// https://www.sqlite.org/fts5.html#external_content_tables. They are keyed by the name of the
+    /// indexed table.
+    async fn get_fulltext_indices(&self, schema: &str) -> HashMap<String, Vec<Index>> {
+        let sql = format!(
+            r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='table' AND sql LIKE 'CREATE VIRTUAL TABLE%'"#,
+            schema
+        );
+        debug!("describing fulltext indices, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[])
+            .await
+            .expect("querying for fulltext indices");
+        debug!("Got fulltext indices description results: {:?}", result_set);
+
+        let mut indices: HashMap<String, Vec<Index>> = HashMap::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+            let sql = row.get("sql").and_then(|x| x.to_string()).expect("get sql");
+
+            let table = match FTS5_CONTENT_TABLE_RE.captures(&sql) {
+                Some(captures) => captures[1].replace("''", "'"),
+                None => continue,
+            };
+
+            let sql = format!(r#"PRAGMA "{}".table_info("{}");"#, schema, name);
+            debug!("describing fulltext index '{}', SQL: '{}'", name, sql);
+            let result_set = self
+                .conn
+                .query_raw(&sql, &[])
+                .await
+                .expect("querying for fulltext index info");
+            let columns = result_set
+                .into_iter()
+                .map(|row| row.get("name").and_then(|x| x.to_string()).expect("get name"))
+                .collect();
+
+            indices.entry(table).or_default().push(Index {
+                name,
+                columns,
+                tpe: IndexType::Fulltext,
+            });
+        }
+
+        indices
+    }
+}
+
+/// Matches the `content` option of an FTS5 table, which names the table it indexes.
+static FTS5_CONTENT_TABLE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)USING\s+fts5\s*\(.*\bcontent\s*=\s*'((?:[^']|'')*)'"#).expect("compile regex"));
+
+/// The FTS5 table backing a fulltext index, and the shadow tables SQLite creates for it.
+fn fts5_table_names(index_name: &str) -> Vec<String> {
+    std::iter::once(index_name.to_owned())
+        .chain(
+            ["data", "idx", "content", "docsize", "config"]
+                .iter()
+                .map(|suffix| format!("{}_{}", index_name, suffix)),
+        )
+        .collect()
 }
 
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
@@ -343,6 +422,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         // It's tolerant though, and you can assign any data type you like to columns
         "int" => ColumnTypeFamily::Int,
         "integer" => ColumnTypeFamily::Int,
+        "bigint" => ColumnTypeFamily::BigInt,
         "real" => ColumnTypeFamily::Float,
         "float" => ColumnTypeFamily::Float,
         "serial" => ColumnTypeFamily::Int,
@@ -353,6 +433,8 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "date" => ColumnTypeFamily::DateTime,
         "datetime" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
+        "uuid" => ColumnTypeFamily::Uuid,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -129,7 +129,7 @@ async fn all_mysql_column_types_must_work() {
             name: "bigint_col".to_string(),
             tpe: ColumnType {
                 raw: "bigint".to_string(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
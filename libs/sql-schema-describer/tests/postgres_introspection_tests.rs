@@ -247,7 +247,7 @@ async fn all_postgres_column_types_must_work() {
             name: "bigint_col".into(),
             tpe: ColumnType {
                 raw: "int8".into(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
@@ -258,7 +258,7 @@ async fn all_postgres_column_types_must_work() {
             name: "bigserial_col".into(),
             tpe: ColumnType {
                 raw: "int8".into(),
-                family: ColumnTypeFamily::Int,
+                family: ColumnTypeFamily::BigInt,
                 arity: ColumnArity::Required,
             },
 
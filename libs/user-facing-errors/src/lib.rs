@@ -64,6 +64,14 @@ impl Error {
         }
     }
 
+    /// The error code of a known error, `None` for unknown errors.
+    pub fn error_code(&self) -> Option<&str> {
+        match &self.inner {
+            ErrorType::Known(err) => Some(err.error_code),
+            ErrorType::Unknown(_) => None,
+        }
+    }
+
     pub fn new_non_panic_with_current_backtrace(message: String) -> Self {
         Error {
             inner: ErrorType::Unknown(UnknownError {
@@ -225,7 +225,9 @@ fn copy_current_table_into_new_table(
 
 fn render_default(column: &Column) -> String {
     match column.tpe.family {
-        ColumnTypeFamily::String => format!("'{}'", column.default.as_ref().unwrap()),
+        ColumnTypeFamily::String | ColumnTypeFamily::Json | ColumnTypeFamily::Uuid => {
+            format!("'{}'", column.default.as_ref().unwrap())
+        }
         _ => column.default.as_ref().unwrap().to_string(),
     }
 }
@@ -205,10 +205,29 @@ fn render_raw_sql(
 
             Ok(vec![create_table])
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => Ok(vec![format!(
-            "DROP TABLE {};",
-            renderer.quote_with_schema(&schema_name, &name)
-        )]),
+        SqlMigrationStep::DropTable(DropTable { name }) => {
+            let mut statements = match sql_family {
+                SqlFamily::Sqlite => current_schema
+                    .table(name)
+                    .map(|table| {
+                        table
+                            .indices
+                            .iter()
+                            .filter(|index| index.tpe == IndexType::Fulltext)
+                            .flat_map(|index| sqlite_drop_fulltext_index(renderer, &schema_name, &index.name))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                _ => Vec::new(),
+            };
+
+            statements.push(format!(
+                "DROP TABLE {};",
+                renderer.quote_with_schema(&schema_name, &name)
+            ));
+
+            Ok(statements)
+        }
         SqlMigrationStep::DropTables(DropTables { names }) => {
             let fully_qualified_names: Vec<String> = names
                 .iter()
@@ -314,7 +333,7 @@ fn render_raw_sql(
             )])
         }
         SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
-            Ok(vec![render_create_index(renderer, database_info, table, index)])
+            Ok(render_create_index(renderer, database_info, table, index))
         }
         SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
             SqlFamily::Mysql => Ok(vec![format!(
@@ -322,6 +341,16 @@ fn render_raw_sql(
                 renderer.quote(&name),
                 renderer.quote_with_schema(&schema_name, &table),
             )]),
+            SqlFamily::Sqlite
+                if current_schema
+                    .table(table)
+                    .ok()
+                    .and_then(|table| table.indices.iter().find(|index| &index.name == name))
+                    .map(|index| index.tpe == IndexType::Fulltext)
+                    .unwrap_or(false) =>
+            {
+                Ok(sqlite_drop_fulltext_index(renderer, &schema_name, name))
+            }
             SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
                 "DROP INDEX {}",
                 renderer.quote_with_schema(&schema_name, &name)
@@ -357,10 +386,10 @@ fn render_raw_sql(
                     new_index.name = index_new_name.clone();
 
                     // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
-                    Ok(vec![
-                        render_create_index(renderer, database_info, table, &new_index),
-                        mysql_drop_index(renderer, &schema_name, table, index_name)?,
-                    ])
+                    let mut statements = render_create_index(renderer, database_info, table, &new_index);
+                    statements.push(mysql_drop_index(renderer, &schema_name, table, index_name)?);
+
+                    Ok(statements)
                 } else {
                     Ok(vec![format!(
                         "ALTER TABLE {table_name} RENAME INDEX {index_name} TO {index_new_name}",
@@ -386,13 +415,24 @@ fn render_create_index(
     database_info: &DatabaseInfo,
     table_name: &str,
     index: &Index,
-) -> String {
+) -> Vec<String> {
     let Index { name, columns, tpe } = index;
+    let sql_family = database_info.sql_family();
+
+    if let (IndexType::Fulltext, SqlFamily::Sqlite) = (tpe, sql_family) {
+        return sqlite_create_fulltext_index(
+            renderer,
+            database_info.connection_info().schema_name(),
+            table_name,
+            index,
+        );
+    }
+
     let index_type = match tpe {
         IndexType::Unique => "UNIQUE",
         IndexType::Normal => "",
+        IndexType::Fulltext => "FULLTEXT",
     };
-    let sql_family = database_info.sql_family();
     let index_name = match sql_family {
         SqlFamily::Sqlite => renderer.quote_with_schema(database_info.connection_info().schema_name(), &name),
         _ => renderer.quote(&name),
@@ -403,13 +443,98 @@ fn render_create_index(
     };
     let columns: Vec<String> = columns.iter().map(|c| renderer.quote(c)).collect();
 
-    format!(
+    vec![format!(
         "CREATE {} INDEX {} ON {}({})",
         index_type,
         index_name,
         table_reference,
         columns.join(",")
-    )
+    )]
+}
+
+/// SQLite has no fulltext indices. They are FTS5 tables over the content of the indexed table
+/// instead, kept in sync by triggers. See https://www.sqlite.org/fts5.html#external_content_tables
+fn sqlite_create_fulltext_index(
+    renderer: &dyn SqlRenderer,
+    schema_name: &str,
+    table_name: &str,
+    index: &Index,
+) -> Vec<String> {
+    let fts_table = renderer.quote(&index.name);
+    let table = renderer.quote(table_name);
+    let columns = index.columns.iter().map(|column| renderer.quote(column)).join(", ");
+    let values = |row: &str| {
+        index
+            .columns
+            .iter()
+            .map(|column| format!("{}.{}", row, renderer.quote(column)))
+            .join(", ")
+    };
+    let trigger_name = |suffix: &str| renderer.quote_with_schema(schema_name, &format!("{}_{}", index.name, suffix));
+
+    let insert_new = format!(
+        "INSERT INTO {fts_table}(rowid, {columns}) VALUES (new.rowid, {values});",
+        fts_table = fts_table,
+        columns = columns,
+        values = values("new"),
+    );
+    let delete_old = format!(
+        "INSERT INTO {fts_table}({fts_table}, rowid, {columns}) VALUES ('delete', old.rowid, {values});",
+        fts_table = fts_table,
+        columns = columns,
+        values = values("old"),
+    );
+
+    vec![
+        format!(
+            "CREATE VIRTUAL TABLE {} USING fts5({}, content='{}', content_rowid='rowid')",
+            renderer.quote_with_schema(schema_name, &index.name),
+            columns,
+            table_name.replace("'", "''"),
+        ),
+        format!(
+            "CREATE TRIGGER {} AFTER INSERT ON {} BEGIN {} END",
+            trigger_name("insert"),
+            table,
+            insert_new
+        ),
+        format!(
+            "CREATE TRIGGER {} AFTER DELETE ON {} BEGIN {} END",
+            trigger_name("delete"),
+            table,
+            delete_old
+        ),
+        format!(
+            "CREATE TRIGGER {} AFTER UPDATE ON {} BEGIN {} {} END",
+            trigger_name("update"),
+            table,
+            delete_old,
+            insert_new
+        ),
+        // Index the rows that are already in the table.
+        format!(
+            "INSERT INTO {}({}) VALUES ('rebuild')",
+            renderer.quote_with_schema(schema_name, &index.name),
+            fts_table
+        ),
+    ]
+}
+
+/// The triggers are gone already when the indexed table was dropped before.
+fn sqlite_drop_fulltext_index(renderer: &dyn SqlRenderer, schema_name: &str, index_name: &str) -> Vec<String> {
+    ["insert", "delete", "update"]
+        .iter()
+        .map(|suffix| {
+            format!(
+                "DROP TRIGGER IF EXISTS {}",
+                renderer.quote_with_schema(schema_name, &format!("{}_{}", index_name, suffix))
+            )
+        })
+        .chain(std::iter::once(format!(
+            "DROP TABLE IF EXISTS {}",
+            renderer.quote_with_schema(schema_name, index_name)
+        )))
+        .collect()
 }
 
 fn mysql_drop_index(
@@ -57,6 +57,10 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer) -> Option<Vec
                 (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
                     changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()))
                 }
+                // Widening an int to a bigint never loses data.
+                (ColumnTypeFamily::Int, ColumnTypeFamily::BigInt) => {
+                    changes.push(PostgresAlterColumn::SetType(columns.next.tpe.clone()))
+                }
                 _ => return None,
             },
             ColumnChange::Renaming => unreachable!("column renaming"),
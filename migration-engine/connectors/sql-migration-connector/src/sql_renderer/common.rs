@@ -13,7 +13,11 @@ pub(crate) fn render_nullability(column: &ColumnRef<'_>) -> &'static str {
 pub(crate) fn render_default(column: &ColumnRef<'_>) -> String {
     match column.default() {
         Some(value) => match &column.column_type().family {
-            ColumnTypeFamily::String | ColumnTypeFamily::DateTime | ColumnTypeFamily::Enum(_) => format!(
+            ColumnTypeFamily::String
+            | ColumnTypeFamily::DateTime
+            | ColumnTypeFamily::Enum(_)
+            | ColumnTypeFamily::Json
+            | ColumnTypeFamily::Uuid => format!(
                 "DEFAULT '{}'",
                 // TODO: remove once sql-schema-describer does unescaping, and perform escaping again here.
                 value
@@ -24,7 +24,11 @@ impl super::SqlRenderer for MySqlRenderer {
         let column_name = self.quote(column.name());
         let tpe_str = self.render_column_type(&column).unwrap();
         let nullability_str = render_nullability(&column);
-        let default_str = render_default(&column);
+        // JSON columns can't have a literal default on MySQL.
+        let default_str = match column.column_type().family {
+            ColumnTypeFamily::Json => String::new(),
+            _ => render_default(&column),
+        };
         let foreign_key = column.table().foreign_key_for_column(column.name());
         let auto_increment_str = if column.auto_increment() { "AUTO_INCREMENT" } else { "" };
 
@@ -61,9 +65,14 @@ impl MySqlRenderer {
             ColumnTypeFamily::DateTime => Ok(format!("datetime(3)")),
             ColumnTypeFamily::Float => Ok(format!("Decimal(65,30)")),
             ColumnTypeFamily::Int => Ok(format!("int")),
+            ColumnTypeFamily::BigInt => Ok(format!("bigint")),
             // we use varchar right now as mediumtext doesn't allow default values
             // a bigger length would not allow to use such a column as primary key
             ColumnTypeFamily::String => Ok(format!("varchar{}", VARCHAR_LENGTH_PREFIX)),
+            ColumnTypeFamily::Json => Ok(format!("json")),
+            ColumnTypeFamily::Binary => Ok(format!("longblob")),
+            // UUIDs are stored in their hyphenated text form.
+            ColumnTypeFamily::Uuid => Ok(format!("char(36)")),
             ColumnTypeFamily::Enum(enum_name) => {
                 let r#enum = column
                     .schema()
@@ -25,7 +25,12 @@ impl super::SqlRenderer for PostgresRenderer {
         let is_serial = column.auto_increment();
 
         if is_serial {
-            format!("{} SERIAL", column_name)
+            let serial_str = match column.column_type().family {
+                ColumnTypeFamily::BigInt => "BIGSERIAL",
+                _ => "SERIAL",
+            };
+
+            format!("{} {}", column_name, serial_str)
         } else {
             format!("{} {} {} {}", column_name, tpe_str, nullability_str, default_str)
         }
@@ -55,7 +60,11 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
         ColumnTypeFamily::Float => format!("Decimal(65,30) {}", array),
         ColumnTypeFamily::Int => format!("integer {}", array),
+        ColumnTypeFamily::BigInt => format!("bigint {}", array),
         ColumnTypeFamily::String => format!("text {}", array),
+        ColumnTypeFamily::Json => format!("jsonb {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
+        ColumnTypeFamily::Uuid => format!("uuid {}", array),
         ColumnTypeFamily::Enum(name) => format!("{}{}", quoted(name), array),
         x => unimplemented!("{:?} not handled yet", x),
     }
@@ -20,7 +20,12 @@ impl super::SqlRenderer for SqliteRenderer {
 
     fn render_column(&self, _schema_name: &str, column: ColumnRef<'_>, _add_fk_prefix: bool) -> String {
         let column_name = quoted(column.name());
-        let tpe_str = self.render_column_type(column.column_type());
+        // AUTOINCREMENT is only allowed on INTEGER PRIMARY KEY columns, which are 64 bit on SQLite anyway.
+        let tpe_str = if column.auto_increment() {
+            "INTEGER".to_string()
+        } else {
+            self.render_column_type(column.column_type())
+        };
         let nullability_str = render_nullability(&column);
         let default_str = render_default(&column);
         let auto_increment_str = if column.auto_increment() {
@@ -54,7 +59,12 @@ impl SqliteRenderer {
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
+            ColumnTypeFamily::BigInt => format!("BIGINT"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Json => format!("TEXT"),
+            ColumnTypeFamily::Binary => format!("BLOB"),
+            // SQLite has no UUID type, the declared type only keeps the column recognizable.
+            ColumnTypeFamily::Uuid => format!("UUID"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
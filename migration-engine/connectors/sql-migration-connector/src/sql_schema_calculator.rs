@@ -150,34 +150,41 @@ impl<'a> SqlSchemaCalculator<'a> {
                     }
                 });
 
-                let multiple_field_indexes = model.indexes().map(|index_definition: &IndexDefinition| {
-                    let referenced_fields: Vec<FieldRef> = index_definition
-                        .fields
-                        .iter()
-                        .map(|field_name| model.find_field(field_name).expect("Unknown field in index directive."))
-                        .collect();
-
-                    sql::Index {
-                        name: index_definition.name.clone().unwrap_or_else(|| {
-                            format!(
-                                "{}.{}",
-                                &model.db_name(),
-                                referenced_fields.iter().map(|field| field.db_name()).join("_")
-                            )
-                        }),
-                        // The model index definition uses the model field names, but the SQL Index
-                        // wants the column names.
-                        columns: referenced_fields
+                // Postgres searches without a dedicated index, so there is nothing to create for `@@fulltext`.
+                let multiple_field_indexes = model
+                    .indexes()
+                    .filter(|index_definition| {
+                        index_definition.tpe != IndexType::Fulltext
+                            || self.database_info.sql_family() != SqlFamily::Postgres
+                    })
+                    .map(|index_definition: &IndexDefinition| {
+                        let referenced_fields: Vec<FieldRef> = index_definition
+                            .fields
                             .iter()
-                            .map(|field| field.db_name().to_owned())
-                            .collect(),
-                        tpe: if index_definition.tpe == IndexType::Unique {
-                            sql::IndexType::Unique
-                        } else {
-                            sql::IndexType::Normal
-                        },
-                    }
-                });
+                            .map(|field_name| model.find_field(field_name).expect("Unknown field in index directive."))
+                            .collect();
+
+                        sql::Index {
+                            name: index_definition.name.clone().unwrap_or_else(|| {
+                                format!(
+                                    "{}.{}",
+                                    &model.db_name(),
+                                    referenced_fields.iter().map(|field| field.db_name()).join("_")
+                                )
+                            }),
+                            // The model index definition uses the model field names, but the SQL Index
+                            // wants the column names.
+                            columns: referenced_fields
+                                .iter()
+                                .map(|field| field.db_name().to_owned())
+                                .collect(),
+                            tpe: match index_definition.tpe {
+                                IndexType::Unique => sql::IndexType::Unique,
+                                IndexType::Normal => sql::IndexType::Normal,
+                                IndexType::Fulltext => sql::IndexType::Fulltext,
+                            },
+                        }
+                    });
 
                 let table = sql::Table {
                     name: model.database_name().to_owned(),
@@ -407,6 +414,11 @@ struct ModelTable {
 }
 
 fn migration_value_new(field: &FieldRef<'_>) -> Option<String> {
+    // Bytes fields can't have default values.
+    if let TypeRef::Base(ScalarType::Bytes) = field.field_type() {
+        return None;
+    }
+
     let value = match (&field.default_value(), field.arity()) {
         (Some(df), _) => match df {
             dml::DefaultValue::Single(s) => s.clone(),
@@ -426,9 +438,11 @@ fn migration_value_new(field: &FieldRef<'_>) -> Option<String> {
             }
         }
         ScalarValue::Int(x) => format!("{}", x),
+        ScalarValue::BigInt(x) => format!("{}", x),
         ScalarValue::Float(x) => format!("{}", x),
         ScalarValue::Decimal(x) => format!("{}", x),
         ScalarValue::String(x) => format!("{}", x),
+        ScalarValue::Json(x) => format!("{}", x),
 
         ScalarValue::DateTime(x) => {
             let mut raw = format!("{}", x); // this will produce a String 1970-01-01 00:00:00 UTC
@@ -461,9 +475,12 @@ fn default_migration_value(field_type: &TypeRef<'_>) -> ScalarValue {
     match field_type {
         TypeRef::Base(ScalarType::Boolean) => ScalarValue::Boolean(false),
         TypeRef::Base(ScalarType::Int) => ScalarValue::Int(0),
+        TypeRef::Base(ScalarType::BigInt) => ScalarValue::BigInt(0),
         TypeRef::Base(ScalarType::Float) => ScalarValue::Float(0.0),
         TypeRef::Base(ScalarType::String) => ScalarValue::String("".to_string()),
         TypeRef::Base(ScalarType::Decimal) => ScalarValue::Decimal(0.0),
+        TypeRef::Base(ScalarType::Json) => ScalarValue::Json("null".to_string()),
+        TypeRef::Base(ScalarType::Uuid) => ScalarValue::String("00000000-0000-0000-0000-000000000000".to_string()),
         TypeRef::Base(ScalarType::DateTime) => {
             let naive = NaiveDateTime::from_timestamp(0, 0);
             let datetime: DateTime<Utc> = DateTime::from_utc(naive, Utc);
@@ -523,10 +540,14 @@ fn column_arity(arity: FieldArity) -> sql::ColumnArity {
 fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnArity) -> sql::ColumnType {
     match scalar_type {
         ScalarType::Int => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::BigInt, column_arity),
+        ScalarType::Uuid => sql::ColumnType::pure(sql::ColumnTypeFamily::Uuid, column_arity),
         ScalarType::Float => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
         ScalarType::Boolean => sql::ColumnType::pure(sql::ColumnTypeFamily::Boolean, column_arity),
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime, column_arity),
+        ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
         ScalarType::Decimal => unimplemented!(),
     }
 }
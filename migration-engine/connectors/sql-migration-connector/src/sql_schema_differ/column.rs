@@ -65,7 +65,7 @@ impl<'a> ColumnDiffer<'a> {
         match self.previous.tpe.family {
             ColumnTypeFamily::String => string_defaults_match(previous_value, next_value),
             ColumnTypeFamily::Float => float_default(previous_value) == float_default(next_value),
-            ColumnTypeFamily::Int => int_default(previous_value) == int_default(next_value),
+            ColumnTypeFamily::Int | ColumnTypeFamily::BigInt => int_default(previous_value) == int_default(next_value),
             ColumnTypeFamily::Boolean => bool_default(previous_value) == bool_default(next_value),
             _ => true,
         }
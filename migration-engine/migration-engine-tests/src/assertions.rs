@@ -333,4 +333,10 @@ impl<'a> IndexAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_is_fulltext(self) -> AssertionResult<Self> {
+        assert_eq!(self.0.tpe, IndexType::Fulltext);
+
+        Ok(self)
+    }
 }
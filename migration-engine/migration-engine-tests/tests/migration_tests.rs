@@ -12,10 +12,12 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) {
         model Test {
             id String @id @default(cuid())
             int Int
+            bigInt BigInt
             float Float
             boolean Boolean
             string String
             dateTime DateTime
+            uuid Uuid
             enum MyEnum
         }
 
@@ -29,10 +31,12 @@ async fn adding_a_scalar_field_must_work(api: &TestApi) {
     table.columns.iter().for_each(|c| assert_eq!(c.is_required(), true));
 
     assert_eq!(table.column_bang("int").tpe.family, ColumnTypeFamily::Int);
+    assert_eq!(table.column_bang("bigInt").tpe.family, ColumnTypeFamily::BigInt);
     assert_eq!(table.column_bang("float").tpe.family, ColumnTypeFamily::Float);
     assert_eq!(table.column_bang("boolean").tpe.family, ColumnTypeFamily::Boolean);
     assert_eq!(table.column_bang("string").tpe.family, ColumnTypeFamily::String);
     assert_eq!(table.column_bang("dateTime").tpe.family, ColumnTypeFamily::DateTime);
+    assert_eq!(table.column_bang("uuid").tpe.family, ColumnTypeFamily::Uuid);
 
     match api.sql_family() {
         SqlFamily::Postgres => assert_eq!(
@@ -75,3 +75,45 @@ async fn enum_creation_is_idempotent(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn fulltext_indexes_are_migrated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Post {
+            id    Int    @id
+            title String
+            body  String
+
+            @@fulltext([title, body], name: "PostSearch")
+        }
+    "#;
+
+    api.infer_apply(dm1).send_assert().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Post", |table| {
+        table
+            .assert_indexes_count(1)?
+            .assert_index_on_columns(&["title", "body"], |idx| idx.assert_is_fulltext())
+    })?;
+
+    api.infer_apply(dm1)
+        .send_assert()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    let dm2 = r#"
+        model Post {
+            id    Int    @id
+            title String
+            body  String
+        }
+    "#;
+
+    api.infer_apply(dm2).send_assert().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Post", |table| table.assert_indexes_count(0))
+        .map(drop)
+}
@@ -75,3 +75,70 @@ async fn sqlite_must_recreate_multi_field_indexes(api: &TestApi) -> TestResult {
         })
         .map(drop)
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn sqlite_must_recreate_fulltext_indexes(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model A {
+            id    Int    @id
+            title String
+
+            @@fulltext([title])
+        }
+    "#;
+
+    api.infer_apply(&dm1).send_assert().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("A", |table| {
+        table
+            .assert_indexes_count(1)?
+            .assert_index_on_columns(&["title"], |idx| idx.assert_is_fulltext())
+    })?;
+
+    api.insert("A")
+        .value("id", 1)
+        .value("title", "hello world")
+        .result_raw()
+        .await?;
+
+    // Adding a required column goes through the table redefinition.
+    let dm2 = r#"
+        model A {
+            id    Int    @id
+            title String
+            other String @default("")
+
+            @@fulltext([title])
+        }
+    "#;
+
+    api.infer_apply(&dm2).send_assert().await?.assert_green()?;
+
+    let sql_schema = api
+        .assert_schema()
+        .await?
+        .assert_table("A", |table| {
+            table
+                .assert_indexes_count(1)?
+                .assert_index_on_columns(&["title"], |idx| idx.assert_is_fulltext())
+        })?
+        .into_schema();
+
+    api.infer_apply(&dm2)
+        .send_assert()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    api.assert_schema().await?.assert_equals(&sql_schema)?;
+
+    let search = format!(
+        r#"SELECT rowid FROM "{schema}"."A.title" WHERE "A.title" MATCH 'hello'"#,
+        schema = api.schema_name()
+    );
+    let rows = api.database().query_raw(&search, &[]).await?;
+
+    assert_eq!(rows.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class AggregationFunctionsSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id    String @id @default(cuid())
+      |  float Float
+      |  int   Int
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Aggregating on an empty table" should "return null for field aggregations and 0 for count" in {
+    val result = server.query(
+      """{
+        |  aggregateItem {
+        |    count
+        |    avg { float int }
+        |    sum { float int }
+        |    min { float int }
+        |    max { float int }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.aggregateItem.count") should be(0)
+    result.pathAsJsValue("data.aggregateItem.avg").toString should be("""{"float":null,"int":null}""")
+    result.pathAsJsValue("data.aggregateItem.sum").toString should be("""{"float":null,"int":null}""")
+    result.pathAsJsValue("data.aggregateItem.min").toString should be("""{"float":null,"int":null}""")
+    result.pathAsJsValue("data.aggregateItem.max").toString should be("""{"float":null,"int":null}""")
+  }
+
+  "Aggregating" should "compute all aggregations over the selected fields" in {
+    createItem(5.5, 5)
+    createItem(4.5, 10)
+
+    val result = server.query(
+      """{
+        |  aggregateItem {
+        |    count
+        |    avg { float int }
+        |    sum { float int }
+        |    min { float int }
+        |    max { float int }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.aggregateItem.count") should be(2)
+    result.pathAsDouble("data.aggregateItem.avg.float") should be(5.0)
+    result.pathAsDouble("data.aggregateItem.avg.int") should be(7.5)
+    result.pathAsDouble("data.aggregateItem.sum.float") should be(10.0)
+    result.pathAsLong("data.aggregateItem.sum.int") should be(15)
+    result.pathAsDouble("data.aggregateItem.min.float") should be(4.5)
+    result.pathAsLong("data.aggregateItem.min.int") should be(5)
+    result.pathAsDouble("data.aggregateItem.max.float") should be(5.5)
+    result.pathAsLong("data.aggregateItem.max.int") should be(10)
+  }
+
+  "Aggregating" should "obey filters and pagination" in {
+    createItem(1.0, 1)
+    createItem(2.0, 2)
+    createItem(3.0, 3)
+    createItem(4.0, 4)
+
+    val result = server.query(
+      """{
+        |  aggregateItem(where: { int_gt: 1 }, orderBy: int_ASC, first: 2) {
+        |    count
+        |    sum { int }
+        |    max { float }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsLong("data.aggregateItem.count") should be(2)
+    result.pathAsLong("data.aggregateItem.sum.int") should be(5)
+    result.pathAsDouble("data.aggregateItem.max.float") should be(3.0)
+  }
+
+  def createItem(float: Double, int: Int) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { float: $float, int: $int }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
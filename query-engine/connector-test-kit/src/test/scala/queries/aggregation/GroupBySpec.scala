@@ -0,0 +1,160 @@
+package queries.aggregation
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class GroupBySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """model Item {
+      |  id    String @id @default(cuid())
+      |  name  String
+      |  float Float
+      |  int   Int
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Grouping on an empty table" should "return no groups" in {
+    val result = server.query(
+      """{
+        |  groupByItem(by: [name]) {
+        |    name
+        |    _count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsJsValue("data.groupByItem").toString should be("""[]""")
+  }
+
+  "Grouping" should "compute the aggregations per group" in {
+    createItem("a", 1.5, 1)
+    createItem("a", 2.5, 3)
+    createItem("b", 10.0, 10)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [name], orderBy: name_ASC) {
+        |    name
+        |    _count
+        |    _avg { int }
+        |    _sum { float int }
+        |    _min { int }
+        |    _max { float }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsString("data.groupByItem.[0].name") should be("a")
+    result.pathAsLong("data.groupByItem.[0]._count") should be(2)
+    result.pathAsDouble("data.groupByItem.[0]._avg.int") should be(2.0)
+    result.pathAsDouble("data.groupByItem.[0]._sum.float") should be(4.0)
+    result.pathAsLong("data.groupByItem.[0]._sum.int") should be(4)
+    result.pathAsLong("data.groupByItem.[0]._min.int") should be(1)
+    result.pathAsDouble("data.groupByItem.[0]._max.float") should be(2.5)
+
+    result.pathAsString("data.groupByItem.[1].name") should be("b")
+    result.pathAsLong("data.groupByItem.[1]._count") should be(1)
+    result.pathAsLong("data.groupByItem.[1]._sum.int") should be(10)
+  }
+
+  "Grouping" should "obey filters, having and pagination" in {
+    createItem("a", 1.0, 1)
+    createItem("a", 2.0, 2)
+    createItem("b", 3.0, 3)
+    createItem("c", 4.0, 4)
+    createItem("c", 5.0, 5)
+    createItem("d", 6.0, 6)
+
+    val result = server.query(
+      """{
+        |  groupByItem(by: [name], where: { int_gt: 1 }, having: { _count_gte: 1, _sum: { int_lt: 10 } }, orderBy: name_DESC, skip: 1, first: 2) {
+        |    name
+        |    _count
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsJsValue("data.groupByItem").toString should be("""[{"name":"c","_count":2},{"name":"b","_count":1}]""")
+  }
+
+  "Grouping" should "fail if a selected field is not grouped by" in {
+    server.queryThatMustFail(
+      """{
+        |  groupByItem(by: [name]) {
+        |    int
+        |    _count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Field 'int' is selected but not present in the fields to group by."
+    )
+  }
+
+  "Grouping" should "fail if a field used in having is not grouped by" in {
+    server.queryThatMustFail(
+      """{
+        |  groupByItem(by: [name], having: { int_gt: 1 }) {
+        |    name
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      errorCode = 0,
+      errorContains = "Field 'int' used in having must be present in the fields to group by."
+    )
+  }
+
+  "Grouping" should "keep model fields named like aggregations apart from the aggregations" in {
+    val project = SchemaDsl.fromStringV11() {
+      """model Stat {
+        |  id    String @id @default(cuid())
+        |  count Int
+        |  sum   Int
+        |}
+      """.stripMargin
+    }
+    database.setup(project)
+
+    server.query("""mutation { createStat(data: { count: 5, sum: 1 }) { id } }""", project)
+    server.query("""mutation { createStat(data: { count: 5, sum: 2 }) { id } }""", project)
+
+    val result = server.query(
+      """{
+        |  groupByStat(by: [count], having: { count: 5, _sum: { sum_gt: 2 } }) {
+        |    count
+        |    _count
+        |    _sum { sum }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsJsValue("data.groupByStat").toString should be("""[{"count":5,"_count":2,"_sum":{"sum":3}}]""")
+  }
+
+  def createItem(name: String, float: Double, int: Int) = {
+    server.query(
+      s"""mutation {
+         |  createItem(data: { name: "$name", float: $float, int: $int }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
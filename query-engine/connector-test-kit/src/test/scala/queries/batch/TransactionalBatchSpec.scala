@@ -0,0 +1,45 @@
+package queries.batch
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class TransactionalBatchSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Todo {
+      |  id    Int    @id
+      |  title String @unique
+      |}
+    """
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "A transactional batch" should "run all operations in order" in {
+    val queries = Array(
+      """mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }""",
+      """mutation { createOneTodo(data: { id: 2, title: "b" }) { id } }""",
+      """query { findManyTodo(orderBy: id_ASC) { id } }""",
+    )
+
+    server.batch(queries, project, legacy = false, transaction = true).toString should be(
+      """[{"data":{"createOneTodo":{"id":1}}},{"data":{"createOneTodo":{"id":2}}},{"data":{"findManyTodo":[{"id":1},{"id":2}]}}]"""
+    )
+  }
+
+  "A transactional batch" should "roll back all operations if one of them fails" in {
+    val queries = Array(
+      """mutation { createOneTodo(data: { id: 1, title: "a" }) { id } }""",
+      """mutation { createOneTodo(data: { id: 2, title: "a" }) { id } }""",
+    )
+
+    val result = server.batch(queries, project, legacy = false, transaction = true)
+    result.pathAsString("errors.[0].error") should include("Operation 1 of the transactional batch failed")
+    result.pathAsString("errors.[0].user_facing_error.error_code") should be("P2002")
+
+    server.query("""{ findManyTodo { id } }""", project, legacy = false).toString should be("""{"data":{"findManyTodo":[]}}""")
+  }
+}
@@ -0,0 +1,74 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorTag.{MySqlConnectorTag, PostgresConnectorTag, SQLiteConnectorTag}
+import util._
+
+class FullTextSearchSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForConnectors: Set[ConnectorTag] = Set(MySqlConnectorTag, PostgresConnectorTag, SQLiteConnectorTag)
+
+  // Postgres searches without an index, MySQL and SQLite only search fields with a fulltext index on them.
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Post {
+      |  id    Int     @id
+      |  title String
+      |  note  String?
+      |
+      |  @@fulltext([title])
+      |}
+    """
+  }
+
+  // The search query uses the query syntax of the database.
+  lazy val foxAndQuick = connectorTag match {
+    case PostgresConnectorTag => "fox & quick"
+    case MySqlConnectorTag    => "+fox +quick"
+    case _                    => "fox AND quick"
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createPost(1, "The quick brown fox")
+    createPost(2, "A lazy dog")
+    createPost(3, "Fox hunts fox and another fox")
+  }
+
+  "The search filter" should "return records matching the search query" in {
+    query("""where: { title_search: "fox" }, orderBy: id_ASC""") should be("""{"data":{"posts":[{"id":1},{"id":3}]}}""")
+    query(s"""where: { title_search: "$foxAndQuick" }, orderBy: id_ASC""") should be("""{"data":{"posts":[{"id":1}]}}""")
+    query("""where: { title_search: "cat" }, orderBy: id_ASC""") should be("""{"data":{"posts":[]}}""")
+  }
+
+  "The search filter" should "not be offered for fields without a fulltext index" taggedAs (IgnorePostgres) in {
+    server.queryThatMustFail(
+      """{ posts(where: { note_search: "fox" }) { id } }""",
+      project,
+      errorCode = 2009
+    )
+  }
+
+  "Ordering by relevance" should "order the records by how well they match the search query" in {
+    query("""where: { title_search: "fox" }, orderBy: title__relevance_DESC""") should be("""{"data":{"posts":[{"id":3},{"id":1}]}}""")
+    query("""where: { title_search: "fox" }, orderBy: title__relevance_ASC""") should be("""{"data":{"posts":[{"id":1},{"id":3}]}}""")
+  }
+
+  "Ordering by relevance" should "fail without a search filter on the field" in {
+    server.queryThatMustFail(
+      """{ posts(orderBy: title__relevance_DESC) { id } }""",
+      project,
+      errorCode = 0,
+      errorContains = "Ordering by the relevance of 'title' requires a search filter on 'title'."
+    )
+  }
+
+  def query(arguments: String): String = {
+    server.query(s"""{ posts($arguments) { id } }""", project).toString
+  }
+
+  def createPost(id: Int, title: String) = {
+    server.query(s"""mutation { createPost(data: { id: $id, title: "$title" }) { id } }""", project)
+  }
+}
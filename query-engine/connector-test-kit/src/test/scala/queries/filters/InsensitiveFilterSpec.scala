@@ -0,0 +1,88 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.InsensitiveFiltersCapability
+import util._
+
+class InsensitiveFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(InsensitiveFiltersCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id   Int    @id
+      |  name String
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createUser(1, "Alice Smith")
+    createUser(2, "ALICE JONES")
+    createUser(3, "Bob")
+  }
+
+  "String filters with mode insensitive" should "ignore the case of the values" in {
+    query("""{ name_contains: "alice", name_mode: insensitive }""") should be("""{"data":{"users":[{"id":1},{"id":2}]}}""")
+    query("""{ name_starts_with: "BOB", name_mode: insensitive }""") should be("""{"data":{"users":[{"id":3}]}}""")
+    query("""{ name_ends_with: "Jones", name_mode: insensitive }""") should be("""{"data":{"users":[{"id":2}]}}""")
+    query("""{ name: "bob", name_mode: insensitive }""") should be("""{"data":{"users":[{"id":3}]}}""")
+    query("""{ name_in: ["bob", "alice smith"], name_mode: insensitive }""") should be("""{"data":{"users":[{"id":1},{"id":3}]}}""")
+    query("""{ name_not_contains: "ALICE", name_mode: insensitive }""") should be("""{"data":{"users":[{"id":3}]}}""")
+  }
+
+  // The default collations of MySQL compare case insensitively.
+  "String filters with mode default" should "respect the case of the values" taggedAs (IgnoreMySql) in {
+    query("""{ name_starts_with: "ALICE", name_mode: default }""") should be("""{"data":{"users":[{"id":2}]}}""")
+  }
+
+  "String filters with mode insensitive" should "only fold ASCII letters on SQLite" in {
+    createUser(4, "ÄRGER")
+
+    val expected = if (connectorTag == ConnectorTag.SQLiteConnectorTag) {
+      """{"data":{"users":[]}}"""
+    } else {
+      """{"data":{"users":[{"id":4}]}}"""
+    }
+
+    query("""{ name: "ärger", name_mode: insensitive }""") should be(expected)
+    query("""{ name: "ÄRGER", name_mode: insensitive }""") should be("""{"data":{"users":[{"id":4}]}}""")
+  }
+
+  "The mode" should "only apply to the filters of its field" in {
+    query("""{ OR: [{ name_contains: "alice", name_mode: insensitive }, { name: "bob" }] }""") should be(
+      """{"data":{"users":[{"id":1},{"id":2}]}}""")
+  }
+
+  "A field named like a mode" should "keep filtering on the field" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model Screen {
+        |  id           Int    @id
+        |  display      String
+        |  display_mode String
+        |}
+      """
+    }
+    database.setup(project)
+
+    server.query("""mutation { createScreen(data: { id: 1, display: "Main", display_mode: "dark" }) { id } }""", project)
+    server.query("""mutation { createScreen(data: { id: 2, display: "main", display_mode: "light" }) { id } }""", project)
+
+    server.query("""{ screens(where: { display_mode: "dark" }) { id } }""", project).toString should be(
+      """{"data":{"screens":[{"id":1}]}}""")
+    server.query("""{ screens(where: { display_mode: "DARK", display_mode_mode: insensitive }) { id } }""", project).toString should be(
+      """{"data":{"screens":[{"id":1}]}}""")
+  }
+
+  def query(where: String): String = {
+    server.query(s"""{ users(where: $where, orderBy: id_ASC) { id } }""", project).toString
+  }
+
+  def createUser(id: Int, name: String) = {
+    server.query(s"""mutation { createUser(data: { id: $id, name: "$name" }) { id } }""", project)
+  }
+}
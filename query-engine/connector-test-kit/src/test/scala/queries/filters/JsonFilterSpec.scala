@@ -0,0 +1,63 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class JsonFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id   Int   @id
+      |  meta Json?
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createUser(1, """{\"city\":\"Berlin\",\"tags\":[\"a\",\"b\"]}""")
+    createUser(2, """{\"city\":\"Paris\",\"tags\":[\"b\"]}""")
+    createUser(3, """[1,2]""")
+  }
+
+  "Json fields" should "be returned as serialized JSON" in {
+    server.query("""{ user(where: { id: 3 }) { meta } }""", project).toString should be("""{"data":{"user":{"meta":"[1,2]"}}}""")
+  }
+
+  "The equals filters" should "compare the whole JSON document" in {
+    query("""{ meta: "[1,2]" }""") should be("""{"data":{"users":[{"id":3}]}}""")
+    query("""{ meta_not: "[1,2]" }""") should be("""{"data":{"users":[{"id":1},{"id":2}]}}""")
+  }
+
+  "The equals filters with a path" should "compare the value at the path" in {
+    query("""{ meta: "\"Berlin\"", meta_path: ["city"] }""") should be("""{"data":{"users":[{"id":1}]}}""")
+    query("""{ meta: "\"b\"", meta_path: ["tags", "0"] }""") should be("""{"data":{"users":[{"id":2}]}}""")
+  }
+
+  "A path that isn't a list" should "fail" in {
+    server.queryThatMustFail(
+      """{ users(where: { meta: "\"Berlin\"", meta_path: null }) { id } }""",
+      project,
+      errorCode = 0,
+      errorContains = "Filter 'meta_path' must be a list of JSON path segments."
+    )
+  }
+
+  "An invalid JSON value" should "fail with a parse error" in {
+    server.queryThatMustFail(
+      """mutation { createUser(data: { id: 4, meta: "{ invalid" }) { id } }""",
+      project,
+      errorCode = 2009
+    )
+  }
+
+  def query(where: String): String = {
+    server.query(s"""{ users(where: $where, orderBy: id_ASC) { id } }""", project).toString
+  }
+
+  def createUser(id: Int, meta: String) = {
+    server.query(s"""mutation { createUser(data: { id: $id, meta: "$meta" }) { id } }""", project)
+  }
+}
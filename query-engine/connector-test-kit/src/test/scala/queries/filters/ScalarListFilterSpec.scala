@@ -0,0 +1,90 @@
+package queries.filters
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.ScalarListsCapability
+import util._
+
+class ScalarListFilterSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(ScalarListsCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Post {
+      |  id   Int      @id
+      |  tags String[]
+      |  ints Int[]
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createPost(1, Seq("a", "b"), Seq(1, 2, 3))
+    createPost(2, Seq("b", "c"), Seq(3))
+    createPost(3, Seq.empty, Seq.empty)
+  }
+
+  "The has filter" should "return lists containing the value" in {
+    query("""{ tags_has: "b" }""") should be("""{"data":{"posts":[{"id":1},{"id":2}]}}""")
+    query("""{ ints_has: 1 }""") should be("""{"data":{"posts":[{"id":1}]}}""")
+  }
+
+  "The has_some filter" should "return lists containing any of the values" in {
+    query("""{ tags_has_some: ["a", "c"] }""") should be("""{"data":{"posts":[{"id":1},{"id":2}]}}""")
+    query("""{ tags_has_some: ["x"] }""") should be("""{"data":{"posts":[]}}""")
+  }
+
+  "The has_every filter" should "return lists containing all of the values" in {
+    query("""{ ints_has_every: [1, 3] }""") should be("""{"data":{"posts":[{"id":1}]}}""")
+    query("""{ tags_has_every: ["b"] }""") should be("""{"data":{"posts":[{"id":1},{"id":2}]}}""")
+  }
+
+  "The is_empty filter" should "return empty or non-empty lists" in {
+    query("""{ tags_is_empty: true }""") should be("""{"data":{"posts":[{"id":3}]}}""")
+    query("""{ tags_is_empty: false }""") should be("""{"data":{"posts":[{"id":1},{"id":2}]}}""")
+  }
+
+  "The equals filter" should "return lists equal to the given list" in {
+    query("""{ tags: ["b", "c"] }""") should be("""{"data":{"posts":[{"id":2}]}}""")
+    query("""{ ints: [3] }""") should be("""{"data":{"posts":[{"id":2}]}}""")
+  }
+
+  "A field named like a list filter" should "keep filtering on the field" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model Item {
+        |  id       Int      @id
+        |  tags     String[]
+        |  tags_has String
+        |}
+      """
+    }
+    database.setup(project)
+
+    server.query("""mutation { createItem(data: { id: 1, tags: { set: ["a"] }, tags_has: "b" }) { id } }""", project)
+    server.query("""mutation { createItem(data: { id: 2, tags: { set: ["b"] }, tags_has: "a" }) { id } }""", project)
+
+    server.query("""{ items(where: { tags_has: "b" }) { id } }""", project).toString should be("""{"data":{"items":[{"id":1}]}}""")
+    server.query("""{ items(where: { tags_has_some: ["b"] }) { id } }""", project).toString should be("""{"data":{"items":[{"id":2}]}}""")
+  }
+
+  def query(where: String): String = {
+    server.query(s"""{ posts(where: $where, orderBy: id_ASC) { id } }""", project).toString
+  }
+
+  def createPost(id: Int, tags: Seq[String], ints: Seq[Int]) = {
+    val tagList = tags.map(tag => s""""$tag"""").mkString("[", ", ", "]")
+    val intList = ints.mkString("[", ", ", "]")
+
+    server.query(
+      s"""mutation {
+         |  createPost(data: { id: $id, tags: { set: $tagList }, ints: { set: $intList } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,87 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class DistinctSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int    @id
+      |  name  String
+      |  city  String
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       Int    @id
+      |  title    String
+      |  authorId Int
+      |  author   User   @relation(fields: [authorId], references: [id])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createUser(1, "A", "X", Seq((1, "a"), (2, "b"), (3, "a")))
+    createUser(2, "B", "X", Seq((4, "b"), (5, "b")))
+    createUser(3, "A", "Y", Seq.empty)
+    createUser(4, "C", "Y", Seq.empty)
+    createUser(5, "A", "X", Seq.empty)
+  }
+
+  "A distinct query" should "return the first record of each set of distinct records" in {
+    query("distinct: [name]") should be("""{"data":{"users":[{"id":1},{"id":2},{"id":4}]}}""")
+    query("distinct: [name, city]") should be("""{"data":{"users":[{"id":1},{"id":2},{"id":3},{"id":4}]}}""")
+    query("distinct: []") should be("""{"data":{"users":[{"id":1},{"id":2},{"id":3},{"id":4},{"id":5}]}}""")
+  }
+
+  "A distinct query" should "pick the first record by the ordering" in {
+    query("distinct: [name], orderBy: id_DESC") should be("""{"data":{"users":[{"id":5},{"id":4},{"id":2}]}}""")
+    query("distinct: [city], orderBy: [name_DESC, id_DESC]") should be("""{"data":{"users":[{"id":4},{"id":2}]}}""")
+  }
+
+  "A distinct query" should "paginate the distinct records" in {
+    query("distinct: [name], first: 2") should be("""{"data":{"users":[{"id":1},{"id":2}]}}""")
+    query("distinct: [name], skip: 1") should be("""{"data":{"users":[{"id":2},{"id":4}]}}""")
+    query("distinct: [name], last: 1") should be("""{"data":{"users":[{"id":4}]}}""")
+    query("distinct: [name], orderBy: id_DESC, first: 2") should be("""{"data":{"users":[{"id":5},{"id":4}]}}""")
+    query("distinct: [name], orderBy: id_DESC, skip: 1, first: 1") should be("""{"data":{"users":[{"id":4}]}}""")
+  }
+
+  "A distinct query" should "not require the distinct fields to be selected" in {
+    server.query("""{ users(distinct: [city], orderBy: id_ASC) { name } }""", project).toString should be(
+      """{"data":{"users":[{"name":"A"},{"name":"A"}]}}""")
+  }
+
+  "A nested distinct query" should "make the related records of each parent distinct" in {
+    server.query(
+      """{ users(where: { id_in: [1, 2] }, orderBy: id_ASC) { id posts(distinct: [title], orderBy: id_ASC) { id } } }""",
+      project
+    ).toString should be(
+      """{"data":{"users":[{"id":1,"posts":[{"id":1},{"id":2}]},{"id":2,"posts":[{"id":4}]}]}}""")
+  }
+
+  def query(arguments: String): String = {
+    server.query(s"""{ users($arguments) { id } }""", project).toString
+  }
+
+  def createUser(id: Int, name: String, city: String, posts: Seq[(Int, String)]) = {
+    val createPosts = posts.map { case (postId, title) => s"""{ id: $postId, title: "$title" }""" }.mkString("[", ", ", "]")
+
+    server.query(
+      s"""mutation {
+         |  createUser(data: { id: $id, name: "$name", city: "$city", posts: { create: $createPosts } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,110 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class MultiFieldOrderBySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id        Int    @id
+      |  firstName String
+      |  lastName  String
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+    createUser(1, "Bob", "Smith")
+    createUser(2, "Alice", "Smith")
+    createUser(3, "Carol", "Jones")
+    createUser(4, "Alice", "Jones")
+    createUser(5, "Alice", "Smith")
+  }
+
+  "Ordering by multiple fields" should "apply the orderings in the given order" in {
+    val result = server.query(
+      """
+        |{
+        |  users(orderBy: [lastName_ASC, firstName_DESC]) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"users":[{"id":3},{"id":4},{"id":1},{"id":2},{"id":5}]}}""")
+  }
+
+  "Ordering by a single field" should "still be possible without a list" in {
+    val result = server.query(
+      """
+        |{
+        |  users(orderBy: firstName_ASC) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"users":[{"id":2},{"id":4},{"id":5},{"id":1},{"id":3}]}}""")
+  }
+
+  "Cursors" should "respect compound orderings" in {
+    val after = server.query(
+      """
+        |{
+        |  users(orderBy: [lastName_ASC, firstName_DESC], after: { id: 4 }) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    after.toString should be("""{"data":{"users":[{"id":1},{"id":2},{"id":5}]}}""")
+
+    val before = server.query(
+      """
+        |{
+        |  users(orderBy: [lastName_ASC, firstName_DESC], before: { id: 2 }, last: 2) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    before.toString should be("""{"data":{"users":[{"id":4},{"id":1}]}}""")
+  }
+
+  "Ordering by the same field twice" should "fail" in {
+    server.queryThatMustFail(
+      """
+        |{
+        |  users(orderBy: [lastName_ASC, lastName_DESC]) {
+        |    id
+        |  }
+        |}
+      """,
+      project,
+      errorCode = 0,
+      errorContains = "Field 'lastName' is used more than once in orderBy."
+    )
+  }
+
+  def createUser(id: Int, firstName: String, lastName: String) = {
+    server.query(
+      s"""mutation {
+         |  createUser(data: { id: $id, firstName: "$firstName", lastName: "$lastName" }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
@@ -0,0 +1,67 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class NestedPaginationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int    @id
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       Int    @id
+      |  title    String
+      |  authorId Int
+      |  author   User   @relation(fields: [authorId], references: [id])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    createUser(1, Seq((1, "b"), (2, "a"), (3, "b"), (4, "c")))
+    createUser(2, Seq((5, "a"), (6, "a")))
+    createUser(3, Seq.empty)
+  }
+
+  "Nested first and skip" should "apply to the related records of each parent" in {
+    query("first: 2") should be("""{"data":{"users":[{"id":1,"posts":[{"id":1},{"id":2}]},{"id":2,"posts":[{"id":5},{"id":6}]},{"id":3,"posts":[]}]}}""")
+    query("skip: 1") should be("""{"data":{"users":[{"id":1,"posts":[{"id":2},{"id":3},{"id":4}]},{"id":2,"posts":[{"id":6}]},{"id":3,"posts":[]}]}}""")
+    query("skip: 1, first: 2") should be("""{"data":{"users":[{"id":1,"posts":[{"id":2},{"id":3}]},{"id":2,"posts":[{"id":6}]},{"id":3,"posts":[]}]}}""")
+    query("skip: 2, first: 1") should be("""{"data":{"users":[{"id":1,"posts":[{"id":3}]},{"id":2,"posts":[]},{"id":3,"posts":[]}]}}""")
+  }
+
+  "Nested last" should "return the last related records of each parent in order" in {
+    query("last: 2") should be("""{"data":{"users":[{"id":1,"posts":[{"id":3},{"id":4}]},{"id":2,"posts":[{"id":5},{"id":6}]},{"id":3,"posts":[]}]}}""")
+    query("skip: 1, last: 1") should be("""{"data":{"users":[{"id":1,"posts":[{"id":3}]},{"id":2,"posts":[{"id":5}]},{"id":3,"posts":[]}]}}""")
+  }
+
+  "Nested pagination" should "respect the ordering of the related records" in {
+    query("orderBy: title_DESC, first: 2") should be("""{"data":{"users":[{"id":1,"posts":[{"id":4},{"id":1}]},{"id":2,"posts":[{"id":5},{"id":6}]},{"id":3,"posts":[]}]}}""")
+    query("orderBy: [title_ASC, id_DESC], first: 2") should be("""{"data":{"users":[{"id":1,"posts":[{"id":2},{"id":3}]},{"id":2,"posts":[{"id":6},{"id":5}]},{"id":3,"posts":[]}]}}""")
+    query("orderBy: [title_ASC, id_DESC], last: 2") should be("""{"data":{"users":[{"id":1,"posts":[{"id":1},{"id":4}]},{"id":2,"posts":[{"id":6},{"id":5}]},{"id":3,"posts":[]}]}}""")
+  }
+
+  "Nested pagination" should "apply after filtering the related records" in {
+    query("""where: { title_not: "a" }, first: 1""") should be("""{"data":{"users":[{"id":1,"posts":[{"id":1}]},{"id":2,"posts":[]},{"id":3,"posts":[]}]}}""")
+    query("""where: { title_not: "a" }, last: 2""") should be("""{"data":{"users":[{"id":1,"posts":[{"id":3},{"id":4}]},{"id":2,"posts":[]},{"id":3,"posts":[]}]}}""")
+  }
+
+  def query(arguments: String): String = {
+    server.query(s"""{ users(orderBy: id_ASC) { id posts($arguments) { id } } }""", project).toString
+  }
+
+  def createUser(id: Int, posts: Seq[(Int, String)]) = {
+    val createPosts = posts.map { case (postId, title) => s"""{ id: $postId, title: "$title" }""" }.mkString("[", ", ", "]")
+
+    server.query(s"""mutation { createUser(data: { id: $id, posts: { create: $createPosts } }) { id } }""", project)
+  }
+}
@@ -0,0 +1,136 @@
+package queries.orderAndPagination
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class RelationOrderBySpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int    @id
+      |  name  String
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id       Int       @id
+      |  title    String
+      |  author   User      @relation(references: [id])
+      |  comments Comment[]
+      |}
+      |
+      |model Comment {
+      |  id   Int  @id
+      |  post Post @relation(references: [id])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query("""mutation { createUser(data: { id: 1, name: "Zoe" }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: 2, name: "Adam" }) { id } }""", project)
+
+    createPost(1, "A", authorId = 1, commentIds = Seq(1))
+    createPost(2, "B", authorId = 2, commentIds = Seq(2, 3, 4))
+    createPost(3, "C", authorId = 1, commentIds = Seq.empty)
+    createPost(4, "D", authorId = 2, commentIds = Seq(5, 6))
+  }
+
+  "Ordering by a field of a to-one relation" should "order by the related field and break ties by id" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: author__name_ASC) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"id":2},{"id":4},{"id":1},{"id":3}]}}""")
+  }
+
+  "Ordering by the count of a to-many relation" should "order by the number of related records" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: comments__count_DESC) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"id":2},{"id":4},{"id":1},{"id":3}]}}""")
+  }
+
+  "Ordering by relations" should "combine with other orderings and cursors" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: [author__name_DESC, comments__count_ASC], after: { id: 3 }) {
+        |    id
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"posts":[{"id":1},{"id":4},{"id":2}]}}""")
+  }
+
+  "Ordering by relations" should "not clash with fields of the same name and follow the referenced fields" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model Author {
+        |  id    Int    @id
+        |  email String @unique
+        |  name  String
+        |  books Book[]
+        |}
+        |
+        |model Book {
+        |  id          Int    @id
+        |  author_name String
+        |  authorEmail String
+        |  author      Author @relation(fields: [authorEmail], references: [email])
+        |}
+      """
+    }
+    database.setup(project)
+
+    server.query(
+      """mutation { createAuthor(data: { id: 1, email: "z@example.com", name: "Zoe", books: { create: [{ id: 1, author_name: "C" }, { id: 3, author_name: "A" }] } }) { id } }""",
+      project
+    )
+    server.query(
+      """mutation { createAuthor(data: { id: 2, email: "a@example.com", name: "Adam", books: { create: [{ id: 2, author_name: "B" }] } }) { id } }""",
+      project
+    )
+
+    server.query("""{ books(orderBy: author_name_ASC) { id } }""", project).toString should be(
+      """{"data":{"books":[{"id":3},{"id":2},{"id":1}]}}""")
+    server.query("""{ books(orderBy: author__name_ASC) { id } }""", project).toString should be(
+      """{"data":{"books":[{"id":2},{"id":1},{"id":3}]}}""")
+    server.query("""{ authors(orderBy: books__count_ASC) { id } }""", project).toString should be(
+      """{"data":{"authors":[{"id":2},{"id":1}]}}""")
+  }
+
+  def createPost(id: Int, title: String, authorId: Int, commentIds: Seq[Int]) = {
+    val comments = commentIds.map(commentId => s"{ id: $commentId }").mkString("[", ", ", "]")
+
+    server.query(
+      s"""mutation {
+         |  createPost(data: { id: $id, title: "$title", author: { connect: { id: $authorId } }, comments: { create: $comments } }) {
+         |    id
+         |  }
+         |}""".stripMargin,
+      project
+    )
+  }
+}
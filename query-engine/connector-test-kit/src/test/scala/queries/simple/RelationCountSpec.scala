@@ -0,0 +1,119 @@
+package queries.simple
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class RelationCountSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int    @id
+      |  name  String
+      |  posts Post[]
+      |}
+      |
+      |model Post {
+      |  id         Int        @id
+      |  title      String
+      |  author     User       @relation(references: [id])
+      |  comments   Comment[]
+      |  categories Category[]
+      |}
+      |
+      |model Comment {
+      |  id   Int  @id
+      |  post Post @relation(references: [id])
+      |}
+      |
+      |model Category {
+      |  id    Int    @id
+      |  posts Post[]
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+
+    server.query("""mutation { createUser(data: { id: 1, name: "Alice" }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: 2, name: "Bob" }) { id } }""", project)
+
+    server.query(
+      """mutation {
+        |  createPost(data: {
+        |    id: 1, title: "A", author: { connect: { id: 1 } },
+        |    comments: { create: [{ id: 1 }, { id: 2 }] },
+        |    categories: { create: [{ id: 1 }, { id: 2 }, { id: 3 }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project
+    )
+
+    server.query(
+      """mutation {
+        |  createPost(data: {
+        |    id: 2, title: "B", author: { connect: { id: 1 } },
+        |    categories: { connect: [{ id: 1 }] }
+        |  }) { id }
+        |}""".stripMargin,
+      project
+    )
+  }
+
+  "Selecting _count" should "return the number of related records of each relation" in {
+    val result = server.query(
+      """
+        |{
+        |  posts(orderBy: id_ASC) {
+        |    id
+        |    _count {
+        |      comments
+        |      categories
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"posts":[{"id":1,"_count":{"comments":2,"categories":3}},{"id":2,"_count":{"comments":0,"categories":1}}]}}""")
+  }
+
+  "Selecting _count" should "work on nested records and for records without related records" in {
+    val result = server.query(
+      """
+        |{
+        |  users(orderBy: id_ASC) {
+        |    id
+        |    _count { posts }
+        |    posts(orderBy: id_DESC) {
+        |      id
+        |      _count { comments }
+        |    }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be(
+      """{"data":{"users":[{"id":1,"_count":{"posts":2},"posts":[{"id":2,"_count":{"comments":0}},{"id":1,"_count":{"comments":2}}]},{"id":2,"_count":{"posts":0},"posts":[]}]}}""")
+  }
+
+  "Selecting _count" should "be possible on single records" in {
+    val result = server.query(
+      """
+        |{
+        |  category(where: { id: 1 }) {
+        |    _count { posts }
+        |  }
+        |}
+      """,
+      project
+    )
+
+    result.toString should be("""{"data":{"category":{"_count":{"posts":2}}}}""")
+  }
+}
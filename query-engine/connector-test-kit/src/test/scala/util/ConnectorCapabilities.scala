@@ -19,6 +19,9 @@ object ConnectorCapability extends Enumeratum[ConnectorCapability] {
   object JoinRelationLinksCapability         extends ConnectorCapability // the ability to join using relation links
   object RelationLinkListCapability          extends ConnectorCapability // relation links can be stored inline in a node in a list
   object RelationLinkTableCapability         extends ConnectorCapability // relation links are stored in a table
+  object InsensitiveFiltersCapability        extends ConnectorCapability // string filters with mode insensitive
+  object FullTextSearchCapability            extends ConnectorCapability // native full-text search without dedicated indexes
+  object FullTextIndexCapability             extends ConnectorCapability // full-text search on fields with a @@fulltext index
 
   sealed trait IdCapability   extends ConnectorCapability
   object IntIdCapability      extends IdCapability
@@ -39,9 +42,9 @@ object ConnectorCapabilities {
   val empty: ConnectorCapabilities                                     = ConnectorCapabilities(Set.empty[ConnectorCapability])
   def apply(capabilities: ConnectorCapability*): ConnectorCapabilities = ConnectorCapabilities(Set(capabilities: _*))
 
-  lazy val sqlite: ConnectorCapabilities = ConnectorCapabilities(sqlShared)
-  lazy val postgres: ConnectorCapabilities = ConnectorCapabilities(sqlShared + ScalarListsCapability)
-  lazy val mysql: ConnectorCapabilities = ConnectorCapabilities(sqlShared)
+  lazy val sqlite: ConnectorCapabilities = ConnectorCapabilities(sqlShared + FullTextIndexCapability)
+  lazy val postgres: ConnectorCapabilities = ConnectorCapabilities(sqlShared + ScalarListsCapability + FullTextSearchCapability)
+  lazy val mysql: ConnectorCapabilities = ConnectorCapabilities(sqlShared + FullTextIndexCapability)
 
   private lazy val sqlShared: Set[ConnectorCapability] = {
     Set(
@@ -56,7 +59,8 @@ object ConnectorCapabilities {
       RawAccessCapability,
       IdSequenceCapability,
       Prisma2Capability,
-      UuidIdCapability
+      UuidIdCapability,
+      InsensitiveFiltersCapability
     )
   }
 }
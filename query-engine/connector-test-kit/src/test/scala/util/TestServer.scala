@@ -32,9 +32,10 @@ case class TestServer() extends PlayJsonExtensions with LogSupport {
       queries: Array[String],
       project: Project,
       legacy: Boolean = true,
+      transaction: Boolean = false,
   ): JsValue = {
     val result = queryBinaryCLI(
-      request = createMultiQuery(queries),
+      request = createMultiQuery(queries, transaction),
       project = project,
       legacy = legacy,
     )
@@ -67,8 +68,8 @@ case class TestServer() extends PlayJsonExtensions with LogSupport {
     Json.obj("query" -> formattedQuery, "variables" -> Json.obj())
   }
 
-  def createMultiQuery(queries: Array[String]): JsValue = {
-    Json.obj("batch" -> queries.map(createSingleQuery))
+  def createMultiQuery(queries: Array[String], transaction: Boolean = false): JsValue = {
+    Json.obj("batch" -> queries.map(createSingleQuery), "transaction" -> transaction)
   }
 
   def queryBinaryCLI(request: JsValue, project: Project, legacy: Boolean = true) = {
@@ -0,0 +1,60 @@
+package writes.dataTypes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BigIntSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Counter {
+      |  id    Int     @id
+      |  value BigInt?
+      |}
+    """
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "BigInt fields" should "keep values above 2^53 and return them as strings" in {
+    server.query("""mutation { createCounter(data: { id: 1, value: 9007199254740993 }) { id value } }""", project).toString should be(
+      """{"data":{"createCounter":{"id":1,"value":"9007199254740993"}}}""")
+
+    server.query("""mutation { createCounter(data: { id: 2, value: "9223372036854775807" }) { value } }""", project).toString should be(
+      """{"data":{"createCounter":{"value":"9223372036854775807"}}}""")
+  }
+
+  "BigInt fields" should "be filterable" in {
+    server.query("""mutation { createCounter(data: { id: 1, value: 1 }) { id } }""", project)
+    server.query("""mutation { createCounter(data: { id: 2, value: 9007199254740993 }) { id } }""", project)
+    server.query("""mutation { createCounter(data: { id: 3, value: "9007199254740994" }) { id } }""", project)
+
+    query("""{ value: "9007199254740993" }""") should be("""{"data":{"counters":[{"id":2}]}}""")
+    query("""{ value_gt: 9007199254740992 }""") should be("""{"data":{"counters":[{"id":2},{"id":3}]}}""")
+    query("""{ value_in: [1, "9007199254740994"] }""") should be("""{"data":{"counters":[{"id":1},{"id":3}]}}""")
+  }
+
+  "BigInt fields" should "support atomic updates" in {
+    server.query("""mutation { createCounter(data: { id: 1, value: 9007199254740990 }) { id } }""", project)
+
+    server.query("""mutation { updateCounter(where: { id: 1 }, data: { value: { increment: 3 } }) { value } }""", project).toString should be(
+      """{"data":{"updateCounter":{"value":"9007199254740993"}}}""")
+
+    server.query("""mutation { updateCounter(where: { id: 1 }, data: { value: { multiply: 2 } }) { value } }""", project).toString should be(
+      """{"data":{"updateCounter":{"value":"18014398509481986"}}}""")
+  }
+
+  "A value out of the 64 bit range" should "fail with a parse error" in {
+    server.queryThatMustFail(
+      """mutation { createCounter(data: { id: 1, value: "9223372036854775808" }) { id } }""",
+      project,
+      errorCode = 2009
+    )
+  }
+
+  def query(where: String): String = {
+    server.query(s"""{ counters(where: $where, orderBy: id_ASC) { id } }""", project).toString
+  }
+}
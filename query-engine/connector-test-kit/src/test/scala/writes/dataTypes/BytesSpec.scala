@@ -0,0 +1,45 @@
+package writes.dataTypes
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class BytesSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model File {
+      |  id      Int    @id
+      |  content Bytes?
+      |}
+    """
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Bytes fields" should "be written and read as base64 encoded strings" in {
+    server.query("""mutation { createFile(data: { id: 1, content: "3q2+7w==" }) { id content } }""", project).toString should be(
+      """{"data":{"createFile":{"id":1,"content":"3q2+7w=="}}}""")
+
+    server.query("""mutation { updateFile(where: { id: 1 }, data: { content: "AAE=" }) { content } }""", project).toString should be(
+      """{"data":{"updateFile":{"content":"AAE="}}}""")
+  }
+
+  "Bytes fields" should "be filterable by equality" in {
+    server.query("""mutation { createFile(data: { id: 1, content: "3q2+7w==" }) { id } }""", project)
+    server.query("""mutation { createFile(data: { id: 2, content: "AAE=" }) { id } }""", project)
+    server.query("""mutation { createFile(data: { id: 3 }) { id } }""", project)
+
+    server.query("""{ files(where: { content: "AAE=" }) { id } }""", project).toString should be("""{"data":{"files":[{"id":2}]}}""")
+    server.query("""{ files(where: { content: null }) { id } }""", project).toString should be("""{"data":{"files":[{"id":3}]}}""")
+  }
+
+  "Invalid base64" should "fail with a parse error" in {
+    server.queryThatMustFail(
+      """mutation { createFile(data: { id: 1, content: "not base64!" }) { id } }""",
+      project,
+      errorCode = 2009
+    )
+  }
+}
@@ -0,0 +1,54 @@
+package writes.dataTypes
+
+import java.util.UUID
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class UuidSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id       Uuid    @id @default(uuid())
+      |  name     String
+      |  parentId Uuid?
+      |}
+    """
+  }
+
+  override protected def beforeEach(): Unit = {
+    super.beforeEach()
+    database.setup(project)
+  }
+
+  "Uuid ids" should "be generated and returned in their hyphenated form" in {
+    val res = server.query("""mutation { createUser(data: { name: "Alice" }) { id } }""", project)
+    val id  = res.pathAsString("data.createUser.id")
+
+    UUID.fromString(id).toString should be(id)
+
+    server.query(s"""{ user(where: { id: "$id" }) { name } }""", project).toString should be("""{"data":{"user":{"name":"Alice"}}}""")
+  }
+
+  "Uuid fields" should "be written, read and filtered by value" in {
+    server.query(
+      """mutation { createUser(data: { id: "2d5b3c26-5b4a-4d4e-9b3e-7c3c0a4b5f01", name: "Bob", parentId: "11111111-2222-3333-4444-555555555555" }) { id parentId } }""",
+      project
+    ).toString should be(
+      """{"data":{"createUser":{"id":"2d5b3c26-5b4a-4d4e-9b3e-7c3c0a4b5f01","parentId":"11111111-2222-3333-4444-555555555555"}}}""")
+
+    server.query("""{ users(where: { parentId: "11111111-2222-3333-4444-555555555555" }) { name } }""", project).toString should be(
+      """{"data":{"users":[{"name":"Bob"}]}}""")
+
+    server.query("""{ users(where: { parentId_in: ["11111111-2222-3333-4444-555555555555"] }) { name } }""", project).toString should be(
+      """{"data":{"users":[{"name":"Bob"}]}}""")
+  }
+
+  "An invalid UUID" should "fail with a parse error" in {
+    server.queryThatMustFail(
+      """mutation { createUser(data: { name: "Carol", parentId: "not-a-uuid" }) { id } }""",
+      project,
+      errorCode = 2009
+    )
+  }
+}
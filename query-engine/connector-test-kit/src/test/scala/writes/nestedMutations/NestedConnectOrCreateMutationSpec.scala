@@ -0,0 +1,150 @@
+package writes.nestedMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util.ConnectorCapability.JoinRelationLinksCapability
+import util._
+
+class NestedConnectOrCreateMutationSpec extends FlatSpec with Matchers with ApiSpecBase {
+  override def runOnlyForCapabilities = Set(JoinRelationLinksCapability)
+
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id    Int    @id
+      |  name  String @unique
+      |  posts Post[]
+      |  tags  Tag[]  @relation(references: [id])
+      |}
+      |
+      |model Post {
+      |  id       Int    @id
+      |  title    String @unique
+      |  authorId Int
+      |  author   User   @relation(fields: [authorId], references: [id])
+      |}
+      |
+      |model Tag {
+      |  id    Int    @id
+      |  name  String @unique
+      |  users User[] @relation(references: [id])
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "A nested connectOrCreate" should "connect existing and create missing records on a many-to-many relation" in {
+    server.query("""mutation { createTag(data: { id: 1, name: "existing" }) { id } }""", project)
+
+    val result = server.query(
+      """mutation {
+        |  createUser(data: {
+        |    id: 1,
+        |    name: "Alice",
+        |    tags: { connectOrCreate: [
+        |      { where: { name: "existing" }, create: { id: 2, name: "existing" } },
+        |      { where: { name: "new" }, create: { id: 3, name: "new" } }
+        |    ]}
+        |  }) {
+        |    tags(orderBy: id_ASC) { id name }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"createUser":{"tags":[{"id":1,"name":"existing"},{"id":3,"name":"new"}]}}}""")
+  }
+
+  "A nested connectOrCreate" should "connect existing and create missing children with the relation inlined on the child" in {
+    server.query("""mutation { createUser(data: { id: 1, name: "Alice", posts: { create: { id: 1, title: "first" } } }) { id } }""", project)
+    server.query("""mutation { createUser(data: { id: 2, name: "Bob" }) { id } }""", project)
+
+    val result = server.query(
+      """mutation {
+        |  updateUser(where: { id: 2 }, data: {
+        |    posts: { connectOrCreate: [
+        |      { where: { title: "first" }, create: { id: 2, title: "first" } },
+        |      { where: { title: "second" }, create: { id: 3, title: "second" } }
+        |    ]}
+        |  }) {
+        |    posts(orderBy: id_ASC) { id title }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"updateUser":{"posts":[{"id":1,"title":"first"},{"id":3,"title":"second"}]}}}""")
+    server.query("""{ user(where: { id: 1 }) { posts { id } } }""", project).toString should be("""{"data":{"user":{"posts":[]}}}""")
+  }
+
+  "A nested connectOrCreate" should "connect an existing parent with the relation inlined on the parent" in {
+    server.query("""mutation { createUser(data: { id: 1, name: "Alice" }) { id } }""", project)
+
+    val result = server.query(
+      """mutation {
+        |  createPost(data: { id: 1, title: "first", author: { connectOrCreate: { where: { name: "Alice" }, create: { id: 2, name: "Alice" } } } }) {
+        |    author { id }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"createPost":{"author":{"id":1}}}}""")
+  }
+
+  "A nested connectOrCreate" should "create a missing parent with the relation inlined on the parent" in {
+    val result = server.query(
+      """mutation {
+        |  createPost(data: { id: 1, title: "first", author: { connectOrCreate: { where: { name: "Bob" }, create: { id: 2, name: "Bob" } } } }) {
+        |    author { id name }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.toString should be("""{"data":{"createPost":{"author":{"id":2,"name":"Bob"}}}}""")
+    server.query("""{ users { id } }""", project).toString should be("""{"data":{"users":[{"id":2}]}}""")
+  }
+
+  "A nested connectOrCreate" should "connect the created parent even if it doesn't match the where input" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model Author {
+        |  id    String @id @default(cuid())
+        |  name  String
+        |  books Book[]
+        |}
+        |
+        |model Book {
+        |  id       Int    @id
+        |  authorId String
+        |  author   Author @relation(fields: [authorId], references: [id])
+        |}
+      """
+    }
+    database.setup(project)
+
+    val result = server.query(
+      """mutation {
+        |  createBook(data: { id: 1, author: { connectOrCreate: { where: { id: "missing" }, create: { name: "Carol" } } } }) {
+        |    author { id name }
+        |  }
+        |}
+      """.stripMargin,
+      project
+    )
+
+    result.pathAsString("data.createBook.author.name") should be("Carol")
+    result.pathAsString("data.createBook.author.id") should not be ("missing")
+    server.query("""{ authors { id } }""", project).pathAsSeq("data.authors").length should be(1)
+  }
+}
@@ -0,0 +1,96 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class AtomicNumberOpsSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model Counter {
+      |  id    Int    @id
+      |  count Int
+      |  score Float
+      |  opt   Int?
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = {
+    database.truncateProjectTables(project)
+    server.query("""mutation { createCounter(data: { id: 1, count: 10, score: 1.5 }) { id } }""", project)
+  }
+
+  "An update" should "still accept plain values for numeric fields" in {
+    update("""count: 5, score: 2.5""") should be("""{"data":{"updateCounter":{"count":5,"score":2.5,"opt":null}}}""")
+  }
+
+  "The increment and decrement operations" should "add to and subtract from the current value" in {
+    update("""count: { increment: 5 }, score: { decrement: 0.5 }""") should be("""{"data":{"updateCounter":{"count":15,"score":1.0,"opt":null}}}""")
+    update("""count: { decrement: 20 }""") should be("""{"data":{"updateCounter":{"count":-5,"score":1.0,"opt":null}}}""")
+  }
+
+  "The multiply and divide operations" should "multiply and divide the current value" in {
+    update("""count: { multiply: 3 }, score: { divide: 2 }""") should be("""{"data":{"updateCounter":{"count":30,"score":0.75,"opt":null}}}""")
+    update("""score: { divide: 0.25 }""") should be("""{"data":{"updateCounter":{"count":30,"score":3.0,"opt":null}}}""")
+  }
+
+  "The divide operation" should "keep the fraction of floats" in {
+    update("""score: { divide: 4 }""") should be("""{"data":{"updateCounter":{"count":10,"score":0.375,"opt":null}}}""")
+  }
+
+  "The divide operation" should "not be offered for ints, as databases round their quotients differently" in {
+    server.queryThatMustFail(
+      """mutation { updateCounter(where: { id: 1 }, data: { count: { divide: 3 } }) { count } }""",
+      project,
+      errorCode = 2009
+    )
+  }
+
+  "Dividing by zero" should "fail" in {
+    server.queryThatMustFail(
+      """mutation { updateCounter(where: { id: 1 }, data: { score: { divide: 0 } }) { score } }""",
+      project,
+      errorCode = 0,
+      errorContains = "Division by zero is not allowed."
+    )
+
+    server.query("""{ counter(where: { id: 1 }) { score } }""", project).toString should be("""{"data":{"counter":{"score":1.5}}}""")
+  }
+
+  "The set operation" should "set the value, including null" in {
+    update("""count: { set: 3 }, opt: { set: 1 }""") should be("""{"data":{"updateCounter":{"count":3,"score":1.5,"opt":1}}}""")
+    update("""opt: { set: null }""") should be("""{"data":{"updateCounter":{"count":3,"score":1.5,"opt":null}}}""")
+  }
+
+  "Operations on null values" should "result in null" in {
+    update("""opt: { increment: 1 }""") should be("""{"data":{"updateCounter":{"count":10,"score":1.5,"opt":null}}}""")
+  }
+
+  "The atomic operations" should "apply to all records of an updateMany" in {
+    server.query("""mutation { createCounter(data: { id: 2, count: 20, score: 0 }) { id } }""", project)
+
+    val result = server.query("""mutation { updateManyCounters(data: { count: { increment: 1 } }) { count } }""", project)
+    result.pathAsLong("data.updateManyCounters.count") should be(2)
+
+    server.query("""{ counters(orderBy: id_ASC) { count } }""", project).toString should be(
+      """{"data":{"counters":[{"count":11},{"count":21}]}}""")
+  }
+
+  "Multiple operations on one field" should "fail" in {
+    server.queryThatMustFail(
+      """mutation { updateCounter(where: { id: 1 }, data: { count: { increment: 1, decrement: 1 } }) { count } }""",
+      project,
+      errorCode = 2009,
+      errorContains = "Expected object to have exactly 1 key-value pairs"
+    )
+  }
+
+  def update(data: String): String = {
+    server.query(s"""mutation { updateCounter(where: { id: 1 }, data: { $data }) { count score opt } }""", project).toString
+  }
+}
@@ -0,0 +1,92 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class CreateManySpec extends FlatSpec with Matchers with ApiSpecBase {
+
+  val project = ProjectDsl.fromString {
+    """model Todo {
+      |  id    Int     @id
+      |  title String  @unique
+      |  done  Boolean @default(false)
+      |}
+    """.stripMargin
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "The create many Mutation" should "create all records and return their count" in {
+    val result = server.query(
+      """mutation {
+        |  createManyTodo(data: [
+        |    { id: 1, title: "a" },
+        |    { id: 2, title: "b", done: true },
+        |    { id: 3, title: "c" }
+        |  ]) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+    result.pathAsLong("data.createManyTodo.count") should equal(3)
+
+    server.query("""{ findManyTodo(orderBy: id_ASC) { id title done } }""", project, legacy = false).toString should be(
+      """{"data":{"findManyTodo":[{"id":1,"title":"a","done":false},{"id":2,"title":"b","done":true},{"id":3,"title":"c","done":false}]}}"""
+    )
+  }
+
+  "The create many Mutation" should "create many records in chunks" in {
+    val todos = (1 to 2000).map(id => s"""{ id: $id, title: "title$id" }""").mkString("[", ", ", "]")
+
+    val result = server.query(s"""mutation { createManyTodo(data: $todos) { count } }""", project, legacy = false)
+    result.pathAsLong("data.createManyTodo.count") should equal(2000)
+  }
+
+  "The create many Mutation" should "fail on duplicates and create nothing" in {
+    createTodo(1, "a")
+
+    server.queryThatMustFail(
+      """mutation { createManyTodo(data: [{ id: 2, title: "b" }, { id: 3, title: "a" }]) { count } }""",
+      project,
+      errorCode = 2002,
+      legacy = false
+    )
+
+    todoCountShouldBe(1)
+  }
+
+  "The create many Mutation" should "skip duplicates with skipDuplicates" in {
+    createTodo(1, "a")
+
+    val result = server.query(
+      """mutation {
+        |  createManyTodo(data: [{ id: 1, title: "x" }, { id: 2, title: "b" }, { id: 3, title: "a" }], skipDuplicates: true) {
+        |    count
+        |  }
+        |}
+      """.stripMargin,
+      project,
+      legacy = false
+    )
+    result.pathAsLong("data.createManyTodo.count") should equal(1)
+
+    todoCountShouldBe(2)
+  }
+
+  def createTodo(id: Int, title: String) = {
+    server.query(s"""mutation { createOneTodo(data: { id: $id, title: "$title" }) { id } }""", project, legacy = false)
+  }
+
+  def todoCountShouldBe(int: Int) = {
+    val result = server.query("{ findManyTodo { id } }", project, legacy = false)
+    result.pathAsJsArray("data.findManyTodo").value.size should be(int)
+  }
+}
@@ -0,0 +1,148 @@
+package writes.topLevelMutations
+
+import org.scalatest.{FlatSpec, Matchers}
+import util._
+
+class NativeUpsertSpec extends FlatSpec with Matchers with ApiSpecBase {
+  val project = SchemaDsl.fromStringV11() {
+    """
+      |model User {
+      |  id     Int    @id @default(autoincrement())
+      |  email  String @unique
+      |  name   String
+      |  visits Int
+      |}
+    """
+  }
+
+  override protected def beforeAll(): Unit = {
+    super.beforeAll()
+    database.setup(project)
+  }
+
+  override def beforeEach(): Unit = database.truncateProjectTables(project)
+
+  "An upsert on a unique field" should "create the record if it doesn't exist" in {
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """name: "B"""") should be(
+      """{"data":{"upsertUser":{"email":"a@test.com","name":"A","visits":1}}}""")
+
+    countUsers() should be(1)
+  }
+
+  "An upsert on a unique field" should "update the record if it exists" in {
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """name: "B"""")
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """name: "B"""") should be(
+      """{"data":{"upsertUser":{"email":"a@test.com","name":"B","visits":1}}}""")
+
+    countUsers() should be(1)
+  }
+
+  "An upsert on a unique field" should "support atomic operations in the update" in {
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """visits: { increment: 1 }""")
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """visits: { increment: 1 }""")
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """visits: { increment: 1 }""") should be(
+      """{"data":{"upsertUser":{"email":"a@test.com","name":"A","visits":3}}}""")
+  }
+
+  "An upsert on a unique field" should "apply atomic operations to the stored value instead of the create input" in {
+    upsert("a@test.com", create = """name: "A", visits: 3""", update = "")
+    upsert("a@test.com", create = """name: "B", visits: 10""", update = """visits: { multiply: 2 }""") should be(
+      """{"data":{"upsertUser":{"email":"a@test.com","name":"A","visits":6}}}""")
+  }
+
+  "An upsert on a unique field" should "keep the record if the update is empty" in {
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = "")
+    upsert("a@test.com", create = """name: "B", visits: 2""", update = "") should be(
+      """{"data":{"upsertUser":{"email":"a@test.com","name":"A","visits":1}}}""")
+
+    countUsers() should be(1)
+  }
+
+  "An upsert that changes the unique field" should "update the record" in {
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = "")
+    upsert("a@test.com", create = """name: "A", visits: 1""", update = """email: "b@test.com"""") should be(
+      """{"data":{"upsertUser":{"email":"b@test.com","name":"A","visits":1}}}""")
+
+    countUsers() should be(1)
+  }
+
+  "An upsert on a model with a generated id" should "create the record once and update it afterwards" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model Account {
+        |  id     String @id @default(cuid())
+        |  email  String @unique
+        |  visits Int
+        |}
+      """
+    }
+    database.setup(project)
+
+    val upsertAccount =
+      """mutation {
+        |  upsertAccount(where: { email: "a@test.com" }, create: { email: "a@test.com", visits: 1 }, update: { visits: { increment: 1 } }) {
+        |    id
+        |    visits
+        |  }
+        |}""".stripMargin
+
+    val created = server.query(upsertAccount, project)
+    val updated = server.query(upsertAccount, project)
+
+    updated.pathAsString("data.upsertAccount.id") should be(created.pathAsString("data.upsertAccount.id"))
+    updated.pathAsLong("data.upsertAccount.visits") should be(2)
+    server.query("""{ accounts { id } }""", project).pathAsSeq("data.accounts").length should be(1)
+  }
+
+  "An upsert that sets an existing id of another record" should "fail instead of updating that record" in {
+    val project = SchemaDsl.fromStringV11() {
+      """
+        |model Account {
+        |  id     String @id @default(cuid())
+        |  email  String @unique
+        |  visits Int
+        |}
+      """
+    }
+    database.setup(project)
+
+    server.query("""mutation { createAccount(data: { id: "acc1", email: "a@test.com", visits: 1 }) { id } }""", project)
+
+    server.queryThatMustFail(
+      """mutation {
+        |  upsertAccount(where: { email: "b@test.com" }, create: { id: "acc1", email: "b@test.com", visits: 1 }, update: { visits: { increment: 1 } }) {
+        |    id
+        |  }
+        |}""".stripMargin,
+      project,
+      errorCode = 3010,
+      errorContains = "A unique constraint would be violated on Account."
+    )
+
+    server.query("""{ account(where: { id: "acc1" }) { email visits } }""", project).toString should be(
+      """{"data":{"account":{"email":"a@test.com","visits":1}}}""")
+  }
+
+  def upsert(email: String, create: String, update: String): String = {
+    server
+      .query(
+        s"""mutation {
+           |  upsertUser(
+           |    where: { email: "$email" }
+           |    create: { email: "$email", $create }
+           |    update: { $update }
+           |  ) {
+           |    email
+           |    name
+           |    visits
+           |  }
+           |}""".stripMargin,
+        project
+      )
+      .toString
+  }
+
+  def countUsers(): Int = {
+    server.query("""{ users { id } }""", project).pathAsSeq("data.users").length
+  }
+}
@@ -0,0 +1,39 @@
+use prisma_models::{PrismaValue, ScalarFieldRef};
+
+/// All aggregation results of a single group of records.
+pub type AggregationRow = Vec<AggregationResult>;
+
+/// A single aggregation requested by a query, e.g. `count` or `avg { fieldA fieldB }`.
+#[derive(Debug, Clone)]
+pub enum Aggregator {
+    Count,
+    Average(Vec<ScalarFieldRef>),
+    Sum(Vec<ScalarFieldRef>),
+    Min(Vec<ScalarFieldRef>),
+    Max(Vec<ScalarFieldRef>),
+}
+
+/// The result of a single aggregation. Field based aggregations yield one result per aggregated field.
+/// `Field` holds the value of a field the records have been grouped by.
+#[derive(Debug, Clone)]
+pub enum AggregationResult {
+    Field(ScalarFieldRef, PrismaValue),
+    Count(usize),
+    Average(ScalarFieldRef, PrismaValue),
+    Sum(ScalarFieldRef, PrismaValue),
+    Min(ScalarFieldRef, PrismaValue),
+    Max(ScalarFieldRef, PrismaValue),
+}
+
+impl Aggregator {
+    /// All scalar fields this aggregator operates on. Empty for `count`.
+    pub fn fields(&self) -> &[ScalarFieldRef] {
+        match self {
+            Self::Count => &[],
+            Self::Average(fields) => fields,
+            Self::Sum(fields) => fields,
+            Self::Min(fields) => fields,
+            Self::Max(fields) => fields,
+        }
+    }
+}
@@ -58,6 +58,10 @@ pub trait ScalarCompare {
     fn greater_than_or_equals<T>(&self, val: T) -> Filter
     where
         T: Into<PrismaValue>;
+
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
 
 /// Comparison methods for relational fields.
@@ -87,13 +91,17 @@ pub trait ScalarListCompare {
     where
         T: Into<PrismaValue>;
 
-    fn contains_every_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
-    fn contains_some_element<T>(&self, filter: Vec<T>) -> Filter
+    fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>;
+        T: Into<PrismaValue>;
 
     fn contains_none(&self) -> Filter;
+
+    fn equals_list<T>(&self, values: Vec<T>) -> Filter
+    where
+        T: Into<PrismaValue>;
 }
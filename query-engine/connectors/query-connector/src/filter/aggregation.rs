@@ -0,0 +1,25 @@
+use super::{Filter, ScalarCondition};
+use prisma_models::DataSourceFieldRef;
+
+/// Filters records groups by the result of an aggregation, e.g. `HAVING AVG(field) > 5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AggregationFilter {
+    pub function: AggregationFunction,
+    pub condition: ScalarCondition,
+}
+
+/// The aggregation to filter on. All aggregations but counting all records of a group require the aggregated field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AggregationFunction {
+    Count,
+    Average(DataSourceFieldRef),
+    Sum(DataSourceFieldRef),
+    Min(DataSourceFieldRef),
+    Max(DataSourceFieldRef),
+}
+
+impl From<AggregationFilter> for Filter {
+    fn from(af: AggregationFilter) -> Self {
+        Filter::Aggregation(af)
+    }
+}
@@ -22,9 +22,11 @@ pub enum ScalarListCondition {
 
     /// List is empty
     ContainsNone,
+
+    /// List equals the given values, in order
+    Equals(Vec<PrismaValue>),
 }
 
-#[allow(warnings)]
 impl ScalarListCompare for Arc<ScalarField> {
     fn contains_element<T>(&self, value: T) -> Filter
     where
@@ -38,24 +40,22 @@ impl ScalarListCompare for Arc<ScalarField> {
 
     fn contains_every_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>,
+        T: Into<PrismaValue>,
     {
-        // Filter::from(ScalarListFilter {
-        //     field: Arc::clone(self),
-        //     condition: ScalarListCondition::ContainsEvery(values.into()),
-        // })
-        unimplemented!()
+        Filter::from(ScalarListFilter {
+            field: Arc::clone(self),
+            condition: ScalarListCondition::ContainsEvery(values.into_iter().map(Into::into).collect()),
+        })
     }
 
     fn contains_some_element<T>(&self, values: Vec<T>) -> Filter
     where
-        T: Into<Filter>,
+        T: Into<PrismaValue>,
     {
-        // Filter::from(ScalarListFilter {
-        //     field: Arc::clone(self),
-        //     condition: ScalarListCondition::ContainsSome(values.into()),
-        // })
-        unimplemented!()
+        Filter::from(ScalarListFilter {
+            field: Arc::clone(self),
+            condition: ScalarListCondition::ContainsSome(values.into_iter().map(Into::into).collect()),
+        })
     }
 
     fn contains_none(&self) -> Filter {
@@ -64,4 +64,14 @@ impl ScalarListCompare for Arc<ScalarField> {
             condition: ScalarListCondition::ContainsNone,
         })
     }
+
+    fn equals_list<T>(&self, values: Vec<T>) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarListFilter {
+            field: Arc::clone(self),
+            condition: ScalarListCondition::Equals(values.into_iter().map(Into::into).collect()),
+        })
+    }
 }
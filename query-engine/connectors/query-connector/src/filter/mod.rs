@@ -4,6 +4,7 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod aggregation;
 mod id_filter;
 mod list;
 mod relation;
@@ -12,6 +13,7 @@ mod scalar;
 use prisma_models::prelude::*;
 use prisma_models::{dml, DataSourceField};
 
+pub use aggregation::*;
 pub use id_filter::*;
 pub use list::*;
 pub use relation::*;
@@ -26,6 +28,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    Aggregation(AggregationFilter),
     NodeSubscription,
     BoolFilter(bool),
     Empty,
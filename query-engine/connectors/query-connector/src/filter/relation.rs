@@ -137,6 +137,7 @@ mod tests {
                     Filter::Scalar(ScalarFilter {
                         field: scalar_field,
                         condition: ScalarCondition::Equals(scalar_val),
+                        ..
                     }) => {
                         assert_eq!(String::from("name"), scalar_field.name);
                         assert_eq!(PrismaValue::from("Blog"), scalar_val);
@@ -177,6 +178,7 @@ mod tests {
                     Filter::Scalar(ScalarFilter {
                         field: scalar_field,
                         condition: ScalarCondition::Equals(scalar_val),
+                        ..
                     }) => {
                         assert_eq!(String::from("name"), scalar_field.name);
                         assert_eq!(PrismaValue::from("Blog"), scalar_val);
@@ -217,6 +219,7 @@ mod tests {
                     Filter::Scalar(ScalarFilter {
                         field: scalar_field,
                         condition: ScalarCondition::Equals(scalar_val),
+                        ..
                     }) => {
                         assert_eq!(String::from("name"), scalar_field.name);
                         assert_eq!(PrismaValue::from("Blog"), scalar_val);
@@ -257,6 +260,7 @@ mod tests {
                     Filter::Scalar(ScalarFilter {
                         field: scalar_field,
                         condition: ScalarCondition::Equals(scalar_val),
+                        ..
                     }) => {
                         assert_eq!(String::from("name"), scalar_field.name);
                         assert_eq!(PrismaValue::from("Blog"), scalar_val);
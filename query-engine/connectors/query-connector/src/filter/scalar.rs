@@ -7,6 +7,34 @@ use std::sync::Arc;
 pub struct ScalarFilter {
     pub field: DataSourceFieldRef,
     pub condition: ScalarCondition,
+    pub mode: QueryMode,
+}
+
+impl ScalarFilter {
+    /// Compares string values regardless of their case.
+    pub fn insensitive(mut self) -> Self {
+        self.mode = QueryMode::Insensitive;
+        self
+    }
+
+    /// Compares the value at the given path of a JSON document instead of the whole document.
+    pub fn at_json_path(mut self, path: Vec<String>) -> Self {
+        self.condition = ScalarCondition::JsonPath(path, Box::new(self.condition));
+        self
+    }
+}
+
+/// Comparison mode of string values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryMode {
+    Default,
+    Insensitive,
+}
+
+impl Default for QueryMode {
+    fn default() -> Self {
+        QueryMode::Default
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -25,6 +53,10 @@ pub enum ScalarCondition {
     GreaterThanOrEquals(PrismaValue),
     In(PrismaListValue),
     NotIn(PrismaListValue),
+    /// Full-text search of the value in the field, using the native text search of the database.
+    Search(PrismaValue),
+    /// The condition applies to the value at the path (object keys or array indices) of a JSON field.
+    JsonPath(Vec<String>, Box<ScalarCondition>),
 }
 
 impl ScalarCompare for DataSourceFieldRef {
@@ -36,6 +68,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::In(values.into_iter().map(|i| i.into()).collect()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -47,6 +80,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotIn(values.into_iter().map(|i| i.into()).collect()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -58,6 +92,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::Equals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -69,6 +104,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotEquals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -80,6 +116,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::Contains(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -91,6 +128,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotContains(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -102,6 +140,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::StartsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -113,6 +152,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotStartsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -124,6 +164,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::EndsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -135,6 +176,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::NotEndsWith(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -146,6 +188,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::LessThan(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -157,6 +200,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::LessThanOrEquals(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -168,6 +212,7 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::GreaterThan(val.into()),
+            mode: QueryMode::Default,
         })
     }
 
@@ -179,6 +224,19 @@ impl ScalarCompare for DataSourceFieldRef {
         Filter::from(ScalarFilter {
             field: Arc::clone(self),
             condition: ScalarCondition::GreaterThanOrEquals(val.into()),
+            mode: QueryMode::Default,
+        })
+    }
+
+    /// Field matches the given full-text search query.
+    fn search<T>(&self, val: T) -> Filter
+    where
+        T: Into<PrismaValue>,
+    {
+        Filter::from(ScalarFilter {
+            field: Arc::clone(self),
+            condition: ScalarCondition::Search(val.into()),
+            mode: QueryMode::Default,
         })
     }
 }
@@ -205,6 +263,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::Equals(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("qwert"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -230,6 +289,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::NotEquals(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("qwert"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -255,6 +315,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::Contains(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("qwert"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -280,6 +341,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::NotContains(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("qwert"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -305,6 +367,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::StartsWith(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("qwert"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -330,6 +393,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::NotStartsWith(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("qwert"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -355,6 +419,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::EndsWith(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("musti"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -380,6 +445,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::NotEndsWith(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from("naukio"), val);
                 assert_eq!(String::from("name"), field.name);
@@ -405,6 +471,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::LessThan(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from(10), val);
                 assert_eq!(String::from("id"), field.name);
@@ -430,6 +497,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::LessThanOrEquals(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from(10), val);
                 assert_eq!(String::from("id"), field.name);
@@ -455,6 +523,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::GreaterThan(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from(10), val);
                 assert_eq!(String::from("id"), field.name);
@@ -480,6 +549,7 @@ mod tests {
             Filter::Scalar(ScalarFilter {
                 field,
                 condition: ScalarCondition::GreaterThanOrEquals(val),
+                ..
             }) => {
                 assert_eq!(PrismaValue::from(10), val);
                 assert_eq!(String::from("id"), field.name);
@@ -487,4 +557,30 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[test]
+    fn search() {
+        let schema = test_data_model();
+        let model = schema.find_model("User").unwrap();
+
+        let field = model
+            .fields()
+            .find_from_scalar("name")
+            .unwrap()
+            .data_source_field()
+            .clone();
+        let filter = field.search("fox & dog");
+
+        match filter {
+            Filter::Scalar(ScalarFilter {
+                field,
+                condition: ScalarCondition::Search(val),
+                ..
+            }) => {
+                assert_eq!(PrismaValue::from("fox & dog"), val);
+                assert_eq!(String::from("name"), field.name);
+            }
+            _ => unreachable!(),
+        }
+    }
 }
@@ -26,6 +26,13 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn supports_distinct(&self, model: &ModelRef, query_arguments: &QueryArguments) -> bool {
+        match self {
+            Self::Connection(c) => c.supports_distinct(model, query_arguments),
+            Self::Transaction(tx) => tx.supports_distinct(model, query_arguments),
+        }
+    }
+
     fn get_related_m2m_record_ids<'a>(
         &'a self,
         from_field: &'a RelationFieldRef,
@@ -37,13 +44,68 @@ impl<'conn, 'tx> ReadOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    // This will eventually become a more generic `aggregate`
+    fn get_related_records<'a>(
+        &'a self,
+        from_field: &'a RelationFieldRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'a SelectedFields,
+    ) -> crate::IO<'a, ManyRecords> {
+        match self {
+            Self::Connection(c) => c.get_related_records(from_field, query_arguments, selected_fields),
+            Self::Transaction(tx) => tx.get_related_records(from_field, query_arguments, selected_fields),
+        }
+    }
+
+    fn supports_related_pagination(&self, from_field: &RelationFieldRef, query_arguments: &QueryArguments) -> bool {
+        match self {
+            Self::Connection(c) => c.supports_related_pagination(from_field, query_arguments),
+            Self::Transaction(tx) => tx.supports_related_pagination(from_field, query_arguments),
+        }
+    }
+
+    fn count_related_records<'a>(
+        &'a self,
+        from_field: &'a RelationFieldRef,
+        from_record_links: &'a [RecordProjection],
+    ) -> crate::IO<'a, Vec<(RecordProjection, usize)>> {
+        match self {
+            Self::Connection(c) => c.count_related_records(from_field, from_record_links),
+            Self::Transaction(tx) => tx.count_related_records(from_field, from_record_links),
+        }
+    }
+
     fn count_by_model<'a>(&'a self, model: &'a ModelRef, query_arguments: QueryArguments) -> crate::IO<'a, usize> {
         match self {
             Self::Connection(c) => c.count_by_model(model, query_arguments),
             Self::Transaction(tx) => tx.count_by_model(model, query_arguments),
         }
     }
+
+    fn aggregate_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<AggregationResult>> {
+        match self {
+            Self::Connection(c) => c.aggregate_records(model, aggregators, query_arguments),
+            Self::Transaction(tx) => tx.aggregate_records(model, aggregators, query_arguments),
+        }
+    }
+
+    fn group_by_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> crate::IO<'a, Vec<AggregationRow>> {
+        match self {
+            Self::Connection(c) => c.group_by_records(model, group_by, aggregators, query_arguments, having),
+            Self::Transaction(tx) => tx.group_by_records(model, group_by, aggregators, query_arguments, having),
+        }
+    }
 }
 
 impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
@@ -54,6 +116,18 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn create_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::IO<usize> {
+        match self {
+            Self::Connection(c) => c.create_records(model, args, skip_duplicates),
+            Self::Transaction(tx) => tx.create_records(model, args, skip_duplicates),
+        }
+    }
+
     fn update_records<'a>(
         &'a self,
         model: &'a ModelRef,
@@ -66,6 +140,19 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
+    fn native_upsert_record<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        conflict_field: ScalarFieldRef,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> crate::IO<RecordProjection> {
+        match self {
+            Self::Connection(c) => c.native_upsert_record(model, conflict_field, create, update),
+            Self::Transaction(tx) => tx.native_upsert_record(model, conflict_field, create, update),
+        }
+    }
+
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> crate::IO<usize> {
         match self {
             Self::Connection(c) => c.delete_records(model, where_),
@@ -97,10 +184,17 @@ impl<'conn, 'tx> WriteOperations for ConnectionLike<'conn, 'tx> {
         }
     }
 
-    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<serde_json::Value> {
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<usize> {
         match self {
             Self::Connection(c) => c.execute_raw(query, parameters),
             Self::Transaction(tx) => tx.execute_raw(query, parameters),
         }
     }
+
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<serde_json::Value> {
+        match self {
+            Self::Connection(c) => c.query_raw(query, parameters),
+            Self::Transaction(tx) => tx.query_raw(query, parameters),
+        }
+    }
 }
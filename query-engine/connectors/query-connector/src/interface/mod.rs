@@ -2,9 +2,14 @@ mod dispatch;
 
 pub use dispatch::*;
 
-use crate::{Filter, QueryArguments, WriteArgs};
+use crate::{AggregationResult, AggregationRow, Aggregator, Filter, QueryArguments, WriteArgs};
+use futures::stream::BoxStream;
 use prisma_models::*;
 use prisma_value::PrismaValue;
+use serde::Serialize;
+
+/// Stream of record chunks, see `Connection::get_many_records_stream`.
+pub type RecordStream<'a> = BoxStream<'a, crate::Result<ManyRecords>>;
 
 pub trait Connector {
     fn get_connection<'a>(&'a self) -> crate::IO<Box<dyn Connection + 'a>>;
@@ -12,11 +17,41 @@ pub trait Connector {
 
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     fn start_transaction<'a>(&'a self) -> crate::IO<Box<dyn Transaction + 'a>>;
+
+    /// Starts a transaction that records all statements it runs, see `Transaction::recorded_statements`.
+    /// With `explain`, the query plan of each statement is recorded as well.
+    fn start_recording_transaction<'a>(&'a self, explain: bool) -> crate::IO<Box<dyn Transaction + 'a>>;
+
+    /// Streams the records of the model matching the query arguments in chunks of at most `chunk_size` records,
+    /// so that large results never have to be held in memory at once. Each chunk is fetched only when polled.
+    /// `first` and `skip` apply to the whole stream, `last` is not supported.
+    fn get_many_records_stream<'a>(
+        &'a self,
+        model: ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: SelectedFields,
+        chunk_size: usize,
+    ) -> RecordStream<'a>;
 }
 
 pub trait Transaction<'a>: ReadOperations + WriteOperations + Send + Sync {
     fn commit<'b>(&'b self) -> crate::IO<'b, ()>;
     fn rollback<'b>(&'b self) -> crate::IO<'b, ()>;
+
+    /// The statements run so far, in order. Always empty if the transaction was not started with
+    /// `Connection::start_recording_transaction`.
+    fn recorded_statements(&self) -> Vec<RecordedStatement>;
+}
+
+/// A statement run by a recording transaction.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordedStatement {
+    pub sql: String,
+    pub parameters: Vec<serde_json::Value>,
+
+    /// The query plan of the statement as returned by the database, if requested and the statement can be explained.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<serde_json::Value>,
 }
 
 pub enum ConnectionLike<'conn, 'tx>
@@ -42,19 +77,74 @@ pub trait ReadOperations {
         selected_fields: &'a SelectedFields,
     ) -> crate::IO<'a, ManyRecords>;
 
+    /// Whether `get_many_records` can apply the `distinct` fields of the query arguments.
+    /// If not, the caller has to remove duplicates in memory, before paginating the records.
+    fn supports_distinct(&self, model: &ModelRef, query_arguments: &QueryArguments) -> bool;
+
     fn get_related_m2m_record_ids<'a>(
         &'a self,
         from_field: &'a RelationFieldRef,
         from_record_ids: &'a [RecordProjection],
     ) -> crate::IO<'a, Vec<(RecordProjection, RecordProjection)>>;
 
-    // This will eventually become a more generic `aggregate`
+    /// Reads the records related via the (to-many) relation field like `get_many_records`, but applies `first`, `last`
+    /// and `skip` of the query arguments to the related records of each parent separately. The filter of the query
+    /// arguments has to restrict the records to the parents. The records are grouped by parent.
+    fn get_related_records<'a>(
+        &'a self,
+        from_field: &'a RelationFieldRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'a SelectedFields,
+    ) -> crate::IO<'a, ManyRecords>;
+
+    /// Whether `get_related_records` can paginate the related records of the relation field with the query arguments.
+    /// If not, the caller has to read all related records with `get_many_records` and paginate them in memory.
+    fn supports_related_pagination(&self, from_field: &RelationFieldRef, query_arguments: &QueryArguments) -> bool;
+
+    /// Counts the records related to the given parents via the (to-many) relation field, in a single query.
+    /// The parents are identified by the values of the linking fields of the relation field.
+    /// Returns the linking values of each parent with at least one related record, paired with the count.
+    fn count_related_records<'a>(
+        &'a self,
+        from_field: &'a RelationFieldRef,
+        from_record_links: &'a [RecordProjection],
+    ) -> crate::IO<'a, Vec<(RecordProjection, usize)>>;
+
     fn count_by_model<'a>(&'a self, model: &'a ModelRef, query_arguments: QueryArguments) -> crate::IO<'a, usize>;
+
+    /// Aggregates the records of the model matching the query arguments.
+    /// Returns one result per requested aggregation and aggregated field, in request order.
+    fn aggregate_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> crate::IO<'a, Vec<AggregationResult>>;
+
+    /// Groups the records of the model matching the query arguments by the given fields and aggregates each group.
+    /// Groups are filtered afterwards by the `having` filter, which may contain aggregation filters.
+    fn group_by_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> crate::IO<'a, Vec<AggregationRow>>;
 }
 
 pub trait WriteOperations {
     fn create_record<'a>(&'a self, model: &'a ModelRef, args: WriteArgs) -> crate::IO<RecordProjection>;
 
+    /// Inserts the records in bulk, resulting in the number of inserted records.
+    /// With `skip_duplicates`, records violating a unique constraint are skipped instead of failing the insert.
+    fn create_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> crate::IO<usize>;
+
     fn update_records<'a>(
         &'a self,
         model: &'a ModelRef,
@@ -62,6 +152,17 @@ pub trait WriteOperations {
         args: WriteArgs,
     ) -> crate::IO<Vec<RecordProjection>>;
 
+    /// Inserts the record with the `create` args or, if a record with the same value for the unique `conflict_field`
+    /// already exists, updates that record with the `update` args, in a single statement.
+    /// Results in the identifier of the inserted or updated record.
+    fn native_upsert_record<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        conflict_field: ScalarFieldRef,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> crate::IO<RecordProjection>;
+
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> crate::IO<usize>;
 
     // We plan to remove the methods below in the future. We want emulate them with the ones above. Those should suffice.
@@ -80,5 +181,10 @@ pub trait WriteOperations {
         child_ids: &'a [RecordProjection],
     ) -> crate::IO<()>;
 
-    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<serde_json::Value>;
+    /// Executes the raw query, returning the number of affected rows.
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<usize>;
+
+    /// Executes the raw query, returning the resulting rows as JSON objects of column name to value.
+    /// Values without a lossless JSON representation are tagged with their type.
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> crate::IO<serde_json::Value>;
 }
@@ -15,7 +15,8 @@ pub struct QueryArguments {
     pub first: Option<i64>,
     pub last: Option<i64>,
     pub filter: Option<Filter>,
-    pub order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
+    pub distinct: Option<Vec<ScalarFieldRef>>,
 }
 
 impl QueryArguments {
@@ -29,14 +30,14 @@ impl QueryArguments {
             || self.first.is_some()
             || self.before.is_some()
             || self.last.is_some()
-            || self.order_by.is_some()
+            || !self.order_by.is_empty()
     }
 
     pub fn ordering_directions(&self) -> OrderDirections {
         OrderDirections {
             needs_to_be_reverse_order: self.needs_reversed_order(),
             needs_implicit_id_ordering: self.needs_implicit_ordering(),
-            primary_order_by: self.order_by.clone(),
+            order_by: self.order_by.clone(),
         }
     }
 
@@ -81,5 +82,5 @@ where
 pub struct OrderDirections {
     pub needs_implicit_id_ordering: bool,
     pub needs_to_be_reverse_order: bool,
-    pub primary_order_by: Option<OrderBy>,
+    pub order_by: Vec<OrderBy>,
 }
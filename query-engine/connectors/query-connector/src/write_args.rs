@@ -6,19 +6,57 @@ use std::collections::{hash_map::Keys, HashMap};
 /// The key is the data source field name, NOT the model field name.
 #[derive(Debug, PartialEq, Clone, Default)]
 pub struct WriteArgs {
-    pub args: HashMap<String, PrismaValue>,
+    pub args: HashMap<String, WriteExpression>,
+}
+
+/// The write operation performed on a single field.
+/// Apart from `Value`, the operations are applied atomically to the current value of the field.
+#[derive(Debug, PartialEq, Clone)]
+pub enum WriteExpression {
+    /// Sets the field to the value.
+    Value(PrismaValue),
+
+    /// Adds the value to the field.
+    Add(PrismaValue),
+
+    /// Subtracts the value from the field.
+    Subtract(PrismaValue),
+
+    /// Multiplies the field with the value.
+    Multiply(PrismaValue),
+
+    /// Divides the field by the value.
+    Divide(PrismaValue),
+}
+
+impl WriteExpression {
+    /// Returns the value if the expression sets the field to it.
+    pub fn as_value(&self) -> Option<&PrismaValue> {
+        match self {
+            Self::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<PrismaValue> for WriteExpression {
+    fn from(value: PrismaValue) -> Self {
+        Self::Value(value)
+    }
 }
 
 impl From<HashMap<String, PrismaValue>> for WriteArgs {
     fn from(args: HashMap<String, PrismaValue>) -> Self {
-        Self { args }
+        Self {
+            args: args.into_iter().map(|(k, v)| (k, v.into())).collect(),
+        }
     }
 }
 
 impl From<Vec<(String, PrismaValue)>> for WriteArgs {
     fn from(pairs: Vec<(String, PrismaValue)>) -> Self {
         Self {
-            args: pairs.into_iter().collect(),
+            args: pairs.into_iter().map(|(k, v)| (k, v.into())).collect(),
         }
     }
 }
@@ -31,7 +69,7 @@ impl WriteArgs {
     pub fn insert<T, V>(&mut self, key: T, arg: V)
     where
         T: Into<String>,
-        V: Into<PrismaValue>,
+        V: Into<WriteExpression>,
     {
         self.args.insert(key.into(), arg.into());
     }
@@ -40,15 +78,15 @@ impl WriteArgs {
         self.args.contains_key(field)
     }
 
-    pub fn get_field_value(&self, field: &str) -> Option<&PrismaValue> {
+    pub fn get_field_value(&self, field: &str) -> Option<&WriteExpression> {
         self.args.get(field)
     }
 
-    pub fn take_field_value(&mut self, field: &str) -> Option<PrismaValue> {
+    pub fn take_field_value(&mut self, field: &str) -> Option<WriteExpression> {
         self.args.remove(field)
     }
 
-    pub fn keys(&self) -> Keys<String, PrismaValue> {
+    pub fn keys(&self) -> Keys<String, WriteExpression> {
         self.args.keys()
     }
 
@@ -92,7 +130,7 @@ impl WriteArgs {
         let pairs: Vec<_> = model_projection
             .data_source_fields()
             .map(|dsf| {
-                let val = match self.get_field_value(dsf.name.as_str()) {
+                let val = match self.get_field_value(dsf.name.as_str()).and_then(|expr| expr.as_value()) {
                     Some(val) => val.clone(),
                     None => PrismaValue::Null,
                 };
@@ -1,6 +1,7 @@
+use crate::ordering::Ordering;
 use connector_interface::QueryArguments;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 #[derive(Clone, Copy)]
 enum CursorType {
@@ -8,61 +9,62 @@ enum CursorType {
     After,
 }
 
-pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree<'static> {
-    match (
-        query_arguments.before.as_ref(),
-        query_arguments.after.as_ref(),
-        query_arguments.order_by.as_ref(),
-    ) {
-        (None, None, _) => ConditionTree::NoCondition,
-        (before, after, order_by) => {
-            let id_field = model.fields().find_singular_id().unwrap().upgrade().unwrap();
-            let (comparison_field, sort_order) = match order_by {
-                Some(x) => (&x.field, x.sort_order),
-                None => (&id_field, SortOrder::Ascending),
-            };
+/// Builds the condition for `before` / `after` cursors, matching the ordering of the query.
+///
+/// For an ordering `(o1, o2, ..., on)`, the rows after a cursor `c` are the ones where:
+/// ```sql
+/// o1 > c.o1
+/// OR (o1 = c.o1 AND o2 > c.o2)
+/// ...
+/// OR (o1 = c.o1 AND ... AND on > c.on)
+/// ```
+/// The comparison flips per column for descending orders and for `before` cursors.
+/// The cursor values of the ordered values are retrieved with a sub select on the cursor row.
+/// Ordered values of related records are correlated sub selects themselves, which then refer
+/// to the cursor row of that sub select.
+pub fn build(query_arguments: &QueryArguments, model: ModelRef, sql_family: SqlFamily) -> ConditionTree<'static> {
+    match (query_arguments.before.as_ref(), query_arguments.after.as_ref()) {
+        (None, None) => ConditionTree::NoCondition,
+        (before, after) => {
+            let order_values = order_values(&query_arguments.order_by, &model, sql_family);
 
             let cursor_for = |cursor_type: CursorType, pairs: &[(ScalarFieldRef, PrismaValue)]| {
                 let (fields, values): (Vec<_>, Vec<_>) = pairs.iter().cloned().unzip();
-                let columns: Vec<_> = vec![comparison_field.as_column()];
-                let order_row = Row::from(columns.clone());
-
                 let cursor_columns: Vec<_> = fields.into_iter().map(|sf| sf.as_column()).collect();
                 let cursor_row = Row::from(cursor_columns);
 
-                let where_condition = cursor_row.clone().equals(values.clone());
-
-                let select_query = Select::from_table(model.as_table())
-                    .columns(columns.clone())
-                    .so_that(where_condition);
+                let cursor_value = |value: &DatabaseValue<'static>| {
+                    Select::from_table(model.as_table())
+                        .value(value.clone())
+                        .so_that(cursor_row.clone().equals(values.clone()))
+                };
 
-                let compare = match (cursor_type, sort_order) {
-                    (CursorType::Before, SortOrder::Ascending) => order_row
-                        .clone()
-                        .equals(select_query.clone())
-                        .and(cursor_row.clone().less_than(values))
-                        .or(order_row.less_than(select_query)),
+                let conditions: Vec<Expression> = (0..order_values.len())
+                    .map(|index| {
+                        let (preceding, following) = order_values.split_at(index);
+                        let (value, sort_order) = &following[0];
 
-                    (CursorType::Before, SortOrder::Descending) => order_row
-                        .clone()
-                        .equals(select_query.clone())
-                        .and(cursor_row.clone().less_than(values))
-                        .or(order_row.greater_than(select_query)),
+                        let mut and_conditions: Vec<Expression> = preceding
+                            .iter()
+                            .map(|(value, _)| Row::from(vec![value.clone()]).equals(cursor_value(value)).into())
+                            .collect();
 
-                    (CursorType::After, SortOrder::Ascending) => order_row
-                        .clone()
-                        .equals(select_query.clone())
-                        .and(cursor_row.clone().greater_than(values))
-                        .or(order_row.greater_than(select_query)),
+                        let order_row = Row::from(vec![value.clone()]);
+                        let compare = match (cursor_type, sort_order) {
+                            (CursorType::After, SortOrder::Ascending) | (CursorType::Before, SortOrder::Descending) => {
+                                order_row.greater_than(cursor_value(value))
+                            }
+                            (CursorType::After, SortOrder::Descending) | (CursorType::Before, SortOrder::Ascending) => {
+                                order_row.less_than(cursor_value(value))
+                            }
+                        };
 
-                    (CursorType::After, SortOrder::Descending) => order_row
-                        .clone()
-                        .equals(select_query.clone())
-                        .and(cursor_row.clone().greater_than(values))
-                        .or(order_row.less_than(select_query)),
-                };
+                        and_conditions.push(compare.into());
+                        ConditionTree::And(and_conditions).into()
+                    })
+                    .collect();
 
-                ConditionTree::single(compare)
+                ConditionTree::Or(conditions)
             };
 
             let after_cursor = after
@@ -77,3 +79,29 @@ pub fn build(query_arguments: &QueryArguments, model: ModelRef) -> ConditionTree
         }
     }
 }
+
+/// The values the query is ordered by, mirroring `Ordering::for_model`: all order bys,
+/// followed by the primary identifier columns if none of the order bys is unique.
+fn order_values(
+    order_by: &[OrderBy],
+    model: &ModelRef,
+    sql_family: SqlFamily,
+) -> Vec<(DatabaseValue<'static>, SortOrder)> {
+    let mut values: Vec<_> = order_by
+        .iter()
+        .map(|order_by| {
+            (
+                Ordering::order_by_value(order_by, sql_family),
+                Ordering::sort_order(order_by, sql_family),
+            )
+        })
+        .collect();
+
+    if !order_by.iter().any(|order_by| order_by.is_unique()) {
+        for column in Ordering::implicit_identifier_columns(model, order_by) {
+            values.push((column.into(), SortOrder::Ascending));
+        }
+    }
+
+    values
+}
@@ -1,9 +1,10 @@
 use super::transaction::SqlConnectorTransaction;
-use crate::{database::operations::*, QueryExt, SqlError};
+use crate::{database::operations::*, metrics::CheckedOutConnection, query_builder, QueryExt, SqlError};
 use connector_interface::{
-    self as connector, filter::Filter, Connection, QueryArguments, ReadOperations, Transaction, WriteArgs,
-    WriteOperations, IO,
+    self as connector, filter::Filter, AggregationResult, AggregationRow, Aggregator, Connection, QueryArguments,
+    ReadOperations, RecordStream, Transaction, WriteArgs, WriteOperations, IO,
 };
+use futures::{StreamExt, TryStreamExt};
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
 use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
@@ -11,14 +12,21 @@ use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
 pub struct SqlConnection<'a, C> {
     inner: C,
     connection_info: &'a ConnectionInfo,
+    supports_row_number: bool,
+    _checked_out: CheckedOutConnection,
 }
 
 impl<'a, C> SqlConnection<'a, C>
 where
     C: QueryExt + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &'a ConnectionInfo) -> Self {
-        Self { inner, connection_info }
+    pub fn new(inner: C, connection_info: &'a ConnectionInfo, supports_row_number: bool) -> Self {
+        Self {
+            inner,
+            connection_info,
+            supports_row_number,
+            _checked_out: CheckedOutConnection::new(),
+        }
     }
 
     async fn catch<O>(
@@ -39,12 +47,50 @@ where
     fn start_transaction<'a>(&'a self) -> IO<'a, Box<dyn Transaction<'a> + 'a>> {
         let fut_tx = self.inner.start_transaction();
         let connection_info = self.connection_info;
+        let supports_row_number = self.supports_row_number;
 
         IO::new(self.catch(async move {
             let tx: quaint::connector::Transaction<'a> = fut_tx.await.map_err(SqlError::from)?;
-            Ok(Box::new(SqlConnectorTransaction::new(tx, connection_info)) as Box<dyn Transaction<'a> + 'a>)
+            let tx = SqlConnectorTransaction::new(tx, connection_info, supports_row_number);
+
+            Ok(Box::new(tx) as Box<dyn Transaction<'a> + 'a>)
+        }))
+    }
+
+    fn start_recording_transaction<'a>(&'a self, explain: bool) -> IO<'a, Box<dyn Transaction<'a> + 'a>> {
+        let fut_tx = self.inner.start_transaction();
+        let connection_info = self.connection_info;
+        let supports_row_number = self.supports_row_number;
+
+        IO::new(self.catch(async move {
+            let tx: quaint::connector::Transaction<'a> = fut_tx.await.map_err(SqlError::from)?;
+            let tx = SqlConnectorTransaction::recording(tx, connection_info, supports_row_number, explain);
+
+            Ok(Box::new(tx) as Box<dyn Transaction<'a> + 'a>)
         }))
     }
+
+    fn get_many_records_stream<'a>(
+        &'a self,
+        model: ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: SelectedFields,
+        chunk_size: usize,
+    ) -> RecordStream<'a> {
+        let connection_info = self.connection_info;
+        let sql_family = self.connection_info.sql_family();
+
+        read::stream_many_records(
+            &self.inner,
+            model,
+            query_arguments,
+            selected_fields,
+            chunk_size,
+            sql_family,
+        )
+        .map_err(move |err| err.into_connector_error(connection_info))
+        .boxed()
+    }
 }
 
 impl<'a, C> ReadOperations for SqlConnection<'a, C>
@@ -57,7 +103,11 @@ where
         filter: &'b Filter,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::get_single_record(&self.inner, model, filter, selected_fields, sql_family).await
+        }))
     }
 
     fn get_many_records<'b>(
@@ -66,11 +116,42 @@ where
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(
-            self.catch(
-                async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await },
-            ),
-        )
+        let distinct_on = query_builder::supports_distinct_on(self.connection_info);
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                model,
+                query_arguments,
+                selected_fields,
+                distinct_on,
+                sql_family,
+            )
+            .await
+        }))
+    }
+
+    fn supports_distinct(&self, model: &ModelRef, query_arguments: &QueryArguments) -> bool {
+        let distinct_on = query_builder::supports_distinct_on(self.connection_info);
+        query_builder::supports_distinct(model, query_arguments, distinct_on)
+    }
+
+    fn get_related_records<'b>(
+        &'b self,
+        from_field: &'b RelationFieldRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'b SelectedFields,
+    ) -> connector::IO<'b, ManyRecords> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::get_related_records(&self.inner, from_field, query_arguments, selected_fields, sql_family).await
+        }))
+    }
+
+    fn supports_related_pagination(&self, from_field: &RelationFieldRef, query_arguments: &QueryArguments) -> bool {
+        self.supports_row_number && query_builder::supports_related_pagination(from_field, query_arguments)
     }
 
     fn get_related_m2m_record_ids<'b>(
@@ -83,8 +164,61 @@ where
         )
     }
 
+    fn count_related_records<'b>(
+        &'b self,
+        from_field: &'b RelationFieldRef,
+        from_record_links: &'b [RecordProjection],
+    ) -> connector::IO<'b, Vec<(RecordProjection, usize)>> {
+        let parameter_limit = query_builder::parameter_limit(self.connection_info);
+
+        IO::new(self.catch(async move {
+            read::count_related_records(&self.inner, from_field, from_record_links, parameter_limit).await
+        }))
+    }
+
     fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(self.catch(async move { read::count_by_model(&self.inner, model, query_arguments).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move { read::count_by_model(&self.inner, model, query_arguments, sql_family).await }))
+    }
+
+    fn aggregate_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<AggregationResult>> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(
+            self.catch(
+                async move { read::aggregate(&self.inner, model, aggregators, query_arguments, sql_family).await },
+            ),
+        )
+    }
+
+    fn group_by_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> connector::IO<'b, Vec<AggregationRow>> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::group_by(
+                &self.inner,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+                having,
+                sql_family,
+            )
+            .await
+        }))
     }
 }
 
@@ -96,17 +230,48 @@ where
         IO::new(self.catch(async move { write::create_record(&self.inner, model, args).await }))
     }
 
+    fn create_records<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::IO<usize> {
+        let parameter_limit = query_builder::parameter_limit(self.connection_info);
+
+        IO::new(self.catch(async move {
+            write::create_records(&self.inner, model, args, skip_duplicates, parameter_limit).await
+        }))
+    }
+
     fn update_records<'a>(
         &'a self,
         model: &'a ModelRef,
         where_: Filter,
         args: WriteArgs,
     ) -> connector::IO<Vec<RecordProjection>> {
-        IO::new(self.catch(async move { write::update_records(&self.inner, model, where_, args).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move { write::update_records(&self.inner, model, where_, args, sql_family).await }))
+    }
+
+    fn native_upsert_record<'a>(
+        &'a self,
+        model: &'a ModelRef,
+        conflict_field: ScalarFieldRef,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> connector::IO<RecordProjection> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            write::native_upsert_record(&self.inner, model, conflict_field, create, update, sql_family).await
+        }))
     }
 
     fn delete_records<'a>(&'a self, model: &'a ModelRef, where_: Filter) -> connector::IO<usize> {
-        IO::new(self.catch(async move { write::delete_records(&self.inner, model, where_).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move { write::delete_records(&self.inner, model, where_, sql_family).await }))
     }
 
     fn connect<'a>(
@@ -127,7 +292,11 @@ where
         IO::new(self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await }))
     }
 
-    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<serde_json::Value> {
+    fn execute_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<usize> {
         IO::new(self.catch(async move { write::execute_raw(&self.inner, query, parameters).await }))
     }
+
+    fn query_raw<'a>(&'a self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<serde_json::Value> {
+        IO::new(self.catch(async move { write::query_raw(&self.inner, query, parameters).await }))
+    }
 }
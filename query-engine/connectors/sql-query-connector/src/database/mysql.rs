@@ -1,16 +1,21 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{metrics, query_builder, FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     error::{ConnectorError, ErrorKind},
     Connection, Connector, IO,
 };
 use datamodel::Source;
-use quaint::{pooled::Quaint, prelude::ConnectionInfo};
+use quaint::{
+    pooled::Quaint,
+    prelude::{ConnectionInfo, Queryable},
+};
+use std::time::Instant;
 
 pub struct Mysql {
     pool: Quaint,
     connection_info: ConnectionInfo,
+    supports_row_number: bool,
 }
 
 #[async_trait]
@@ -24,15 +29,51 @@ impl FromSource for Mysql {
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
         let connection_info = pool.connection_info().to_owned();
-        Ok(Mysql { pool, connection_info })
+
+        // Not being able to read the version only costs us `ROW_NUMBER()`, so it must not prevent startup.
+        let version = match database_version(&pool).await {
+            Ok(version) => version,
+            Err(err) => {
+                log::warn!(
+                    "Could not read the MySQL server version, not using ROW_NUMBER(): {}",
+                    err
+                );
+                None
+            }
+        };
+        let supports_row_number =
+            query_builder::supports_row_number(&connection_info, version.as_ref().map(String::as_str));
+
+        Ok(Mysql {
+            pool,
+            connection_info,
+            supports_row_number,
+        })
     }
 }
 
+/// The version string of the server, telling MySQL and MariaDB and their versions apart.
+async fn database_version(pool: &Quaint) -> crate::Result<Option<String>> {
+    let conn = pool.check_out().await?;
+    let rows = conn.query_raw("SELECT @@GLOBAL.version version", &[]).await?;
+
+    Ok(rows
+        .get(0)
+        .and_then(|row| row.get("version").and_then(|version| version.to_string())))
+}
+
 impl Connector for Mysql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let started = Instant::now();
+            let conn = self.pool.check_out().await;
+            metrics::observe_checkout(started, &conn);
+
+            let conn = SqlConnection::new(
+                conn.map_err(SqlError::from)?,
+                &self.connection_info,
+                self.supports_row_number,
+            );
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         }))
@@ -1,18 +1,22 @@
 use crate::{
     query_builder::{self, read},
-    QueryExt, SqlError,
+    QueryExt, SqlError, SqlRow,
 };
 use connector_interface::*;
+use datamodel::FieldArity;
+use futures::stream::{self, Stream};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
+use std::sync::Arc;
 
 pub async fn get_single_record(
     conn: &dyn QueryExt,
     model: &ModelRef,
     filter: &Filter,
     selected_fields: &SelectedFields,
+    sql_family: SqlFamily,
 ) -> crate::Result<Option<SingleRecord>> {
-    let query = read::get_records(&model, selected_fields.columns(), filter);
+    let query = read::get_records(&model, selected_fields.columns(), filter, sql_family);
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.types().collect();
 
@@ -28,15 +32,53 @@ pub async fn get_single_record(
     Ok(record)
 }
 
+/// Makes the records distinct in the database if the query arguments contain `distinct` fields, with `DISTINCT ON`
+/// if `distinct_on` is set. See `read::supports_distinct` for the queries that can be made distinct.
 pub async fn get_many_records(
     conn: &dyn QueryExt,
     model: &ModelRef,
+    mut query_arguments: QueryArguments,
+    selected_fields: &SelectedFields,
+    distinct_on: bool,
+    sql_family: SqlFamily,
+) -> crate::Result<ManyRecords> {
+    let field_names = selected_fields.db_names().map(String::from).collect();
+    let idents: Vec<_> = selected_fields.types().collect();
+
+    let query = match query_arguments.distinct.take() {
+        Some(distinct) => read::get_distinct_records(
+            model,
+            selected_fields.columns(),
+            query_arguments,
+            &distinct,
+            distinct_on,
+            sql_family,
+        ),
+        None => read::get_records(model, selected_fields.columns(), query_arguments, sql_family),
+    };
+
+    let records = conn
+        .filter(query.into(), idents.as_slice())
+        .await?
+        .into_iter()
+        .map(Record::from)
+        .collect();
+
+    Ok(ManyRecords { records, field_names })
+}
+
+/// Reads the related records of the relation field, paginated per parent in the database, see
+/// `read::get_related_records`.
+pub async fn get_related_records(
+    conn: &dyn QueryExt,
+    from_field: &RelationFieldRef,
     query_arguments: QueryArguments,
     selected_fields: &SelectedFields,
+    sql_family: SqlFamily,
 ) -> crate::Result<ManyRecords> {
     let field_names = selected_fields.db_names().map(String::from).collect();
     let idents: Vec<_> = selected_fields.types().collect();
-    let query = read::get_records(model, selected_fields.columns(), query_arguments);
+    let query = read::get_related_records(from_field, selected_fields, query_arguments, sql_family);
 
     let records = conn
         .filter(query.into(), idents.as_slice())
@@ -48,6 +90,88 @@ pub async fn get_many_records(
     Ok(ManyRecords { records, field_names })
 }
 
+/// Streams the records in chunks, each chunk being a separate query of at most `chunk_size` records.
+/// Chunks after the first one continue after the last record of the previous chunk with an `after` cursor
+/// on the primary identifier, which is correct for any ordering, as the ordering always ends with the identifier.
+/// The total number of records is bounded by `first`, `skip` only applies to the first chunk.
+pub fn stream_many_records<'a>(
+    conn: &'a dyn QueryExt,
+    model: ModelRef,
+    query_arguments: QueryArguments,
+    selected_fields: SelectedFields,
+    chunk_size: usize,
+    sql_family: SqlFamily,
+) -> impl Stream<Item = crate::Result<ManyRecords>> + Send + 'a {
+    let remaining = query_arguments.first.map(|first| first as usize);
+
+    stream::try_unfold(Some((query_arguments, remaining)), move |state| {
+        let model = Arc::clone(&model);
+        let selected_fields = selected_fields.clone();
+
+        async move {
+            let (mut query_arguments, remaining) = match state {
+                Some(state) => state,
+                None => return Ok(None),
+            };
+
+            let limit = remaining.map(|r| r.min(chunk_size)).unwrap_or(chunk_size);
+
+            if limit == 0 {
+                return Ok(None);
+            }
+
+            // One record more than requested is fetched to see if there's more data.
+            query_arguments.first = Some(limit as i64);
+
+            // Streamed queries can't be distinct, the core rejects them.
+            let mut chunk = get_many_records(
+                conn,
+                &model,
+                query_arguments.clone(),
+                &selected_fields,
+                false,
+                sql_family,
+            )
+            .await?;
+            let has_more = chunk.records.len() > limit;
+
+            chunk.records.truncate(limit);
+
+            let next_state = match chunk.records.last() {
+                Some(last) if has_more => {
+                    query_arguments.skip = None;
+                    query_arguments.after = Some(cursor_of(&model, last, &chunk.field_names)?);
+
+                    Some((query_arguments, remaining.map(|r| r - limit)))
+                }
+                _ => None,
+            };
+
+            Ok(Some((chunk, next_state)))
+        }
+    })
+}
+
+/// The primary identifier values of the record, as cursor for the following records.
+fn cursor_of(
+    model: &ModelRef,
+    record: &Record,
+    field_names: &[String],
+) -> crate::Result<Vec<(ScalarFieldRef, PrismaValue)>> {
+    model
+        .primary_identifier()
+        .fields()
+        .filter_map(|field| match field {
+            Field::Scalar(sf) => Some(Arc::clone(sf)),
+            Field::Relation(_) => None,
+        })
+        .map(|sf| {
+            let value = record.get_field_value(field_names, &sf.data_source_field().name)?;
+            Ok((sf, value.clone()))
+        })
+        .collect()
+}
+
 pub async fn get_related_m2m_record_ids(
     conn: &dyn QueryExt,
     from_field: &RelationFieldRef,
@@ -108,13 +232,200 @@ pub async fn get_related_m2m_record_ids(
         .collect())
 }
 
+/// Counts the related records of all given parents in grouped queries on the table holding the relation:
+/// ```sql
+/// SELECT `post`, COUNT(*) FROM `Comment` WHERE `post` IN (...) GROUP BY `post`
+/// ```
+/// The parents are split into chunks so that no query exceeds the `parameter_limit` of the database.
+pub async fn count_related_records(
+    conn: &dyn QueryExt,
+    from_field: &RelationFieldRef,
+    from_record_links: &[RecordProjection],
+    parameter_limit: usize,
+) -> crate::Result<Vec<(RecordProjection, usize)>> {
+    let link_fields = from_field.linking_fields();
+    let link_dsfs: Vec<_> = link_fields.data_source_fields().collect();
+
+    let mut idents = link_fields.type_identifiers_with_arities();
+    idents.push((TypeIdentifier::Int, FieldArity::Required));
+
+    // The columns of the relation table pointing to the parent records.
+    let relation_columns: Vec<Column<'static>> = if from_field.relation().is_many_to_many() {
+        from_field
+            .related_field()
+            .m2m_column_names()
+            .into_iter()
+            .map(Column::from)
+            .collect()
+    } else {
+        from_field.relation_columns(false).collect()
+    };
+
+    let links_per_query = std::cmp::max(1, parameter_limit / relation_columns.len());
+    let mut counts = Vec::with_capacity(from_record_links.len());
+
+    for chunk in from_record_links.chunks(links_per_query) {
+        let select = Select::from_table(from_field.relation().as_table())
+            .columns(relation_columns.clone())
+            .value(count(asterisk()))
+            .so_that(query_builder::conditions(&relation_columns, chunk));
+
+        let select = relation_columns
+            .iter()
+            .fold(select, |acc, column| acc.group_by(column.clone()));
+
+        let rows = conn.filter(select.into(), idents.as_slice()).await?;
+
+        counts.extend(rows.into_iter().map(|row| {
+            let mut values = row.values;
+            let count = match values.pop() {
+                Some(PrismaValue::Int(i)) => i as usize,
+                _ => 0,
+            };
+
+            let link: RecordProjection = link_dsfs
+                .iter()
+                .zip(values)
+                .map(|(dsf, val)| (dsf.clone(), val))
+                .collect::<Vec<_>>()
+                .into();
+
+            (link, count)
+        }));
+    }
+
+    Ok(counts)
+}
+
 pub async fn count_by_model(
     conn: &dyn QueryExt,
     model: &ModelRef,
     query_arguments: QueryArguments,
+    sql_family: SqlFamily,
 ) -> crate::Result<usize> {
-    let query = read::count_by_model(model, query_arguments);
+    let query = read::count_by_model(model, query_arguments, sql_family);
     let result = conn.find_int(query).await? as usize;
 
     Ok(result)
 }
+
+pub async fn aggregate(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    aggregators: Vec<Aggregator>,
+    query_arguments: QueryArguments,
+    sql_family: SqlFamily,
+) -> crate::Result<Vec<AggregationResult>> {
+    let query = read::aggregate(model, &aggregators, query_arguments, sql_family);
+    let idents = aggregation_idents(&aggregators);
+
+    let row = conn.find(query, idents.as_slice()).await.or_else(|err| match err {
+        // An empty sub select still yields a single row of aggregations.
+        SqlError::RecordDoesNotExist => Ok(SqlRow::default()),
+        err => Err(err),
+    })?;
+
+    Ok(map_aggregation_values(&aggregators, &mut row.values.into_iter()))
+}
+
+pub async fn group_by(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    group_by: Vec<ScalarFieldRef>,
+    aggregators: Vec<Aggregator>,
+    query_arguments: QueryArguments,
+    having: Option<Filter>,
+    sql_family: SqlFamily,
+) -> crate::Result<Vec<AggregationRow>> {
+    let query = read::group_by_aggregate(model, &group_by, &aggregators, query_arguments, having, sql_family);
+
+    let idents: Vec<_> = group_by
+        .iter()
+        .map(|sf| sf.type_identifier_with_arity())
+        .chain(aggregation_idents(&aggregators))
+        .collect();
+
+    let rows = conn.filter(query.into(), idents.as_slice()).await?;
+
+    let results = rows
+        .into_iter()
+        .map(|row| {
+            let mut values = row.values.into_iter();
+            let mut results: AggregationRow = group_by
+                .iter()
+                .map(|sf| AggregationResult::Field(Arc::clone(sf), values.next().unwrap_or(PrismaValue::Null)))
+                .collect();
+
+            results.append(&mut map_aggregation_values(&aggregators, &mut values));
+            results
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// The types of the aggregation columns, in the order of the given aggregators.
+/// Averages are always floating point values, everything else retains the type of the aggregated field.
+fn aggregation_idents(aggregators: &[Aggregator]) -> Vec<(TypeIdentifier, FieldArity)> {
+    aggregators
+        .iter()
+        .flat_map(|aggregator| match aggregator {
+            Aggregator::Count => vec![(TypeIdentifier::Int, FieldArity::Required)],
+            Aggregator::Average(fields) => fields
+                .iter()
+                .map(|_| (TypeIdentifier::Float, FieldArity::Optional))
+                .collect(),
+            Aggregator::Sum(fields) | Aggregator::Min(fields) | Aggregator::Max(fields) => fields
+                .iter()
+                .map(|f| (f.type_identifier.clone(), FieldArity::Optional))
+                .collect(),
+        })
+        .collect()
+}
+
+/// Maps the aggregation columns of a row to results, in the order of the given aggregators.
+fn map_aggregation_values(
+    aggregators: &[Aggregator],
+    values: &mut impl Iterator<Item = PrismaValue>,
+) -> Vec<AggregationResult> {
+    let mut results = Vec::new();
+
+    for aggregator in aggregators {
+        match aggregator {
+            Aggregator::Count => {
+                let count = match values.next() {
+                    Some(PrismaValue::Int(i)) => i as usize,
+                    _ => 0,
+                };
+
+                results.push(AggregationResult::Count(count));
+            }
+            Aggregator::Average(fields) => {
+                for field in fields {
+                    let value = values.next().unwrap_or(PrismaValue::Null);
+                    results.push(AggregationResult::Average(Arc::clone(field), value));
+                }
+            }
+            Aggregator::Sum(fields) => {
+                for field in fields {
+                    let value = values.next().unwrap_or(PrismaValue::Null);
+                    results.push(AggregationResult::Sum(Arc::clone(field), value));
+                }
+            }
+            Aggregator::Min(fields) => {
+                for field in fields {
+                    let value = values.next().unwrap_or(PrismaValue::Null);
+                    results.push(AggregationResult::Min(Arc::clone(field), value));
+                }
+            }
+            Aggregator::Max(fields) => {
+                for field in fields {
+                    let value = values.next().unwrap_or(PrismaValue::Null);
+                    results.push(AggregationResult::Max(Arc::clone(field), value));
+                }
+            }
+        }
+    }
+
+    results
+}
@@ -2,7 +2,7 @@ use crate::{error::SqlError, query_builder::write, QueryExt, RawQuery};
 use connector_interface::*;
 use prisma_models::*;
 use prisma_value::PrismaValue;
-use quaint::error::ErrorKind;
+use quaint::{error::ErrorKind, prelude::SqlFamily};
 use std::{collections::HashMap, convert::TryFrom};
 use user_facing_errors::query_engine::DatabaseConstraint;
 
@@ -11,40 +11,7 @@ use user_facing_errors::query_engine::DatabaseConstraint;
 pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArgs) -> crate::Result<RecordProjection> {
     let (insert, returned_id) = write::create_record(model, args);
 
-    let result_set = match conn.insert(insert).await {
-        Ok(id) => id,
-        Err(e) => match e.kind() {
-            ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
-                quaint::error::DatabaseConstraint::Index(name) => {
-                    let constraint = DatabaseConstraint::Index(name.clone());
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::Fields(fields) => {
-                    let constraint = DatabaseConstraint::Fields(fields.clone());
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::ForeignKey => {
-                    let constraint = DatabaseConstraint::ForeignKey;
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-            },
-            ErrorKind::NullConstraintViolation { constraint } => match constraint {
-                quaint::error::DatabaseConstraint::Index(name) => {
-                    let constraint = DatabaseConstraint::Index(name.clone());
-                    return Err(SqlError::NullConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::Fields(fields) => {
-                    let constraint = DatabaseConstraint::Fields(fields.clone());
-                    return Err(SqlError::NullConstraintViolation { constraint });
-                }
-                quaint::error::DatabaseConstraint::ForeignKey => {
-                    let constraint = DatabaseConstraint::ForeignKey;
-                    return Err(SqlError::UniqueConstraintViolation { constraint });
-                }
-            },
-            _ => return Err(SqlError::from(e)),
-        },
-    };
+    let result_set = conn.insert(insert).await.map_err(insert_error)?;
 
     match (returned_id, result_set.len(), result_set.last_insert_id()) {
         // All values provided in the write arrghs
@@ -63,6 +30,52 @@ pub async fn create_record(conn: &dyn QueryExt, model: &ModelRef, args: WriteArg
     }
 }
 
+/// Create the records in bulk in the database defined in `conn`, resulting in
+/// the number of records inserted. Duplicates are not counted if they are skipped.
+pub async fn create_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    parameter_limit: usize,
+) -> crate::Result<usize> {
+    let mut count = 0;
+
+    for insert in write::create_records(model, args, skip_duplicates, parameter_limit) {
+        count += conn.execute(insert.into()).await.map_err(insert_error)? as usize;
+    }
+
+    Ok(count)
+}
+
+/// Upserts a single record in the database defined in `conn` with one native upsert statement,
+/// conflicting on `conflict_field`. The record is read back by its value for `conflict_field`,
+/// as not all databases can return the identifier of an updated row.
+pub async fn native_upsert_record(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    conflict_field: ScalarFieldRef,
+    create: WriteArgs,
+    update: WriteArgs,
+    sql_family: SqlFamily,
+) -> crate::Result<RecordProjection> {
+    let value = create
+        .get_field_value(conflict_field.db_name())
+        .and_then(|expr| expr.as_value())
+        .cloned()
+        .expect("Expected the create args of a native upsert to contain the conflict field.");
+
+    let upsert = write::native_upsert(model, &conflict_field, create, update);
+    conn.execute(upsert.into()).await.map_err(insert_error)?;
+
+    let mut ids = conn.filter_ids(model, conflict_field.equals(value), sql_family).await?;
+
+    match ids.pop() {
+        Some(id) => Ok(id),
+        None => Err(SqlError::RecordDoesNotExist),
+    }
+}
+
 /// Update multiple records in a database defined in `conn` and the records
 /// defined in `args`, resulting the identifiers that were modified in the
 /// operation.
@@ -71,8 +84,9 @@ pub async fn update_records(
     model: &ModelRef,
     where_: Filter,
     args: WriteArgs,
+    sql_family: SqlFamily,
 ) -> crate::Result<Vec<RecordProjection>> {
-    let ids = conn.filter_ids(model, where_.clone()).await?;
+    let ids = conn.filter_ids(model, where_.clone(), sql_family).await?;
     let id_args = pick_args(&model.primary_identifier(), &args);
 
     if ids.len() == 0 {
@@ -94,8 +108,13 @@ pub async fn update_records(
 /// Delete multiple records in `conn`, defined in the `Filter`. Results the
 /// number of items deleted.
 /// [DTODO] The filter id query is probably not necessary.
-pub async fn delete_records(conn: &dyn QueryExt, model: &ModelRef, where_: Filter) -> crate::Result<usize> {
-    let ids = conn.filter_ids(model, where_.clone()).await?;
+pub async fn delete_records(
+    conn: &dyn QueryExt,
+    model: &ModelRef,
+    where_: Filter,
+    sql_family: SqlFamily,
+) -> crate::Result<usize> {
+    let ids = conn.filter_ids(model, where_.clone(), sql_family).await?;
     let ids: Vec<&RecordProjection> = ids.iter().map(|id| &*id).collect();
     let count = ids.len();
 
@@ -138,9 +157,16 @@ pub async fn disconnect(
     Ok(())
 }
 
-/// Execute a plain SQL query with the given parameters, returning the answer as
-/// a JSON `Value`.
-pub async fn execute_raw(
+/// Execute a plain SQL statement with the given parameters, returning the number
+/// of affected rows.
+pub async fn execute_raw(conn: &dyn QueryExt, query: String, parameters: Vec<PrismaValue>) -> crate::Result<usize> {
+    let changes = conn.raw_count(RawQuery::new(query, parameters)).await?;
+    Ok(changes)
+}
+
+/// Execute a plain SQL query with the given parameters, returning the resulting
+/// rows as a JSON `Value`.
+pub async fn query_raw(
     conn: &dyn QueryExt,
     query: String,
     parameters: Vec<PrismaValue>,
@@ -149,13 +175,53 @@ pub async fn execute_raw(
     Ok(value)
 }
 
+/// Maps constraint violations of an insert to their errors.
+fn insert_error(e: quaint::error::Error) -> SqlError {
+    match e.kind() {
+        ErrorKind::UniqueConstraintViolation { constraint } => match constraint {
+            quaint::error::DatabaseConstraint::Index(name) => {
+                let constraint = DatabaseConstraint::Index(name.clone());
+                SqlError::UniqueConstraintViolation { constraint }
+            }
+            quaint::error::DatabaseConstraint::Fields(fields) => {
+                let constraint = DatabaseConstraint::Fields(fields.clone());
+                SqlError::UniqueConstraintViolation { constraint }
+            }
+            quaint::error::DatabaseConstraint::ForeignKey => {
+                let constraint = DatabaseConstraint::ForeignKey;
+                SqlError::UniqueConstraintViolation { constraint }
+            }
+        },
+        ErrorKind::NullConstraintViolation { constraint } => match constraint {
+            quaint::error::DatabaseConstraint::Index(name) => {
+                let constraint = DatabaseConstraint::Index(name.clone());
+                SqlError::NullConstraintViolation { constraint }
+            }
+            quaint::error::DatabaseConstraint::Fields(fields) => {
+                let constraint = DatabaseConstraint::Fields(fields.clone());
+                SqlError::NullConstraintViolation { constraint }
+            }
+            quaint::error::DatabaseConstraint::ForeignKey => {
+                let constraint = DatabaseConstraint::ForeignKey;
+                SqlError::UniqueConstraintViolation { constraint }
+            }
+        },
+        _ => SqlError::from(e),
+    }
+}
+
 /// Picks all arguments out of `args` that are updating a value for a field
 /// contained in `projection`, as those need to be merged into the records later on.
+/// Only plain values are picked, the schema doesn't offer atomic operations on identifying fields.
 fn pick_args(projection: &ModelProjection, args: &WriteArgs) -> WriteArgs {
     let pairs: Vec<_> = projection
         .data_source_fields()
         .into_iter()
-        .filter_map(|dsf| args.get_field_value(&dsf.name).map(|v| (dsf.name.clone(), v.clone())))
+        .filter_map(|dsf| {
+            args.get_field_value(&dsf.name)
+                .and_then(|expr| expr.as_value())
+                .map(|v| (dsf.name.clone(), v.clone()))
+        })
         .collect();
 
     WriteArgs::from(pairs)
@@ -172,7 +238,11 @@ fn merge_write_args(ids: Vec<RecordProjection>, args: WriteArgs) -> Vec<RecordPr
         .pairs
         .iter()
         .enumerate()
-        .filter_map(|(i, (dsf, _))| args.get_field_value(&dsf.name).map(|val| (i, val)))
+        .filter_map(|(i, (dsf, _))| {
+            args.get_field_value(&dsf.name)
+                .and_then(|expr| expr.as_value())
+                .map(|val| (i, val))
+        })
         .collect();
 
     ids.into_iter()
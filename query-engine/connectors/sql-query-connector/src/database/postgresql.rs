@@ -1,5 +1,5 @@
 use super::connection::SqlConnection;
-use crate::{FromSource, SqlError};
+use crate::{metrics, query_builder, FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     error::{ConnectorError, ErrorKind},
@@ -7,6 +7,7 @@ use connector_interface::{
 };
 use datamodel::Source;
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
+use std::time::Instant;
 
 pub struct PostgreSql {
     pool: Quaint,
@@ -31,8 +32,15 @@ impl FromSource for PostgreSql {
 impl Connector for PostgreSql {
     fn get_connection<'a>(&'a self) -> IO<Box<dyn Connection + 'a>> {
         IO::new(super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let started = Instant::now();
+            let conn = self.pool.check_out().await;
+            metrics::observe_checkout(started, &conn);
+
+            let conn = SqlConnection::new(
+                conn.map_err(SqlError::from)?,
+                &self.connection_info,
+                query_builder::supports_row_number(&self.connection_info, None),
+            );
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         }))
@@ -1,22 +1,43 @@
 use crate::database::operations::*;
-use crate::SqlError;
+use crate::{query_builder, SqlError, StatementRecorder};
 use connector_interface::{
-    self as connector, filter::Filter, QueryArguments, ReadOperations, Transaction, WriteArgs, WriteOperations, IO,
+    self as connector, filter::Filter, AggregationResult, AggregationRow, Aggregator, QueryArguments, ReadOperations,
+    RecordedStatement, Transaction, WriteArgs, WriteOperations, IO,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
 use quaint::prelude::ConnectionInfo;
 
 pub struct SqlConnectorTransaction<'a> {
-    inner: quaint::connector::Transaction<'a>,
+    inner: StatementRecorder<'a, quaint::connector::Transaction<'a>>,
     connection_info: &'a ConnectionInfo,
+    supports_row_number: bool,
 }
 
 impl<'a> SqlConnectorTransaction<'a> {
-    pub fn new<'b: 'a>(tx: quaint::connector::Transaction<'a>, connection_info: &'b ConnectionInfo) -> Self {
+    pub fn new<'b: 'a>(
+        tx: quaint::connector::Transaction<'a>,
+        connection_info: &'b ConnectionInfo,
+        supports_row_number: bool,
+    ) -> Self {
         Self {
-            inner: tx,
+            inner: StatementRecorder::disabled(tx, connection_info),
             connection_info,
+            supports_row_number,
+        }
+    }
+
+    /// A transaction recording its statements, see `Connection::start_recording_transaction`.
+    pub fn recording<'b: 'a>(
+        tx: quaint::connector::Transaction<'a>,
+        connection_info: &'b ConnectionInfo,
+        supports_row_number: bool,
+        explain: bool,
+    ) -> Self {
+        Self {
+            inner: StatementRecorder::enabled(tx, connection_info, explain),
+            connection_info,
+            supports_row_number,
         }
     }
 
@@ -33,11 +54,15 @@ impl<'a> SqlConnectorTransaction<'a> {
 
 impl<'a> Transaction<'a> for SqlConnectorTransaction<'a> {
     fn commit<'b>(&'b self) -> IO<'b, ()> {
-        IO::new(self.catch(async move { Ok(self.inner.commit().await.map_err(SqlError::from)?) }))
+        IO::new(self.catch(async move { Ok(self.inner.inner().commit().await.map_err(SqlError::from)?) }))
     }
 
     fn rollback<'b>(&'b self) -> IO<'b, ()> {
-        IO::new(self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) }))
+        IO::new(self.catch(async move { Ok(self.inner.inner().rollback().await.map_err(SqlError::from)?) }))
+    }
+
+    fn recorded_statements(&self) -> Vec<RecordedStatement> {
+        self.inner.statements()
     }
 }
 
@@ -48,7 +73,11 @@ impl<'a> ReadOperations for SqlConnectorTransaction<'a> {
         filter: &'b Filter,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, Option<SingleRecord>> {
-        IO::new(self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::get_single_record(&self.inner, model, filter, selected_fields, sql_family).await
+        }))
     }
 
     fn get_many_records<'b>(
@@ -57,11 +86,42 @@ impl<'a> ReadOperations for SqlConnectorTransaction<'a> {
         query_arguments: QueryArguments,
         selected_fields: &'b SelectedFields,
     ) -> connector::IO<'b, ManyRecords> {
-        IO::new(
-            self.catch(
-                async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await },
-            ),
-        )
+        let distinct_on = query_builder::supports_distinct_on(self.connection_info);
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::get_many_records(
+                &self.inner,
+                model,
+                query_arguments,
+                selected_fields,
+                distinct_on,
+                sql_family,
+            )
+            .await
+        }))
+    }
+
+    fn supports_distinct(&self, model: &ModelRef, query_arguments: &QueryArguments) -> bool {
+        let distinct_on = query_builder::supports_distinct_on(self.connection_info);
+        query_builder::supports_distinct(model, query_arguments, distinct_on)
+    }
+
+    fn get_related_records<'b>(
+        &'b self,
+        from_field: &'b RelationFieldRef,
+        query_arguments: QueryArguments,
+        selected_fields: &'b SelectedFields,
+    ) -> connector::IO<'b, ManyRecords> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::get_related_records(&self.inner, from_field, query_arguments, selected_fields, sql_family).await
+        }))
+    }
+
+    fn supports_related_pagination(&self, from_field: &RelationFieldRef, query_arguments: &QueryArguments) -> bool {
+        self.supports_row_number && query_builder::supports_related_pagination(from_field, query_arguments)
     }
 
     fn get_related_m2m_record_ids<'b>(
@@ -74,8 +134,61 @@ impl<'a> ReadOperations for SqlConnectorTransaction<'a> {
         )
     }
 
+    fn count_related_records<'b>(
+        &'b self,
+        from_field: &'b RelationFieldRef,
+        from_record_links: &'b [RecordProjection],
+    ) -> connector::IO<'b, Vec<(RecordProjection, usize)>> {
+        let parameter_limit = query_builder::parameter_limit(self.connection_info);
+
+        IO::new(self.catch(async move {
+            read::count_related_records(&self.inner, from_field, from_record_links, parameter_limit).await
+        }))
+    }
+
     fn count_by_model<'b>(&'b self, model: &'b ModelRef, query_arguments: QueryArguments) -> connector::IO<'b, usize> {
-        IO::new(self.catch(async move { read::count_by_model(&self.inner, model, query_arguments).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move { read::count_by_model(&self.inner, model, query_arguments, sql_family).await }))
+    }
+
+    fn aggregate_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::IO<'b, Vec<AggregationResult>> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(
+            self.catch(
+                async move { read::aggregate(&self.inner, model, aggregators, query_arguments, sql_family).await },
+            ),
+        )
+    }
+
+    fn group_by_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        group_by: Vec<ScalarFieldRef>,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+        having: Option<Filter>,
+    ) -> connector::IO<'b, Vec<AggregationRow>> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            read::group_by(
+                &self.inner,
+                model,
+                group_by,
+                aggregators,
+                query_arguments,
+                having,
+                sql_family,
+            )
+            .await
+        }))
     }
 }
 
@@ -84,17 +197,48 @@ impl<'a> WriteOperations for SqlConnectorTransaction<'a> {
         IO::new(self.catch(async move { write::create_record(&self.inner, model, args).await }))
     }
 
+    fn create_records<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        args: Vec<WriteArgs>,
+        skip_duplicates: bool,
+    ) -> connector::IO<usize> {
+        let parameter_limit = query_builder::parameter_limit(self.connection_info);
+
+        IO::new(self.catch(async move {
+            write::create_records(&self.inner, model, args, skip_duplicates, parameter_limit).await
+        }))
+    }
+
     fn update_records<'b>(
         &'b self,
         model: &'b ModelRef,
         where_: Filter,
         args: WriteArgs,
     ) -> connector::IO<Vec<RecordProjection>> {
-        IO::new(self.catch(async move { write::update_records(&self.inner, model, where_, args).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move { write::update_records(&self.inner, model, where_, args, sql_family).await }))
+    }
+
+    fn native_upsert_record<'b>(
+        &'b self,
+        model: &'b ModelRef,
+        conflict_field: ScalarFieldRef,
+        create: WriteArgs,
+        update: WriteArgs,
+    ) -> connector::IO<RecordProjection> {
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move {
+            write::native_upsert_record(&self.inner, model, conflict_field, create, update, sql_family).await
+        }))
     }
 
     fn delete_records<'b>(&'b self, model: &'b ModelRef, where_: Filter) -> connector::IO<usize> {
-        IO::new(self.catch(async move { write::delete_records(&self.inner, model, where_).await }))
+        let sql_family = self.connection_info.sql_family();
+
+        IO::new(self.catch(async move { write::delete_records(&self.inner, model, where_, sql_family).await }))
     }
 
     fn connect<'b>(
@@ -115,7 +259,11 @@ impl<'a> WriteOperations for SqlConnectorTransaction<'a> {
         IO::new(self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await }))
     }
 
-    fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<serde_json::Value> {
+    fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<usize> {
         IO::new(self.catch(async move { write::execute_raw(&self.inner, query, parameters).await }))
     }
+
+    fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::IO<serde_json::Value> {
+        IO::new(self.catch(async move { write::query_raw(&self.inner, query, parameters).await }))
+    }
 }
@@ -1,6 +1,6 @@
 use connector_interface::filter::*;
 use prisma_models::prelude::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 #[derive(Clone, Copy, Debug)]
 /// A distinction in aliasing to separate the parent table and the joined data
@@ -59,8 +59,9 @@ pub trait AliasedCondition {
     /// alias if provided, otherwise using the fully qualified path.
     ///
     /// Alias should be used only when nesting, making the top level queries
-    /// more explicit.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static>;
+    /// more explicit. The SQL family picks the database specific syntax where
+    /// the databases differ.
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static>;
 }
 
 trait AliasedSelect {
@@ -69,20 +70,20 @@ trait AliasedSelect {
     ///
     /// Alias should be used only when nesting, making the top level queries
     /// more explicit.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static>;
+    fn aliased_sel(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static>;
 }
 
 impl AliasedCondition for Filter {
     /// Conversion from a `Filter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         match self {
             Filter::And(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -91,11 +92,11 @@ impl AliasedCondition for Filter {
             },
             Filter::Or(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NegativeCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias))
+                        .map(|f| f.aliased_cond(alias, sql_family))
                         .map(Expression::from)
                         .collect();
 
@@ -104,20 +105,22 @@ impl AliasedCondition for Filter {
             },
             Filter::Not(mut filters) => match filters.len() {
                 n if n == 0 => ConditionTree::NoCondition,
-                n if n == 1 => filters.pop().unwrap().aliased_cond(alias).not(),
+                n if n == 1 => filters.pop().unwrap().aliased_cond(alias, sql_family).not(),
                 _ => {
                     let exprs = filters
                         .into_iter()
-                        .map(|f| f.aliased_cond(alias).not())
+                        .map(|f| f.aliased_cond(alias, sql_family).not())
                         .map(Expression::from)
                         .collect();
 
                     ConditionTree::And(exprs)
                 }
             },
-            Filter::Scalar(filter) => filter.aliased_cond(alias),
-            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
-            Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Scalar(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::ScalarList(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Relation(filter) => filter.aliased_cond(alias, sql_family),
+            Filter::Aggregation(filter) => filter.aliased_cond(alias, sql_family),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -133,38 +136,219 @@ impl AliasedCondition for Filter {
 
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    /// Full-text searches use the text search of the database, with its query syntax. MySQL searches the `FULLTEXT` index
+    /// on the column, SQLite the FTS5 table of the `@@fulltext` index on the field, matched by row id:
+    /// ```sql
+    /// to_tsvector("title") @@ to_tsquery($1)                                            -- Postgres
+    /// MATCH (`title`) AGAINST (? IN BOOLEAN MODE)                                        -- MySQL
+    /// "Post"."rowid" IN (SELECT "rowid" FROM "Post.title" WHERE "Post.title" MATCH ?)   -- SQLite
+    /// ```
+    /// JSON path conditions compare the extracted value with the JSON value of the condition:
+    /// ```sql
+    /// "meta" #> '{address,city}' = $1              -- Postgres
+    /// JSON_EXTRACT(`meta`, '$.address.city') = ?   -- MySQL
+    /// json_extract(`meta`, '$.address.city') = ?   -- SQLite
+    /// ```
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         let column = match alias {
             Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
             None => self.field.as_column(),
         };
 
-        let condition = match self.condition {
-            ScalarCondition::Equals(PrismaValue::Null) => column.is_null(),
-            ScalarCondition::NotEquals(PrismaValue::Null) => column.is_not_null(),
-            ScalarCondition::Equals(value) => column.equals(value),
-            ScalarCondition::NotEquals(value) => column.not_equals(value),
-            ScalarCondition::Contains(value) => column.like(format!("{}", value)),
-            ScalarCondition::NotContains(value) => column.not_like(format!("{}", value)),
-            ScalarCondition::StartsWith(value) => column.begins_with(format!("{}", value)),
-            ScalarCondition::NotStartsWith(value) => column.not_begins_with(format!("{}", value)),
-            ScalarCondition::EndsWith(value) => column.ends_into(format!("{}", value)),
-            ScalarCondition::NotEndsWith(value) => column.not_ends_into(format!("{}", value)),
-            ScalarCondition::LessThan(value) => column.less_than(value),
-            ScalarCondition::LessThanOrEquals(value) => column.less_than_or_equals(value),
-            ScalarCondition::GreaterThan(value) => column.greater_than(value),
-            ScalarCondition::GreaterThanOrEquals(value) => column.greater_than_or_equals(value),
-            ScalarCondition::In(values) => column.in_selection(values),
-            ScalarCondition::NotIn(values) => column.not_in_selection(values),
+        let (comparable, condition): (DatabaseValue<'static>, _) = match self.condition {
+            ScalarCondition::Search(query) if sql_family.is_sqlite() => {
+                let table = fulltext_table_name(&self.field);
+                let matches = Select::from_table(fulltext_table(&self.field))
+                    .column("rowid")
+                    .so_that(Column::from(table).compare_raw("MATCH", format!("{}", query)));
+
+                return ConditionTree::single(
+                    rowid_column(&self.field, alias.map(|alias| alias.to_string(None))).in_selection(matches),
+                );
+            }
+            ScalarCondition::Search(query) => {
+                return ConditionTree::single(text_search(&[column]).matches(format!("{}", query)))
+            }
+            ScalarCondition::JsonPath(path, condition) => (json_extract(column, path).into(), *condition),
+            condition => (column.into(), condition),
         };
 
-        ConditionTree::single(condition)
+        match self.mode {
+            QueryMode::Default => ConditionTree::single(convert_scalar_condition(comparable, condition)),
+            QueryMode::Insensitive => {
+                ConditionTree::single(convert_insensitive_condition(comparable, condition, sql_family))
+            }
+        }
+    }
+}
+
+impl AliasedCondition for ScalarListFilter {
+    /// Conversion from a `ScalarListFilter` to a query condition tree. Aliased when in a nested `SELECT`.
+    /// Scalar lists are native arrays, compared with the array operators of the database:
+    /// ```sql
+    /// "tags" @> ARRAY['a']         -- has / hasEvery
+    /// "tags" && ARRAY['a', 'b']    -- hasSome
+    /// "tags" <@ ARRAY[]            -- isEmpty
+    /// ```
+    fn aliased_cond(self, alias: Option<Alias>, _: SqlFamily) -> ConditionTree<'static> {
+        let column = match alias {
+            Some(ref alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
+
+        match self.condition {
+            ScalarListCondition::Contains(value) => column.array_contains(PrismaValue::List(vec![value])).into(),
+            ScalarListCondition::ContainsEvery(values) => column.array_contains(PrismaValue::List(values)).into(),
+            ScalarListCondition::ContainsSome(values) => column.array_overlaps(PrismaValue::List(values)).into(),
+            ScalarListCondition::Equals(values) => column.equals(PrismaValue::List(values)).into(),
+
+            // Unset lists are read as empty lists.
+            ScalarListCondition::ContainsNone => ConditionTree::or(
+                column.clone().is_null(),
+                column.array_contained(PrismaValue::List(vec![])),
+            ),
+        }
+    }
+}
+
+/// Case insensitive comparisons (`InsensitiveFilters` capability). Pattern matches on Postgres use `ILIKE`,
+/// all other comparisons compare the lowercased column with the lowercased values:
+/// ```sql
+/// "name" ILIKE $1            -- contains, starts with, ends with on Postgres
+/// LOWER(`name`) LIKE ?       -- contains, starts with, ends with on MySQL and SQLite
+/// LOWER("name") = $1         -- equals, in, lt, ...
+/// ```
+/// The `LOWER()` function of SQLite only folds ASCII characters, so the values are folded the same way, and
+/// non-ASCII letters in a different case don't match on SQLite.
+fn convert_insensitive_condition(
+    comparable: DatabaseValue<'static>,
+    condition: ScalarCondition,
+    sql_family: SqlFamily,
+) -> Compare<'static> {
+    match (sql_family, condition) {
+        (SqlFamily::Postgres, ScalarCondition::Contains(value)) => {
+            comparable.compare_raw("ILIKE", format!("%{}%", value))
+        }
+        (SqlFamily::Postgres, ScalarCondition::NotContains(value)) => {
+            comparable.compare_raw("NOT ILIKE", format!("%{}%", value))
+        }
+        (SqlFamily::Postgres, ScalarCondition::StartsWith(value)) => {
+            comparable.compare_raw("ILIKE", format!("{}%", value))
+        }
+        (SqlFamily::Postgres, ScalarCondition::NotStartsWith(value)) => {
+            comparable.compare_raw("NOT ILIKE", format!("{}%", value))
+        }
+        (SqlFamily::Postgres, ScalarCondition::EndsWith(value)) => {
+            comparable.compare_raw("ILIKE", format!("%{}", value))
+        }
+        (SqlFamily::Postgres, ScalarCondition::NotEndsWith(value)) => {
+            comparable.compare_raw("NOT ILIKE", format!("%{}", value))
+        }
+        (_, condition) => {
+            let comparable: DatabaseValue<'static> = lower(comparable).into();
+            convert_scalar_condition(comparable, lowercase_condition(condition, sql_family))
+        }
+    }
+}
+
+fn lowercase_condition(condition: ScalarCondition, sql_family: SqlFamily) -> ScalarCondition {
+    let lowercase = |value: PrismaValue| match (sql_family, value) {
+        (SqlFamily::Sqlite, PrismaValue::String(s)) => PrismaValue::String(s.to_ascii_lowercase()),
+        (_, PrismaValue::String(s)) => PrismaValue::String(s.to_lowercase()),
+        (_, value) => value,
+    };
+
+    match condition {
+        ScalarCondition::Contains(value) => comparable.compare_raw("ILIKE", format!("%{}%", value)),
+        ScalarCondition::NotContains(value) => comparable.compare_raw("NOT ILIKE", format!("%{}%", value)),
+        ScalarCondition::StartsWith(value) => comparable.compare_raw("ILIKE", format!("{}%", value)),
+        ScalarCondition::NotStartsWith(value) => comparable.compare_raw("NOT ILIKE", format!("{}%", value)),
+        ScalarCondition::EndsWith(value) => comparable.compare_raw("ILIKE", format!("%{}", value)),
+        ScalarCondition::NotEndsWith(value) => comparable.compare_raw("NOT ILIKE", format!("%{}", value)),
+        condition => {
+            let comparable: DatabaseValue<'static> = lower(comparable).into();
+            convert_scalar_condition(comparable, lowercase_condition(condition))
+        }
+    }
+}
+
+fn lowercase_condition(condition: ScalarCondition) -> ScalarCondition {
+    let lowercase = |value: PrismaValue| match value {
+        PrismaValue::String(s) => PrismaValue::String(s.to_lowercase()),
+        value => value,
+    };
+
+    match condition {
+        ScalarCondition::Equals(value) => ScalarCondition::Equals(lowercase(value)),
+        ScalarCondition::NotEquals(value) => ScalarCondition::NotEquals(lowercase(value)),
+        ScalarCondition::Contains(value) => ScalarCondition::Contains(lowercase(value)),
+        ScalarCondition::NotContains(value) => ScalarCondition::NotContains(lowercase(value)),
+        ScalarCondition::StartsWith(value) => ScalarCondition::StartsWith(lowercase(value)),
+        ScalarCondition::NotStartsWith(value) => ScalarCondition::NotStartsWith(lowercase(value)),
+        ScalarCondition::EndsWith(value) => ScalarCondition::EndsWith(lowercase(value)),
+        ScalarCondition::NotEndsWith(value) => ScalarCondition::NotEndsWith(lowercase(value)),
+        ScalarCondition::LessThan(value) => ScalarCondition::LessThan(lowercase(value)),
+        ScalarCondition::LessThanOrEquals(value) => ScalarCondition::LessThanOrEquals(lowercase(value)),
+        ScalarCondition::GreaterThan(value) => ScalarCondition::GreaterThan(lowercase(value)),
+        ScalarCondition::GreaterThanOrEquals(value) => ScalarCondition::GreaterThanOrEquals(lowercase(value)),
+        ScalarCondition::In(values) => ScalarCondition::In(values.into_iter().map(lowercase).collect()),
+        ScalarCondition::NotIn(values) => ScalarCondition::NotIn(values.into_iter().map(lowercase).collect()),
+        ScalarCondition::Search(value) => ScalarCondition::Search(value),
+        ScalarCondition::JsonPath(path, condition) => {
+            ScalarCondition::JsonPath(path, Box::new(lowercase_condition(*condition, sql_family)))
+        }
+    }
+}
+
+impl AliasedCondition for AggregationFilter {
+    /// Conversion from an `AggregationFilter` to a query condition tree, to be used in a `HAVING` clause.
+    fn aliased_cond(self, alias: Option<Alias>, _: SqlFamily) -> ConditionTree<'static> {
+        let column = |field: DataSourceFieldRef| match alias {
+            Some(ref alias) => field.as_column().table(alias.to_string(None)),
+            None => field.as_column(),
+        };
+
+        let aggregation: DatabaseValue<'static> = match self.function {
+            AggregationFunction::Count => count(asterisk()).into(),
+            AggregationFunction::Average(field) => avg(column(field)).into(),
+            AggregationFunction::Sum(field) => sum(column(field)).into(),
+            AggregationFunction::Min(field) => min(column(field)).into(),
+            AggregationFunction::Max(field) => max(column(field)).into(),
+        };
+
+        ConditionTree::single(convert_scalar_condition(aggregation, self.condition))
+    }
+}
+
+fn convert_scalar_condition<T>(comparable: T, condition: ScalarCondition) -> Compare<'static>
+where
+    T: Comparable<'static>,
+{
+    match condition {
+        ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
+        ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
+        ScalarCondition::Equals(value) => comparable.equals(value),
+        ScalarCondition::NotEquals(value) => comparable.not_equals(value),
+        ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
+        ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
+        ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
+        ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
+        ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
+        ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
+        ScalarCondition::LessThan(value) => comparable.less_than(value),
+        ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
+        ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
+        ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
+        ScalarCondition::In(values) => comparable.in_selection(values),
+        ScalarCondition::NotIn(values) => comparable.not_in_selection(values),
+        ScalarCondition::Search(_) => unreachable!("Full-text searches are converted on the column."),
+        ScalarCondition::JsonPath(..) => unreachable!("JSON path conditions are converted on the column."),
     }
 }
 
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, sql_family: SqlFamily) -> ConditionTree<'static> {
         let identifier = self.field.model().primary_identifier();
         let ids = identifier.as_columns();
 
@@ -174,7 +358,7 @@ impl AliasedCondition for RelationFilter {
         };
 
         let condition = self.condition.clone();
-        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)));
+        let sub_select = self.aliased_sel(alias.map(|a| a.inc(AliasMode::Table)), sql_family);
 
         let comparison = match condition {
             RelationCondition::AtLeastOneRelatedRecord => Row::from(columns).in_selection(sub_select),
@@ -189,7 +373,7 @@ impl AliasedCondition for RelationFilter {
 
 impl AliasedSelect for RelationFilter {
     /// The subselect part of the `RelationFilter` `ConditionTree`.
-    fn aliased_sel(self, alias: Option<Alias>) -> Select<'static> {
+    fn aliased_sel(self, alias: Option<Alias>, sql_family: SqlFamily) -> Select<'static> {
         let alias = alias.unwrap_or(Alias::default());
         let condition = self.condition.clone();
         let relation = self.field.relation();
@@ -220,7 +404,7 @@ impl AliasedSelect for RelationFilter {
             // Don't do the useless join
             let conditions = self
                 .nested_filter
-                .aliased_cond(Some(alias))
+                .aliased_cond(Some(alias), sql_family)
                 .invert_if(condition.invert_of_subselect());
 
             let select_base = Select::from_table(relation.as_table().alias(alias.to_string(None))).so_that(conditions);
@@ -243,7 +427,7 @@ impl AliasedSelect for RelationFilter {
 
             let conditions = self
                 .nested_filter
-                .aliased_cond(Some(alias.flip(AliasMode::Join)))
+                .aliased_cond(Some(alias.flip(AliasMode::Join)), sql_family)
                 .invert_if(condition.invert_of_subselect());
 
             let join = related_table
@@ -260,7 +444,7 @@ impl AliasedSelect for RelationFilter {
 
 impl AliasedCondition for OneRelationIsNullFilter {
     /// Conversion from a `OneRelationIsNullFilter` to a query condition tree. Aliased when in a nested `SELECT`.
-    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+    fn aliased_cond(self, alias: Option<Alias>, _: SqlFamily) -> ConditionTree<'static> {
         let alias = alias.map(|a| a.to_string(None));
 
         let condition = if self.field.relation_is_inlined_in_parent() {
@@ -303,3 +487,26 @@ impl AliasedCondition for OneRelationIsNullFilter {
         ConditionTree::single(condition)
     }
 }
+
+/// The name of the FTS5 table SQLite searches the field with. The migration engine names it like the `@@fulltext`
+/// index on the field, which defaults to `<table>.<column>`.
+pub(crate) fn fulltext_table_name(field: &ScalarFieldRef) -> String {
+    let model = field.model();
+    let index = model
+        .fulltext_index_on(field)
+        .expect("Full-text searches on SQLite require a fulltext index on the field.");
+
+    index
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("{}.{}", model.db_name(), field.db_name()))
+}
+
+pub(crate) fn fulltext_table(field: &ScalarFieldRef) -> Table<'static> {
+    (field.internal_data_model().db_name.clone(), fulltext_table_name(field)).into()
+}
+
+/// The row id of the record of the field, which the FTS5 tables refer to.
+pub(crate) fn rowid_column(field: &ScalarFieldRef, alias: Option<String>) -> Column<'static> {
+    Column::from((field.model().as_table(), "rowid")).opt_table(alias)
+}
@@ -2,16 +2,19 @@ mod cursor_condition;
 mod database;
 mod error;
 mod filter_conversion;
+mod metrics;
 mod ordering;
 mod query_builder;
 mod query_ext;
 mod raw_query;
 mod row;
+mod statement_recorder;
 
 use filter_conversion::*;
 use query_ext::QueryExt;
 use raw_query::*;
 use row::*;
+use statement_recorder::StatementRecorder;
 
 pub use database::*;
 pub use error::SqlError;
@@ -0,0 +1,53 @@
+//! Prometheus metrics of the connection pool, registered in the default registry of the `prometheus` crate.
+use once_cell::sync::Lazy;
+use prometheus::{register_histogram, register_int_counter, register_int_gauge, Histogram, IntCounter, IntGauge};
+use std::time::Instant;
+
+static BUSY_CONNECTIONS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "prisma_pool_connections_busy",
+        "Number of connections currently checked out of the pool."
+    )
+    .unwrap()
+});
+
+static CHECKOUT_DURATION: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "prisma_pool_checkout_duration_seconds",
+        "Time spent waiting for a connection from the pool in seconds."
+    )
+    .unwrap()
+});
+
+static CHECKOUT_ERRORS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "prisma_pool_checkout_errors_total",
+        "Number of failed attempts to check out a connection, e.g. because the pool timed out."
+    )
+    .unwrap()
+});
+
+/// Records an attempt to check out a connection from the pool.
+pub(crate) fn observe_checkout<T, E>(started: Instant, result: &Result<T, E>) {
+    CHECKOUT_DURATION.observe(started.elapsed().as_secs_f64());
+
+    if result.is_err() {
+        CHECKOUT_ERRORS.inc();
+    }
+}
+
+/// Counts a connection as busy for as long as it is checked out.
+pub(crate) struct CheckedOutConnection(());
+
+impl CheckedOutConnection {
+    pub(crate) fn new() -> Self {
+        BUSY_CONNECTIONS.inc();
+        Self(())
+    }
+}
+
+impl Drop for CheckedOutConnection {
+    fn drop(&mut self) {
+        BUSY_CONNECTIONS.dec();
+    }
+}
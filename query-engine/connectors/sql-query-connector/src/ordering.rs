@@ -1,6 +1,7 @@
+use crate::filter_conversion::{fulltext_table, fulltext_table_name, rowid_column, Alias, AliasMode};
 use connector_interface::OrderDirections;
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 
 pub type OrderVec<'a> = Vec<(DatabaseValue<'a>, Option<Order>)>;
 
@@ -8,76 +9,187 @@ pub struct Ordering;
 
 /// Tooling for generating orderings for different query types.
 impl Ordering {
-    pub fn for_model(model: &ModelRef, order_directive: OrderDirections) -> OrderVec<'static> {
-        Self::by_fields(
-            order_directive
-                .primary_order_by
-                .as_ref()
-                .map(|oby| oby.field.as_column()),
-            model.primary_identifier().as_columns().collect(),
-            order_directive,
-        )
+    /// Orders by all given order bys, in order. If none of the ordered fields is unique
+    /// and an implicit ordering is required, the primary identifier columns that are not
+    /// already ordered by are appended to guarantee a stable ordering.
+    pub fn for_model(model: &ModelRef, order_directive: OrderDirections, sql_family: SqlFamily) -> OrderVec<'static> {
+        let reverse = order_directive.needs_to_be_reverse_order;
+
+        let mut order_vec: OrderVec<'static> = order_directive
+            .order_by
+            .iter()
+            .map(|order_by| {
+                Self::order_definition(
+                    Self::order_by_value(order_by, sql_family),
+                    Self::sort_order(order_by, sql_family),
+                    reverse,
+                )
+            })
+            .collect();
+
+        let is_unique = order_directive.order_by.iter().any(|order_by| order_by.is_unique());
+
+        if order_directive.needs_implicit_id_ordering && !is_unique {
+            for column in Self::implicit_identifier_columns(model, &order_directive.order_by) {
+                order_vec.push(Self::order_definition(column.into(), SortOrder::Ascending, reverse));
+            }
+        }
+
+        order_vec
+    }
+
+    /// The primary identifier columns of the model that are not already ordered by.
+    pub fn implicit_identifier_columns(model: &ModelRef, order_by: &[OrderBy]) -> Vec<Column<'static>> {
+        let ordered_columns: Vec<Column<'static>> = order_by
+            .iter()
+            .filter_map(|order_by| order_by.own_field())
+            .map(|field| field.as_column())
+            .collect();
+
+        model
+            .primary_identifier()
+            .as_columns()
+            .filter(|column| !ordered_columns.contains(column))
+            .collect()
+    }
+
+    /// The value to order by. Fields of the model itself are ordered by their column, values of related
+    /// records are retrieved with correlated sub selects on the relation, e.g. for `author__name`:
+    /// ```sql
+    /// (SELECT `j1`.`name` FROM `Post` AS `t1` INNER JOIN `User` AS `j1` ON `j1`.`id` = `t1`.`author` WHERE `t1`.`id` = `Post`.`id`)
+    /// ```
+    /// Relation counts count the rows of the relation linked to the record, e.g. for `comments__count`:
+    /// ```sql
+    /// (SELECT COUNT(*) FROM `Comment` AS `t1` WHERE `t1`.`post` = `Post`.`id`)
+    /// ```
+    /// Relevance orderings use the full-text search relevance of the field for the search query:
+    /// ```sql
+    /// ts_rank(to_tsvector("title"), to_tsquery($1))                                                    -- Postgres
+    /// MATCH (`title`) AGAINST (? IN BOOLEAN MODE)                                                       -- MySQL
+    /// (SELECT "rank" FROM "Post.title" WHERE "Post.title" MATCH ? AND "rowid" = "Post"."rowid")        -- SQLite
+    /// ```
+    pub fn order_by_value(order_by: &OrderBy, sql_family: SqlFamily) -> DatabaseValue<'static> {
+        Self::path_value(&order_by.path, &order_by.target, None, Alias::default(), sql_family)
+    }
+
+    /// The direction the value of the order by is sorted in. SQLite ranks full-text matches with `bm25`,
+    /// which is lower for better matches, so relevance orderings are sorted the other way around there.
+    pub fn sort_order(order_by: &OrderBy, sql_family: SqlFamily) -> SortOrder {
+        match (&order_by.target, order_by.sort_order) {
+            (OrderByTarget::Relevance { .. }, SortOrder::Ascending) if sql_family.is_sqlite() => SortOrder::Descending,
+            (OrderByTarget::Relevance { .. }, SortOrder::Descending) if sql_family.is_sqlite() => SortOrder::Ascending,
+            (_, sort_order) => sort_order,
+        }
     }
 
-    fn by_fields(
-        first_column: Option<Column<'static>>,
-        identifier: Vec<Column<'static>>,
-        order_directive: OrderDirections,
-    ) -> OrderVec<'static> {
-        match order_directive.primary_order_by {
-            Some(order_by) => {
-                let first = first_column.unwrap();
-                let size_hint = identifier.len() + 1;
-
-                if !identifier.contains(&first)
-                    && order_directive.needs_implicit_id_ordering
-                    && !order_by.field.unique()
-                {
-                    match (order_by.sort_order, order_directive.needs_to_be_reverse_order) {
-                        (SortOrder::Ascending, true) => {
-                            Self::merge_columns(first.descend(), identifier.into_iter().map(|c| c.descend()), size_hint)
-                        }
-                        (SortOrder::Descending, true) => {
-                            Self::merge_columns(first.ascend(), identifier.into_iter().map(|c| c.descend()), size_hint)
-                        }
-                        (SortOrder::Ascending, false) => {
-                            Self::merge_columns(first.ascend(), identifier.into_iter().map(|c| c.ascend()), size_hint)
-                        }
-                        (SortOrder::Descending, false) => {
-                            Self::merge_columns(first.descend(), identifier.into_iter().map(|c| c.ascend()), size_hint)
-                        }
-                    }
+    fn path_value(
+        path: &[RelationFieldRef],
+        target: &OrderByTarget,
+        parent_alias: Option<String>,
+        alias: Alias,
+        sql_family: SqlFamily,
+    ) -> DatabaseValue<'static> {
+        match (path.split_first(), target) {
+            (None, OrderByTarget::Field(field)) => field.as_column().opt_table(parent_alias).into(),
+            (None, OrderByTarget::Count) => unreachable!("Ordering by a relation count requires a relation."),
+            (None, OrderByTarget::Relevance { field, search }) => {
+                let search = search.clone().expect("Ordering by relevance requires a search query.");
+
+                if sql_family.is_sqlite() {
+                    let matches = Column::from(fulltext_table_name(field))
+                        .compare_raw("MATCH", search)
+                        .and(Column::from("rowid").equals(rowid_column(field, parent_alias)));
+
+                    Select::from_table(fulltext_table(field))
+                        .column("rank")
+                        .so_that(matches)
+                        .into()
                 } else {
-                    match (order_by.sort_order, order_directive.needs_to_be_reverse_order) {
-                        (SortOrder::Ascending, true) => vec![first.descend()],
-                        (SortOrder::Descending, true) => vec![first.ascend()],
-                        (SortOrder::Ascending, false) => vec![first.ascend()],
-                        (SortOrder::Descending, false) => vec![first.descend()],
-                    }
+                    text_search_relevance(&[field.as_column().opt_table(parent_alias)], search).into()
                 }
             }
-            None if order_directive.needs_implicit_id_ordering && order_directive.needs_to_be_reverse_order => {
-                identifier.into_iter().map(|c| c.descend()).collect()
+            (Some((rf, [])), OrderByTarget::Count) => {
+                let alias = alias.inc(AliasMode::Table);
+                let table = rf.relation().as_table().alias(alias.to_string(None));
+
+                Select::from_table(table)
+                    .value(count(asterisk()))
+                    .so_that(Self::parent_condition(rf, parent_alias, alias))
+                    .into()
             }
-            None if order_directive.needs_implicit_id_ordering && !order_directive.needs_to_be_reverse_order => {
-                identifier.into_iter().map(|c| c.ascend()).collect()
+            (Some((rf, rest)), target) => {
+                let alias = alias.inc(AliasMode::Table);
+                let related_model = rf.related_model();
+                let table = rf.relation().as_table().alias(alias.to_string(None));
+
+                let identifiers: Vec<_> = Self::linked_columns(&rf.related_field())
+                    .into_iter()
+                    .map(|c| c.table(alias.to_string(Some(AliasMode::Join))))
+                    .collect();
+
+                let other_columns: Vec<_> = rf
+                    .opposite_columns(false)
+                    .map(|c| c.table(alias.to_string(None)))
+                    .collect();
+
+                let join = related_model
+                    .as_table()
+                    .alias(alias.to_string(Some(AliasMode::Join)))
+                    .on(Row::from(identifiers).equals(Row::from(other_columns)));
+
+                let value = Self::path_value(
+                    rest,
+                    target,
+                    Some(alias.to_string(Some(AliasMode::Join))),
+                    alias,
+                    sql_family,
+                );
+
+                Select::from_table(table)
+                    .inner_join(join)
+                    .value(value)
+                    .so_that(Self::parent_condition(rf, parent_alias, alias))
+                    .into()
             }
-            None => Vec::new(),
         }
     }
 
-    fn merge_columns(
-        first: OrderDefinition<'static>,
-        rest: impl IntoIterator<Item = OrderDefinition<'static>>,
-        size_hint: usize,
-    ) -> OrderVec<'static> {
-        let mut order_vec = Vec::with_capacity(size_hint);
-        order_vec.push(first);
+    /// Links the rows of the relation table to the (outer) parent record.
+    fn parent_condition(rf: &RelationFieldRef, parent_alias: Option<String>, alias: Alias) -> Compare<'static> {
+        let relation_columns: Vec<_> = rf
+            .relation_columns(false)
+            .map(|c| c.table(alias.to_string(None)))
+            .collect();
+
+        let parent_columns: Vec<_> = Self::linked_columns(rf)
+            .into_iter()
+            .map(|c| c.opt_table(parent_alias.clone()))
+            .collect();
+
+        Row::from(relation_columns).equals(Row::from(parent_columns))
+    }
 
-        for col in rest.into_iter() {
-            order_vec.push(col);
+    /// The columns of the model of the relation field its relation columns refer to. If the relation is inlined
+    /// in the table of the model, the relation table is that table and its rows are identified by the primary
+    /// identifier. Otherwise the relation columns hold the values of the referenced fields (`references: [...]`).
+    fn linked_columns(rf: &RelationFieldRef) -> Vec<Column<'static>> {
+        if rf.relation_is_inlined_in_parent() {
+            rf.model().primary_identifier().as_columns().collect()
+        } else {
+            rf.linking_fields().as_columns().collect()
         }
+    }
 
-        order_vec
+    fn order_definition(
+        value: DatabaseValue<'static>,
+        sort_order: SortOrder,
+        reverse: bool,
+    ) -> OrderDefinition<'static> {
+        match (sort_order, reverse) {
+            (SortOrder::Ascending, true) => (value, Some(Order::Desc)),
+            (SortOrder::Descending, true) => (value, Some(Order::Asc)),
+            (SortOrder::Ascending, false) => (value, Some(Order::Asc)),
+            (SortOrder::Descending, false) => (value, Some(Order::Desc)),
+        }
     }
 }
@@ -6,10 +6,56 @@ pub use write::*;
 
 use prisma_models::RecordProjection;
 use prisma_value::PrismaValue;
-use quaint::ast::{Column, Comparable, ConditionTree, Query};
+use quaint::{
+    ast::{Column, Comparable, ConditionTree, Query},
+    prelude::ConnectionInfo,
+};
 
 const PARAMETER_LIMIT: usize = 10000;
 
+/// The maximum number of bind parameters a single statement can have on the database.
+pub(crate) fn parameter_limit(connection_info: &ConnectionInfo) -> usize {
+    match connection_info {
+        ConnectionInfo::Postgres(_) => 32767,
+        ConnectionInfo::Mysql(_) => 65535,
+        // Default of `SQLITE_MAX_VARIABLE_NUMBER` before SQLite 3.32.
+        ConnectionInfo::Sqlite { .. } => 999,
+    }
+}
+
+/// Whether the database supports `SELECT DISTINCT ON`.
+pub(crate) fn supports_distinct_on(connection_info: &ConnectionInfo) -> bool {
+    match connection_info {
+        ConnectionInfo::Postgres(_) => true,
+        _ => false,
+    }
+}
+
+/// Whether the database supports window functions like `ROW_NUMBER() OVER (PARTITION BY ...)`, judging by its version
+/// string where necessary. Postgres has them since 8.4 and the bundled SQLite is newer than 3.25, while MySQL only got
+/// them with version 8 and MariaDB with 10.2.
+pub(crate) fn supports_row_number(connection_info: &ConnectionInfo, version: Option<&str>) -> bool {
+    match connection_info {
+        ConnectionInfo::Mysql(_) => version.map(mysql_supports_row_number).unwrap_or(false),
+        _ => true,
+    }
+}
+
+fn mysql_supports_row_number(version: &str) -> bool {
+    let mut numbers = version
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|part| part.parse::<u32>().ok());
+
+    let major = numbers.next().unwrap_or(0);
+    let minor = numbers.next().unwrap_or(0);
+
+    if version.contains("MariaDB") {
+        (major, minor) >= (10, 2)
+    } else {
+        major >= 8
+    }
+}
+
 pub(super) fn chunked_conditions<F, Q>(
     columns: &[Column<'static>],
     records: &[&RecordProjection],
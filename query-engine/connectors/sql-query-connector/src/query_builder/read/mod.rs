@@ -1,41 +1,43 @@
 use crate::{cursor_condition, filter_conversion::AliasedCondition, ordering::Ordering};
-use connector_interface::{filter::Filter, QueryArguments};
+use connector_interface::{filter::Filter, Aggregator, OrderDirections, QueryArguments};
 use prisma_models::*;
-use quaint::ast::*;
+use quaint::{ast::*, prelude::SqlFamily};
 use std::sync::Arc;
 
+const ROW_NUMBER_ALIAS: &str = "prisma_row_number";
+
 pub trait SelectDefinition {
-    fn into_select(self, _: &ModelRef) -> Select<'static>;
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static>;
 }
 
 impl SelectDefinition for Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
         let args = QueryArguments::from(self);
-        args.into_select(model)
+        args.into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for &Filter {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        self.clone().into_select(model)
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
+        self.clone().into_select(model, sql_family)
     }
 }
 
 impl SelectDefinition for Select<'static> {
-    fn into_select(self, _: &ModelRef) -> Select<'static> {
+    fn into_select(self, _: &ModelRef, _: SqlFamily) -> Select<'static> {
         self
     }
 }
 
 impl SelectDefinition for QueryArguments {
-    fn into_select(self, model: &ModelRef) -> Select<'static> {
-        let cursor: ConditionTree = cursor_condition::build(&self, Arc::clone(&model));
+    fn into_select(self, model: &ModelRef, sql_family: SqlFamily) -> Select<'static> {
+        let cursor: ConditionTree = cursor_condition::build(&self, Arc::clone(&model), sql_family);
         let ordering_directions = self.ordering_directions();
-        let ordering = Ordering::for_model(&model, ordering_directions);
+        let ordering = Ordering::for_model(&model, ordering_directions, sql_family);
 
         let filter: ConditionTree = self
             .filter
-            .map(|f| f.aliased_cond(None))
+            .map(|f| f.aliased_cond(None, sql_family))
             .unwrap_or(ConditionTree::NoCondition);
 
         let conditions = match (filter, cursor) {
@@ -62,17 +64,269 @@ impl SelectDefinition for QueryArguments {
     }
 }
 
-pub fn get_records<T>(model: &ModelRef, columns: impl Iterator<Item = Column<'static>>, query: T) -> Select<'static>
+pub fn get_records<T>(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query: T,
+    sql_family: SqlFamily,
+) -> Select<'static>
 where
     T: SelectDefinition,
 {
-    columns.fold(query.into_select(model), |acc, col| acc.column(col))
+    columns.fold(query.into_select(model, sql_family), |acc, col| acc.column(col))
+}
+
+/// Whether the records can be made distinct in the database. Without `DISTINCT ON`, the record with the lowest
+/// identifier of each set of distinct records is picked. That is only the first record of the set without an
+/// explicit ordering, and requires a single identifier column.
+pub fn supports_distinct(model: &ModelRef, query_arguments: &QueryArguments, distinct_on: bool) -> bool {
+    distinct_on || (query_arguments.order_by.is_empty() && model.primary_identifier().len() == 1)
+}
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT ... FROM `Table` WHERE ... AND (`id`) IN (SELECT DISTINCT ON (`field`, ...) `id` FROM `Table` WHERE ... ORDER BY `field`, ..., ...) ORDER BY ... LIMIT ... OFFSET ...
+/// ```
+/// The sub select picks one record of each set of records with the same values for the distinct fields, which is
+/// the first one by the ordering of the query. Without `DISTINCT ON`, the record with the lowest identifier is picked:
+/// ```sql
+/// ... (`id`) IN (SELECT MIN(`id`) FROM `Table` WHERE ... GROUP BY `field`, ...) ...
+/// ```
+/// Cursors and pagination apply to the distinct records.
+pub fn get_distinct_records(
+    model: &ModelRef,
+    columns: impl Iterator<Item = Column<'static>>,
+    query_arguments: QueryArguments,
+    distinct: &[ScalarFieldRef],
+    distinct_on: bool,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let filter: ConditionTree = query_arguments
+        .filter
+        .clone()
+        .map(|f| f.aliased_cond(None, sql_family))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let id_columns: Vec<Column<'static>> = model.primary_identifier().as_columns().collect();
+    let distinct_columns: Vec<Column<'static>> = distinct.iter().map(|field| field.as_column()).collect();
+
+    let distinct_ids = if distinct_on {
+        let ordering = Ordering::for_model(
+            model,
+            OrderDirections {
+                needs_implicit_id_ordering: true,
+                needs_to_be_reverse_order: false,
+                order_by: query_arguments.order_by.clone(),
+            },
+            sql_family,
+        );
+
+        // The ordering of a `DISTINCT ON` select has to start with the distinct columns.
+        let select = Select::from_table(model.as_table())
+            .distinct_on(distinct_columns.clone())
+            .so_that(filter);
+
+        let select = distinct_columns.into_iter().fold(select, |acc, column| {
+            acc.order_by((DatabaseValue::from(column), Some(Order::Asc)))
+        });
+
+        let select = ordering.into_iter().fold(select, |acc, ord| acc.order_by(ord));
+        id_columns
+            .iter()
+            .cloned()
+            .fold(select, |acc, column| acc.column(column))
+    } else {
+        let select = Select::from_table(model.as_table())
+            .value(min(id_columns[0].clone()))
+            .so_that(filter);
+
+        distinct_columns
+            .into_iter()
+            .fold(select, |acc, column| acc.group_by(column))
+    };
+
+    get_records(model, columns, query_arguments, sql_family).and_where(Row::from(id_columns).in_selection(distinct_ids))
+}
+
+/// Whether the related records of the relation field can be paginated per parent with `ROW_NUMBER()`, see
+/// `get_related_records`. The related records have to hold the link to their parent, and can't be made distinct.
+pub fn supports_related_pagination(from_field: &RelationFieldRef, query_arguments: &QueryArguments) -> bool {
+    !from_field.relation().is_many_to_many()
+        && from_field.related_field().is_inlined_on_enclosing_model()
+        && query_arguments.distinct.is_none()
 }
 
-pub fn count_by_model(model: &ModelRef, query_arguments: QueryArguments) -> Select<'static> {
+/// Generates a query of the form:
+/// ```sql
+/// SELECT `id`, `post`, ... FROM (
+///     SELECT `Comment`.`id`, `Comment`.`post`, ..., ROW_NUMBER() OVER (PARTITION BY `Comment`.`post` ORDER BY ...) AS `prisma_row_number`
+///     FROM `Comment` WHERE ...
+/// ) AS `sub` WHERE `prisma_row_number` >= ... AND `prisma_row_number` < ... ORDER BY `post`, `prisma_row_number`
+/// ```
+/// Filters and cursors apply to all related records, while `first`, `last` and `skip` apply to the records of each
+/// parent, numbered in the order of the query arguments. With `last` the numbering runs in reverse, and the outer
+/// ordering restores the order of the query arguments.
+pub fn get_related_records(
+    from_field: &RelationFieldRef,
+    selected_fields: &SelectedFields,
+    query_arguments: QueryArguments,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let model = from_field.related_model();
+    let link_fields = from_field.related_field().linking_fields();
+    let ordering = Ordering::for_model(&model, query_arguments.ordering_directions(), sql_family);
+    let (lower, upper) = query_arguments.window_limits();
+    let reverse = query_arguments.last.is_some();
+
+    let numbering = link_fields
+        .as_columns()
+        .fold(row_number(), |acc, column| acc.partition_by(column));
+
+    let numbering = ordering.into_iter().fold(numbering, |acc, ord| acc.order_by(ord));
+
+    let mut window_arguments = query_arguments;
+    window_arguments.skip = None;
+    window_arguments.first = None;
+    window_arguments.last = None;
+
+    let window = get_records(&model, selected_fields.columns(), window_arguments, sql_family)
+        .value(Function::from(numbering).alias(ROW_NUMBER_ALIAS));
+
+    let columns: Vec<Column<'static>> = selected_fields
+        .db_names()
+        .map(|name| Column::from(name.to_owned()))
+        .collect();
+
+    let row_number_column = Column::from(ROW_NUMBER_ALIAS);
+    let in_window = ConditionTree::and(
+        row_number_column.clone().greater_than_or_equals(lower),
+        row_number_column.clone().less_than(upper),
+    );
+
+    let select = Select::from_table(Table::from(window).alias("sub"))
+        .columns(columns)
+        .so_that(in_window);
+
+    let select = link_fields.data_source_fields().fold(select, |acc, dsf| {
+        acc.order_by((DatabaseValue::from(Column::from(dsf.name.clone())), Some(Order::Asc)))
+    });
+
+    let row_number_order = if reverse { Order::Desc } else { Order::Asc };
+    select.order_by((DatabaseValue::from(row_number_column), Some(row_number_order)))
+}
+
+pub fn count_by_model(model: &ModelRef, query_arguments: QueryArguments, sql_family: SqlFamily) -> Select<'static> {
     let selected_columns = model.primary_identifier().as_columns();
-    let base_query = get_records(model, selected_columns, query_arguments);
+    let base_query = get_records(model, selected_columns, query_arguments, sql_family);
     let table = Table::from(base_query).alias("sub");
 
     Select::from_table(table).value(count(asterisk()))
 }
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT COUNT(*), AVG(`field`), ... FROM (SELECT `id`, `field`, ... FROM `Table` WHERE ... LIMIT ... OFFSET ...) AS `sub`
+/// ```
+/// The sub select takes care of applying the query arguments (filters, cursors and pagination),
+/// while the outer select computes the aggregations in the order of the given aggregators.
+pub fn aggregate(
+    model: &ModelRef,
+    aggregators: &[Aggregator],
+    query_arguments: QueryArguments,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let mut aggregated_fields: Vec<ScalarFieldRef> = Vec::new();
+
+    for field in aggregators.iter().flat_map(|aggregator| aggregator.fields()) {
+        if !aggregated_fields.iter().any(|f| f.name == field.name) {
+            aggregated_fields.push(Arc::clone(field));
+        }
+    }
+
+    let id_columns: Vec<Column<'static>> = model.primary_identifier().as_columns().collect();
+    let mut selected_columns = id_columns.clone();
+
+    for column in aggregated_fields.iter().map(|f| f.as_column()) {
+        if !id_columns.contains(&column) {
+            selected_columns.push(column);
+        }
+    }
+
+    let base_query = get_records(model, selected_columns.into_iter(), query_arguments, sql_family);
+    let table = Table::from(base_query).alias("sub");
+
+    select_aggregations(Select::from_table(table), aggregators, |field| {
+        Column::from(field.db_name().to_owned())
+    })
+}
+
+/// Generates a query of the form:
+/// ```sql
+/// SELECT `field`, ..., COUNT(*), AVG(`other`), ... FROM `Table` WHERE ... GROUP BY `field`, ... HAVING ... ORDER BY ... LIMIT ... OFFSET ...
+/// ```
+/// Cursors are not supported, as there is no unique identifier for a group of records.
+pub fn group_by_aggregate(
+    model: &ModelRef,
+    group_by: &[ScalarFieldRef],
+    aggregators: &[Aggregator],
+    query_arguments: QueryArguments,
+    having: Option<Filter>,
+    sql_family: SqlFamily,
+) -> Select<'static> {
+    let filter: ConditionTree = query_arguments
+        .filter
+        .map(|f| f.aliased_cond(None, sql_family))
+        .unwrap_or(ConditionTree::NoCondition);
+
+    let select = group_by
+        .iter()
+        .fold(Select::from_table(model.as_table()).so_that(filter), |acc, field| {
+            acc.column(field.as_column())
+        });
+
+    let select = select_aggregations(select, aggregators, |field| field.as_column());
+    let select = group_by
+        .iter()
+        .fold(select, |acc, field| acc.group_by(field.as_column()));
+
+    let select = match having {
+        Some(having) => select.having(having.aliased_cond(None, sql_family)),
+        None => select,
+    };
+
+    let select = query_arguments.order_by.into_iter().fold(select, |acc, order_by| {
+        match Ordering::sort_order(&order_by, sql_family) {
+            SortOrder::Ascending => acc.order_by((Ordering::order_by_value(&order_by, sql_family), Some(Order::Asc))),
+            SortOrder::Descending => acc.order_by((Ordering::order_by_value(&order_by, sql_family), Some(Order::Desc))),
+        }
+    });
+
+    let select = select.offset(query_arguments.skip.unwrap_or(0) as usize);
+
+    match query_arguments.first {
+        Some(first) => select.limit(first as usize),
+        None => select,
+    }
+}
+
+/// Adds the aggregation values to the select, in the order of the given aggregators.
+fn select_aggregations<F>(select: Select<'static>, aggregators: &[Aggregator], column_for: F) -> Select<'static>
+where
+    F: Fn(&ScalarFieldRef) -> Column<'static>,
+{
+    aggregators.iter().fold(select, |select, aggregator| match aggregator {
+        Aggregator::Count => select.value(count(asterisk())),
+        Aggregator::Average(fields) => fields
+            .iter()
+            .fold(select, |acc, field| acc.value(avg(column_for(field)))),
+        Aggregator::Sum(fields) => fields
+            .iter()
+            .fold(select, |acc, field| acc.value(sum(column_for(field)))),
+        Aggregator::Min(fields) => fields
+            .iter()
+            .fold(select, |acc, field| acc.value(min(column_for(field)))),
+        Aggregator::Max(fields) => fields
+            .iter()
+            .fold(select, |acc, field| acc.value(max(column_for(field)))),
+    })
+}
@@ -1,4 +1,4 @@
-use connector_interface::WriteArgs;
+use connector_interface::{WriteArgs, WriteExpression};
 use prisma_models::*;
 use quaint::ast::*;
 
@@ -23,7 +23,7 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
         .into_iter()
         .fold(Insert::single_into(model.as_table()), |insert, db_name| {
             let value = args.take_field_value(&db_name).unwrap();
-            insert.value(db_name, value)
+            insert.value(db_name.clone(), write_expression(model, &db_name, value))
         });
 
     (
@@ -32,17 +32,100 @@ pub fn create_record(model: &ModelRef, mut args: WriteArgs) -> (Insert<'static>,
     )
 }
 
+/// Multi-row `INSERT`s of the records, chunked so that no statement exceeds the `parameter_limit` of the database.
+/// Records are grouped by the fields they set, as all rows of an `INSERT` have the same columns. Fields without a
+/// value fall back to the column default that way. With `skip_duplicates`, conflicting rows are skipped.
+pub fn create_records(
+    model: &ModelRef,
+    args: Vec<WriteArgs>,
+    skip_duplicates: bool,
+    parameter_limit: usize,
+) -> Vec<Insert<'static>> {
+    let mut groups: Vec<(Vec<String>, Vec<WriteArgs>)> = Vec::new();
+
+    for args in args {
+        let fields: Vec<String> = model
+            .fields()
+            .db_names()
+            .filter(|db_name| args.has_arg_for(db_name))
+            .collect();
+
+        match groups.iter_mut().find(|(group_fields, _)| group_fields == &fields) {
+            Some((_, records)) => records.push(args),
+            None => groups.push((fields, vec![args])),
+        }
+    }
+
+    let mut inserts = Vec::new();
+
+    for (fields, records) in groups {
+        // Records without any values can only be inserted one by one, with `DEFAULT VALUES`.
+        if fields.is_empty() {
+            for _ in records {
+                inserts.push(Insert::single_into(model.as_table()).into());
+            }
+
+            continue;
+        }
+
+        let rows_per_insert = std::cmp::max(1, parameter_limit / fields.len());
+
+        for chunk in records.chunks(rows_per_insert) {
+            let insert = chunk.iter().cloned().fold(
+                Insert::multi_into(model.as_table(), fields.clone()),
+                |insert, mut args| {
+                    let row: Vec<_> = fields
+                        .iter()
+                        .map(|db_name| write_expression(model, db_name, args.take_field_value(db_name).unwrap()))
+                        .collect();
+
+                    insert.values(row)
+                },
+            );
+
+            inserts.push(Insert::from(insert));
+        }
+    }
+
+    if skip_duplicates {
+        inserts
+            .into_iter()
+            .map(|insert| insert.on_conflict(OnConflict::DoNothing))
+            .collect()
+    } else {
+        inserts
+    }
+}
+
+/// `INSERT` a new record, or `UPDATE` the existing record with the same value for the unique `conflict_field` instead,
+/// in one statement (`ON CONFLICT ... DO UPDATE` or `ON DUPLICATE KEY UPDATE`, depending on the database).
+pub fn native_upsert(
+    model: &ModelRef,
+    conflict_field: &ScalarFieldRef,
+    create: WriteArgs,
+    update: WriteArgs,
+) -> Insert<'static> {
+    let (insert, _) = create_record(model, create);
+    let conflict_column = conflict_field.db_name().clone();
+
+    // The conflicting record must be updated for the statement to be valid, even if there is nothing to update.
+    // Columns on the right hand side are qualified, Postgres has both the table and `EXCLUDED` in scope there.
+    let update = if update.is_empty() {
+        let current_value = Column::from(conflict_column.clone()).table(model.as_table());
+        Update::table(model.as_table()).set(conflict_column.clone(), current_value)
+    } else {
+        update_args(model, Update::table(model.as_table()), update)
+    };
+
+    insert.on_conflict(OnConflict::Update(update, vec![Column::from(conflict_column)]))
+}
+
 pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs) -> crate::Result<Vec<Query<'static>>> {
     if args.args.is_empty() || ids.is_empty() {
         return Ok(Vec::new());
     }
 
-    let query = args
-        .args
-        .into_iter()
-        .fold(Update::table(model.as_table()), |acc, (name, val)| {
-            acc.set(name, val.clone())
-        });
+    let query = update_args(model, Update::table(model.as_table()), args);
 
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
     let result: Vec<Query> = super::chunked_conditions(&columns, ids, |conditions| query.clone().so_that(conditions));
@@ -50,6 +133,27 @@ pub fn update_many(model: &ModelRef, ids: &[&RecordProjection], args: WriteArgs)
     Ok(result)
 }
 
+fn update_args(model: &ModelRef, update: Update<'static>, args: WriteArgs) -> Update<'static> {
+    args.args.into_iter().fold(update, |acc, (name, expr)| {
+        let expr = write_expression(model, &name, expr);
+        acc.set(name, expr)
+    })
+}
+
+/// Renders the write expression for the column, e.g. `"Table"."col" + ?` for an addition.
+/// The column is qualified with the model table to stay unambiguous in upserts.
+fn write_expression(model: &ModelRef, db_name: &str, expr: WriteExpression) -> Expression<'static> {
+    let column = || Expression::from(Column::from(db_name.to_owned()).table(model.as_table()));
+
+    match expr {
+        WriteExpression::Value(value) => value.into(),
+        WriteExpression::Add(value) => column() + value.into(),
+        WriteExpression::Subtract(value) => column() - value.into(),
+        WriteExpression::Multiply(value) => column() * value.into(),
+        WriteExpression::Divide(value) => column() / value.into(),
+    }
+}
+
 pub fn delete_many(model: &ModelRef, ids: &[&RecordProjection]) -> Vec<Query<'static>> {
     let columns: Vec<_> = model.primary_identifier().as_columns().collect();
 
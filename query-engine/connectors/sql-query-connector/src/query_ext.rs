@@ -1,4 +1,4 @@
-use crate::{error::*, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
+use crate::{error::*, raw_query::serialize_result_set, AliasedCondition, RawQuery, SqlRow, ToSqlRow};
 use async_trait::async_trait;
 use connector_interface::filter::Filter;
 use datamodel::FieldArity;
@@ -6,11 +6,11 @@ use futures::future::FutureExt;
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
+    connector::{self, Queryable, SqlFamily},
     pooled::PooledConnection,
 };
 
-use serde_json::{Map, Number, Value};
+use serde_json::Value;
 use std::{convert::TryFrom, panic::AssertUnwindSafe};
 
 impl<'t> QueryExt for connector::Transaction<'t> {}
@@ -32,36 +32,24 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(sql_rows)
     }
 
-    /// Execute a singular SQL query in the database, returning an arbitrary
-    /// JSON `Value` as a result.
+    /// Execute a singular SQL query in the database, returning the resulting rows
+    /// as a JSON `Value`, see `raw_query::serialize_value` for the values.
     async fn raw_json<'a>(&'a self, q: RawQuery<'a>) -> std::result::Result<Value, crate::error::RawError> {
-        if q.is_select() {
-            let result_set = AssertUnwindSafe(self.query_raw(q.query(), q.parameters()))
-                .catch_unwind()
-                .await??;
+        let result_set = AssertUnwindSafe(self.query_raw(q.query(), q.parameters()))
+            .catch_unwind()
+            .await??;
 
-            let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
-            let mut result = Vec::new();
-
-            for row in result_set.into_iter() {
-                let mut object = Map::new();
-
-                for (idx, p_value) in row.into_iter().enumerate() {
-                    let column_name: String = columns[idx].clone();
-                    object.insert(column_name, Value::from(p_value));
-                }
-
-                result.push(Value::Object(object));
-            }
+        Ok(serialize_result_set(result_set))
+    }
 
-            Ok(Value::Array(result))
-        } else {
-            let changes = AssertUnwindSafe(self.execute_raw(q.query(), q.parameters()))
-                .catch_unwind()
-                .await??;
+    /// Execute a singular SQL statement in the database, returning the number of
+    /// affected rows.
+    async fn raw_count<'a>(&'a self, q: RawQuery<'a>) -> std::result::Result<usize, crate::error::RawError> {
+        let changes = AssertUnwindSafe(self.execute_raw(q.query(), q.parameters()))
+            .catch_unwind()
+            .await??;
 
-            Ok(Value::Number(Number::from(changes)))
-        }
+        Ok(changes as usize)
     }
 
     /// Select one row from the database.
@@ -91,13 +79,18 @@ pub trait QueryExt: Queryable + Send + Sync {
     }
 
     /// Read the all columns as a (primary) identifier.
-    async fn filter_ids(&self, model: &ModelRef, filter: Filter) -> crate::Result<Vec<RecordProjection>> {
+    async fn filter_ids(
+        &self,
+        model: &ModelRef,
+        filter: Filter,
+        sql_family: SqlFamily,
+    ) -> crate::Result<Vec<RecordProjection>> {
         let model_id = model.primary_identifier();
         let id_cols: Vec<Column<'static>> = model_id.as_columns().collect();
 
         let select = Select::from_table(model.as_table())
             .columns(id_cols)
-            .so_that(filter.aliased_cond(None));
+            .so_that(filter.aliased_cond(None, sql_family));
 
         self.select_ids(select, model_id).await
     }
@@ -1,12 +1,12 @@
-use prisma_value::PrismaValue;
-use quaint::ast::ParameterizedValue;
+use prisma_value::{encode_bytes, PrismaValue};
+use quaint::{ast::ParameterizedValue, connector::ResultSet};
+use serde_json::{json, Map, Value};
 
 pub struct RawQuery<'a> {
     query: String,
     parameters: Vec<ParameterizedValue<'a>>,
 }
 
-#[allow(dead_code)]
 impl<'a> RawQuery<'a> {
     pub fn new(query: String, parameters: Vec<PrismaValue>) -> Self {
         let parameters = parameters.into_iter().map(ParameterizedValue::from).collect();
@@ -15,14 +15,6 @@ impl<'a> RawQuery<'a> {
         Self { query, parameters }
     }
 
-    pub fn is_select(&self) -> bool {
-        self.query
-            .split(" ")
-            .next()
-            .map(|t| t.to_uppercase().trim() == "SELECT")
-            .unwrap_or(false)
-    }
-
     pub fn query(&self) -> &str {
         &self.query
     }
@@ -31,3 +23,38 @@ impl<'a> RawQuery<'a> {
         self.parameters.as_slice()
     }
 }
+
+/// Serializes the rows of a raw query result as an array of objects, keyed by column name.
+pub fn serialize_result_set(result_set: ResultSet) -> Value {
+    let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
+    let mut result = Vec::new();
+
+    for row in result_set.into_iter() {
+        let mut object = Map::new();
+
+        for (idx, p_value) in row.into_iter().enumerate() {
+            let column_name: String = columns[idx].clone();
+            object.insert(column_name, serialize_value(p_value));
+        }
+
+        result.push(Value::Object(object));
+    }
+
+    Value::Array(result)
+}
+
+/// Serializes a value of a raw query result. Values without a lossless JSON representation
+/// are tagged with their type, e.g. `{"prisma__type": "datetime", "prisma__value": "2020-01-01T00:00:00+00:00"}`.
+pub fn serialize_value(value: ParameterizedValue<'_>) -> Value {
+    match value {
+        ParameterizedValue::DateTime(dt) => tagged("datetime", dt.to_rfc3339()),
+        ParameterizedValue::Real(decimal) => tagged("decimal", decimal.to_string()),
+        ParameterizedValue::Bytes(bytes) => tagged("bytes", encode_bytes(&bytes)),
+        ParameterizedValue::Array(values) => Value::Array(values.into_iter().map(serialize_value).collect()),
+        value => Value::from(value),
+    }
+}
+
+fn tagged(type_name: &str, value: impl Into<Value>) -> Value {
+    json!({ "prisma__type": type_name, "prisma__value": value.into() })
+}
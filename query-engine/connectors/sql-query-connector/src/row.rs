@@ -88,13 +88,32 @@ pub fn row_value_to_prisma_value(
 
         TypeIdentifier::Json => match p_value {
             ParameterizedValue::Null => PrismaValue::Null,
-            ParameterizedValue::Text(json) => PrismaValue::String(json.into()),
-            ParameterizedValue::Json(json) => PrismaValue::String(json.to_string()),
+            ParameterizedValue::Text(json) => PrismaValue::Json(json.into()),
+            ParameterizedValue::Json(json) => PrismaValue::Json(json.to_string()),
             _ => {
                 let error = io::Error::new(io::ErrorKind::InvalidData, "Json value not stored as text or json");
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
+        TypeIdentifier::BigInt => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Integer(i) => PrismaValue::BigInt(i),
+            ParameterizedValue::Text(s) => {
+                PrismaValue::BigInt(s.parse().map_err(|err| SqlError::ColumnReadFailure(Box::new(err)))?)
+            }
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "BigInt value not stored as int or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Bytes => match p_value {
+            ParameterizedValue::Null => PrismaValue::Null,
+            ParameterizedValue::Bytes(bytes) => PrismaValue::Bytes(bytes.into_owned()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::UUID => match p_value {
             ParameterizedValue::Null => PrismaValue::Null,
             ParameterizedValue::Text(uuid) => PrismaValue::Uuid(Uuid::parse_str(&uuid)?),
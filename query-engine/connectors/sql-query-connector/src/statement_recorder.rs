@@ -0,0 +1,154 @@
+use crate::{
+    raw_query::{serialize_result_set, serialize_value},
+    QueryExt,
+};
+use async_trait::async_trait;
+use connector_interface::RecordedStatement;
+use quaint::{
+    ast::*,
+    connector::{Queryable, ResultSet},
+    prelude::ConnectionInfo,
+    visitor::{self, Visitor},
+};
+use serde_json::{json, Value};
+use std::sync::Mutex;
+
+/// Wraps a queryable and records the statements run on it, see `Connection::start_recording_transaction`.
+/// A disabled recorder passes all statements through unchanged.
+pub struct StatementRecorder<'a, Q> {
+    inner: Q,
+    connection_info: &'a ConnectionInfo,
+    recording: Option<Recording>,
+}
+
+struct Recording {
+    explain: bool,
+    statements: Mutex<Vec<RecordedStatement>>,
+}
+
+impl<'a, Q> StatementRecorder<'a, Q>
+where
+    Q: Queryable + Send + Sync,
+{
+    pub fn disabled(inner: Q, connection_info: &'a ConnectionInfo) -> Self {
+        Self {
+            inner,
+            connection_info,
+            recording: None,
+        }
+    }
+
+    pub fn enabled(inner: Q, connection_info: &'a ConnectionInfo, explain: bool) -> Self {
+        let recording = Recording {
+            explain,
+            statements: Mutex::new(Vec::new()),
+        };
+
+        Self {
+            inner,
+            connection_info,
+            recording: Some(recording),
+        }
+    }
+
+    pub fn inner(&self) -> &Q {
+        &self.inner
+    }
+
+    pub fn statements(&self) -> Vec<RecordedStatement> {
+        match self.recording {
+            Some(ref recording) => recording.statements.lock().unwrap().clone(),
+            None => Vec::new(),
+        }
+    }
+
+    fn render<'b>(&self, query: Query<'b>) -> (String, Vec<ParameterizedValue<'b>>) {
+        match self.connection_info {
+            ConnectionInfo::Postgres(..) => visitor::Postgres::build(query),
+            ConnectionInfo::Mysql(..) => visitor::Mysql::build(query),
+            ConnectionInfo::Sqlite { .. } => visitor::Sqlite::build(query),
+        }
+    }
+
+    async fn record(&self, sql: &str, params: &[ParameterizedValue<'_>]) {
+        let recording = match self.recording {
+            Some(ref recording) => recording,
+            None => return,
+        };
+
+        let plan = if recording.explain && is_explainable(sql) {
+            Some(self.explain(sql, params).await)
+        } else {
+            None
+        };
+
+        let statement = RecordedStatement {
+            sql: sql.to_owned(),
+            parameters: params.iter().cloned().map(serialize_value).collect(),
+            plan,
+        };
+
+        recording.statements.lock().unwrap().push(statement);
+    }
+
+    /// A failing `EXPLAIN` is recorded as the plan instead of failing the statement. Note that on PostgreSQL, it
+    /// still aborts the transaction, which only ever happens for statements that would have failed anyways.
+    async fn explain(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> Value {
+        let explain = match self.connection_info {
+            ConnectionInfo::Sqlite { .. } => format!("EXPLAIN QUERY PLAN {}", sql),
+            _ => format!("EXPLAIN {}", sql),
+        };
+
+        match self.inner.query_raw(&explain, params).await {
+            Ok(result_set) => serialize_result_set(result_set),
+            Err(err) => json!({ "error": err.to_string() }),
+        }
+    }
+}
+
+/// Only data manipulating statements can be explained on all databases.
+fn is_explainable(sql: &str) -> bool {
+    let first_word = sql.trim_start().split_whitespace().next().unwrap_or("").to_uppercase();
+
+    ["SELECT", "INSERT", "UPDATE", "DELETE", "WITH"].contains(&first_word.as_str())
+}
+
+#[async_trait]
+impl<'a, Q> Queryable for StatementRecorder<'a, Q>
+where
+    Q: Queryable + Send + Sync,
+{
+    async fn query(&self, q: Query<'_>) -> quaint::Result<ResultSet> {
+        if self.recording.is_none() {
+            return self.inner.query(q).await;
+        }
+
+        let (sql, params) = self.render(q);
+        self.query_raw(&sql, &params).await
+    }
+
+    async fn execute(&self, q: Query<'_>) -> quaint::Result<u64> {
+        if self.recording.is_none() {
+            return self.inner.execute(q).await;
+        }
+
+        let (sql, params) = self.render(q);
+        self.execute_raw(&sql, &params).await
+    }
+
+    async fn query_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> quaint::Result<ResultSet> {
+        self.record(sql, params).await;
+        self.inner.query_raw(sql, params).await
+    }
+
+    async fn execute_raw(&self, sql: &str, params: &[ParameterizedValue<'_>]) -> quaint::Result<u64> {
+        self.record(sql, params).await;
+        self.inner.execute_raw(sql, params).await
+    }
+
+    async fn raw_cmd(&self, cmd: &str) -> quaint::Result<()> {
+        self.inner.raw_cmd(cmd).await
+    }
+}
+
+impl<'a, Q> QueryExt for StatementRecorder<'a, Q> where Q: Queryable + Send + Sync {}
@@ -2,6 +2,7 @@ use crate::{InterpreterError, QueryGraphBuilderError, QueryGraphError, QueryPars
 use connector::error::ConnectorError;
 use failure::Fail;
 use prisma_models::DomainError;
+use user_facing_errors::{query_engine::QueryValidationFailed, UserFacingError};
 
 // TODO: Cleanup unused errors after refactorings.
 #[derive(Debug, Fail)]
@@ -32,6 +33,35 @@ pub enum CoreError {
 
     #[fail(display = "{}", _0)]
     InterpreterError(InterpreterError),
+
+    /// An operation of a transactional batch failed, which rolled back the whole batch.
+    #[fail(
+        display = "Operation {} of the transactional batch failed, the batch was rolled back: {}",
+        index, error
+    )]
+    BatchOperationError { index: usize, error: Box<CoreError> },
+}
+
+impl CoreError {
+    /// The code of the user facing error this error is rendered as, `None` if it is unknown.
+    pub fn error_code(&self) -> Option<&'static str> {
+        match self {
+            CoreError::ConnectorError(ConnectorError {
+                user_facing_error: Some(user_facing_error),
+                ..
+            })
+            | CoreError::InterpreterError(InterpreterError::ConnectorError(ConnectorError {
+                user_facing_error: Some(user_facing_error),
+                ..
+            })) => Some(user_facing_error.error_code),
+            CoreError::QueryParserError(_)
+            | CoreError::QueryGraphBuilderError(QueryGraphBuilderError::QueryParserError(_)) => {
+                Some(QueryValidationFailed::ERROR_CODE)
+            }
+            CoreError::BatchOperationError { error, .. } => error.error_code(),
+            _ => None,
+        }
+    }
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -90,6 +120,8 @@ impl From<CoreError> for user_facing_errors::Error {
                 .unwrap()
                 .into()
             }
+            // Keeps known error codes of the failed operation. Its index is part of the message of the response error.
+            CoreError::BatchOperationError { error, .. } => (*error).into(),
             _ => user_facing_errors::Error::from_dyn_error(&err.compat()),
         }
     }
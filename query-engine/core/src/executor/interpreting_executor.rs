@@ -1,7 +1,15 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response, Responses};
+use super::{pipeline::QueryPipeline, QueryExecutor, TransactionMessage};
+use crate::{
+    metrics::{self, ActiveTransaction, OperationLabels},
+    CoreError, Explanation, Item, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response, Responses,
+};
 use async_trait::async_trait;
-use connector::{ConnectionLike, Connector};
+use connector::{ConnectionLike, Connector, Transaction};
+use futures::{
+    channel::{mpsc, oneshot},
+    StreamExt,
+};
+use std::{sync::Arc, time::Instant};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
@@ -23,25 +31,19 @@ where
             force_transactions,
         }
     }
-}
 
-#[async_trait]
-impl<C> QueryExecutor for InterpretingExecutor<C>
-where
-    C: Connector + Send + Sync,
-{
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses> {
+    async fn execute_operation(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses> {
         let conn = self.connector.get_connection().await?;
 
         // Parse, validate, and extract query graphs from query document.
         let (query, info) = QueryGraphBuilder::new(query_schema).build(operation)?;
 
         // Create pipelines for all separate queries
-        let mut responses = Responses::with_capacity(1);
         let needs_transaction = self.force_transactions || query.needs_transaction();
 
         let result = if needs_transaction {
             let tx = conn.start_transaction().await?;
+            let _active = ActiveTransaction::start();
 
             let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
             let result = QueryPipeline::new(query, interpreter, info).execute().await;
@@ -58,15 +60,207 @@ where
             QueryPipeline::new(query, interpreter, info).execute().await?
         };
 
-        match result {
-            Response::Data(key, item) => responses.insert_data(key, item),
-            Response::Error(error) => responses.insert_error(error),
+        Ok(into_responses(result))
+    }
+}
+
+#[async_trait]
+impl<C> QueryExecutor for InterpretingExecutor<C>
+where
+    C: Connector + Send + Sync,
+{
+    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses> {
+        let labels = OperationLabels::new(&operation, &query_schema);
+        let started = Instant::now();
+        let result = self.execute_operation(operation, query_schema).await;
+
+        metrics::observe_responses(&labels, started, &result);
+        result
+    }
+
+    async fn execute_batch(
+        &self,
+        operations: Vec<Operation>,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<Vec<Responses>> {
+        let conn = self.connector.get_connection().await?;
+        let tx = conn.start_transaction().await?;
+        let _active = ActiveTransaction::start();
+        let mut responses = Vec::with_capacity(operations.len());
+
+        for (index, operation) in operations.into_iter().enumerate() {
+            match execute_in_transaction(tx.as_ref(), operation, Arc::clone(&query_schema)).await {
+                Ok(result) => responses.push(result),
+                Err(error) => {
+                    // The error of the operation is the one to report, a failed rollback is only logged.
+                    if let Err(err) = tx.rollback().await {
+                        warn!("Rolling back a failed batch failed: {}", err);
+                    }
+
+                    return Err(CoreError::BatchOperationError {
+                        index,
+                        error: Box::new(error),
+                    });
+                }
+            }
         }
 
+        tx.commit().await?;
+
         Ok(responses)
     }
 
+    async fn stream(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        chunk_size: usize,
+        sink: mpsc::Sender<Item>,
+    ) -> crate::Result<()> {
+        let labels = OperationLabels::new(&operation, &query_schema);
+        let started = Instant::now();
+
+        let result = async {
+            let conn = self.connector.get_connection().await?;
+            let (query, info) = QueryGraphBuilder::new(query_schema).build(operation)?;
+
+            // Streams only read, so they never run in a transaction. Each chunk is a separate query.
+            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
+            QueryPipeline::new(query, interpreter, info)
+                .stream(chunk_size, sink)
+                .await
+        }
+        .await;
+
+        metrics::observe_request(&labels, started);
+
+        if let Err(ref err) = result {
+            metrics::observe_error(err);
+        }
+
+        result
+    }
+
+    async fn run_transaction(
+        &self,
+        query_schema: QuerySchemaRef,
+        started: oneshot::Sender<crate::Result<()>>,
+        mut messages: mpsc::Receiver<TransactionMessage>,
+    ) {
+        let conn = match self.connector.get_connection().await {
+            Ok(conn) => conn,
+            Err(err) => {
+                let _ = started.send(Err(err.into()));
+                return;
+            }
+        };
+
+        let tx = match conn.start_transaction().await {
+            Ok(tx) => tx,
+            Err(err) => {
+                let _ = started.send(Err(err.into()));
+                return;
+            }
+        };
+
+        let _ = started.send(Ok(()));
+        let _active = ActiveTransaction::start();
+
+        while let Some(message) = messages.next().await {
+            match message {
+                TransactionMessage::Execute { operation, reply } => {
+                    let result = execute_in_transaction(tx.as_ref(), operation, Arc::clone(&query_schema)).await;
+                    let _ = reply.send(result);
+                }
+
+                TransactionMessage::Commit(reply) => {
+                    let _ = reply.send(tx.commit().await.map_err(Into::into));
+                    return;
+                }
+
+                TransactionMessage::Rollback(reply) => {
+                    let _ = reply.send(tx.rollback().await.map_err(Into::into));
+                    return;
+                }
+            }
+        }
+
+        if let Err(err) = tx.rollback().await {
+            warn!("Rolling back an abandoned transaction failed: {}", err);
+        }
+    }
+
+    async fn explain(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        explain: bool,
+    ) -> crate::Result<Explanation> {
+        let conn = self.connector.get_connection().await?;
+        let (query, info) = QueryGraphBuilder::new(query_schema).build(operation)?;
+
+        let tx = conn.start_recording_transaction(explain).await?;
+        let _active = ActiveTransaction::start();
+
+        let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+        let (query_graph, result) = QueryPipeline::new(query, interpreter, info).explain().await;
+        let statements = tx.recorded_statements();
+
+        tx.rollback().await?;
+
+        // Failed operations are the interesting ones to explain, so their error is part of the explanation.
+        let response = match result {
+            Ok(result) => into_responses(result),
+            Err(err) => {
+                let mut responses = Responses::default();
+                responses.insert_error(err);
+                responses
+            }
+        };
+
+        Ok(Explanation {
+            query_graph,
+            statements,
+            response,
+        })
+    }
+
     fn primary_connector(&self) -> &'static str {
         self.primary_connector
     }
 }
+
+async fn execute_in_transaction<'conn, 'tx>(
+    tx: &'conn (dyn Transaction<'tx> + 'tx),
+    operation: Operation,
+    query_schema: QuerySchemaRef,
+) -> crate::Result<Responses>
+where
+    'tx: 'conn,
+{
+    let labels = OperationLabels::new(&operation, &query_schema);
+    let started = Instant::now();
+
+    let result = async {
+        let (query, info) = QueryGraphBuilder::new(query_schema).build(operation)?;
+        let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx));
+        let result = QueryPipeline::new(query, interpreter, info).execute().await?;
+
+        Ok::<_, CoreError>(into_responses(result))
+    }
+    .await;
+
+    metrics::observe_responses(&labels, started, &result);
+    result
+}
+
+fn into_responses(result: Response) -> Responses {
+    let mut responses = Responses::with_capacity(1);
+
+    match result {
+        Response::Data(key, item) => responses.insert_data(key, item),
+        Response::Error(error) => responses.insert_error(error),
+    }
+
+    responses
+}
@@ -10,12 +10,88 @@ mod pipeline;
 
 pub use interpreting_executor::*;
 
-use crate::{query_document::Operation, response_ir::Responses, schema::QuerySchemaRef};
+use crate::{
+    query_document::Operation,
+    response_ir::{Item, Responses},
+    schema::QuerySchemaRef,
+};
 use async_trait::async_trait;
+use connector::RecordedStatement;
+use futures::channel::{mpsc, oneshot};
+
+/// The result of a dry run, see `QueryExecutor::explain`.
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Explanation {
+    /// The query graph of the operation, rendered by `query_graph::formatters`.
+    pub query_graph: String,
+
+    /// The statements run by the connector, in order.
+    pub statements: Vec<RecordedStatement>,
+
+    /// The response the operation would have returned if it was not rolled back.
+    pub response: Responses,
+}
+
+/// Messages to an interactive transaction run by `QueryExecutor::run_transaction`.
+/// Each message carries the channel its result is replied on.
+#[derive(Debug)]
+pub enum TransactionMessage {
+    /// Executes the operation within the transaction.
+    Execute {
+        operation: Operation,
+        reply: oneshot::Sender<crate::Result<Responses>>,
+    },
+
+    /// Commits the transaction, ending it.
+    Commit(oneshot::Sender<crate::Result<()>>),
+
+    /// Rolls the transaction back, ending it.
+    Rollback(oneshot::Sender<crate::Result<()>>),
+}
 
 #[async_trait]
 pub trait QueryExecutor {
     async fn execute(&self, query_doc: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses>;
 
+    /// Executes the operations one after the other within a single transaction. If an operation fails, the
+    /// whole transaction is rolled back and a `CoreError::BatchOperationError` with the index of the failed
+    /// operation is returned.
+    async fn execute_batch(
+        &self,
+        operations: Vec<Operation>,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<Vec<Responses>>;
+
+    /// Executes a single `findMany` operation, sending its serialized records one by one into the sink
+    /// while they are read from the database in chunks of `chunk_size` records.
+    async fn stream(
+        &self,
+        query_doc: Operation,
+        query_schema: QuerySchemaRef,
+        chunk_size: usize,
+        sink: mpsc::Sender<Item>,
+    ) -> crate::Result<()>;
+
+    /// Runs an interactive transaction spanning several operations: Opens a transaction, then executes the
+    /// operations received on `messages` within it until it is committed or rolled back. If all senders are
+    /// dropped before, e.g. because the transaction timed out, the transaction is rolled back.
+    /// `started` receives the result of opening the transaction.
+    async fn run_transaction(
+        &self,
+        query_schema: QuerySchemaRef,
+        started: oneshot::Sender<crate::Result<()>>,
+        messages: mpsc::Receiver<TransactionMessage>,
+    );
+
+    /// Executes the operation within a transaction that is always rolled back, recording the statements the
+    /// connector runs, see `Explanation`. With `explain`, the query plans of the statements are recorded as well.
+    async fn explain(
+        &self,
+        query_doc: Operation,
+        query_schema: QuerySchemaRef,
+        explain: bool,
+    ) -> crate::Result<Explanation>;
+
     fn primary_connector(&self) -> &'static str;
 }
@@ -1,4 +1,9 @@
-use crate::{Env, Expression, Expressionista, IrSerializer, QueryInterpreter, QueryType, Response};
+use crate::{
+    interpreter::query_interpreters::read, CoreError, Env, Expression, Expressionista, IrSerializer, Item,
+    ManyRecordsQuery, Node, Query, QueryGraph, QueryInterpreter, QueryType, ReadQuery, RecordSelection, Response,
+};
+use connector::{ConnectionLike, QueryArguments};
+use futures::{channel::mpsc, SinkExt, StreamExt};
 
 pub struct QueryPipeline<'conn, 'tx> {
     query: QueryType,
@@ -16,6 +21,19 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
     }
 
     pub async fn execute(self) -> crate::Result<Response> {
+        self.run(None).await
+    }
+
+    /// Executes the query like `execute`, additionally returning the rendered query graph, which is empty if the
+    /// graph could not be finalized. Raw queries have no graph and are described instead.
+    pub async fn explain(self) -> (String, crate::Result<Response>) {
+        let mut plan = String::new();
+        let result = self.run(Some(&mut plan)).await;
+
+        (plan, result)
+    }
+
+    async fn run(self, plan: Option<&mut String>) -> crate::Result<Response> {
         let serializer = self.serializer;
 
         match self.query {
@@ -24,18 +42,30 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
                 graph.finalize()?;
                 trace!("{}", graph);
 
+                if let Some(plan) = plan {
+                    *plan = format!("{}", graph);
+                }
+
                 let expr = Expressionista::translate(graph)?;
                 let result = self.interpreter.interpret(expr, Env::default(), 0).await;
 
                 trace!("{}", self.interpreter.log_output());
                 Ok(serializer.serialize(result?))
             }
-            QueryType::Raw { query, parameters } => {
-                trace!("Raw query: {} ({:?})", query, parameters);
+            QueryType::Raw {
+                raw_type,
+                query,
+                parameters,
+            } => {
+                trace!("Raw query ({:?}): {} ({:?})", raw_type, query, parameters);
+
+                if let Some(plan) = plan {
+                    *plan = format!("Raw query ({:?}): {}", raw_type, query);
+                }
 
                 let result = self
                     .interpreter
-                    .interpret(Expression::raw(query, parameters), Env::default(), 0)
+                    .interpret(Expression::raw(raw_type, query, parameters), Env::default(), 0)
                     .await;
 
                 trace!("{}", self.interpreter.log_output());
@@ -44,4 +74,83 @@ impl<'conn, 'tx> QueryPipeline<'conn, 'tx> {
             }
         }
     }
+
+    /// Executes a `findMany` query chunk by chunk and sends the serialized records one by one into the sink.
+    /// Nested selections are resolved per chunk, so at most one chunk of records is held in memory at once.
+    /// Stops early without error if the receiving end of the sink is dropped.
+    pub async fn stream(self, chunk_size: usize, mut sink: mpsc::Sender<Item>) -> crate::Result<()> {
+        let query = match self.query {
+            QueryType::Graph(graph) => Self::streamed_query(graph)?,
+            QueryType::Raw { .. } => return Err(Self::unsupported_stream()),
+        };
+
+        if query.args.last.is_some() {
+            return Err(CoreError::UnsupportedFeatureError(
+                "Streamed queries do not support `last`.".to_owned(),
+            ));
+        }
+
+        if query.args.distinct.is_some() {
+            return Err(CoreError::UnsupportedFeatureError(
+                "Streamed queries do not support `distinct`.".to_owned(),
+            ));
+        }
+
+        let conn = match self.interpreter.conn {
+            ConnectionLike::Connection(conn) => conn,
+            ConnectionLike::Transaction(_) => {
+                return Err(CoreError::UnsupportedFeatureError(
+                    "Queries can't be streamed within transactions.".to_owned(),
+                ))
+            }
+        };
+
+        let model_id = query.model.primary_identifier();
+        let mut chunks = conn.get_many_records_stream(
+            query.model.clone(),
+            query.args.clone(),
+            query.selected_fields.only_scalar_and_inlined(),
+            chunk_size,
+        );
+
+        while let Some(scalars) = chunks.next().await {
+            let scalars = scalars?;
+            let nested = read::process_nested(&self.interpreter.conn, query.nested.clone(), Some(&scalars)).await?;
+
+            // The connector already applied the pagination to the chunks, nothing is trimmed on serialization.
+            let records = self.serializer.serialize_records(RecordSelection {
+                name: query.name.clone(),
+                fields: query.selection_order.clone(),
+                query_arguments: QueryArguments::default(),
+                model_id: model_id.clone(),
+                scalars,
+                nested,
+            })?;
+
+            for record in records {
+                if sink.send(record).await.is_err() {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Only a plain `findMany` query, which is a graph of a single many records query, can be streamed.
+    fn streamed_query(mut graph: QueryGraph) -> crate::Result<ManyRecordsQuery> {
+        let mut roots = graph.root_nodes();
+
+        match (roots.pop(), roots.is_empty()) {
+            (Some(root), true) if graph.child_pairs(&root).is_empty() => match graph.pluck_node(&root) {
+                Node::Query(Query::Read(ReadQuery::ManyRecordsQuery(query))) => Ok(query),
+                _ => Err(Self::unsupported_stream()),
+            },
+            _ => Err(Self::unsupported_stream()),
+        }
+    }
+
+    fn unsupported_stream() -> CoreError {
+        CoreError::UnsupportedFeatureError("Only findMany queries can be streamed.".to_owned())
+    }
 }
@@ -1,5 +1,5 @@
 use super::{Env, ExpressionResult, InterpretationResult};
-use crate::{Query, WriteQuery};
+use crate::{Query, RawQueryType, WriteQuery};
 use prisma_value::PrismaValue;
 
 pub enum Expression {
@@ -40,8 +40,12 @@ pub enum Expression {
 }
 
 impl Expression {
-    pub fn raw(query: String, parameters: Vec<PrismaValue>) -> Self {
-        let query = Query::Write(WriteQuery::Raw { query, parameters });
+    pub fn raw(raw_type: RawQueryType, query: String, parameters: Vec<PrismaValue>) -> Self {
+        let query = Query::Write(WriteQuery::Raw {
+            raw_type,
+            query,
+            parameters,
+        });
         Self::Query { query }
     }
 }
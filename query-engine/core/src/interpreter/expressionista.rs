@@ -181,15 +181,22 @@ impl Expressionista {
     ) -> InterpretationResult<Expression> {
         match graph.node_content(node).unwrap() {
             Node::Flow(Flow::If(_)) => {
+                let node_id = node.id();
                 let child_pairs = graph.child_pairs(node);
 
                 // Graph validation guarantees this succeeds.
-                let (mut then_pair, mut else_pair): (Vec<(EdgeRef, NodeRef)>, Vec<(EdgeRef, NodeRef)>) = child_pairs
+                let (arm_pairs, other_pairs): (Vec<(EdgeRef, NodeRef)>, Vec<(EdgeRef, NodeRef)>) = child_pairs
+                    .into_iter()
+                    .partition(|(edge, _)| match graph.edge_content(&edge).unwrap() {
+                        QueryGraphDependency::Then | QueryGraphDependency::Else => true,
+                        _ => false,
+                    });
+
+                let (mut then_pair, mut else_pair): (Vec<(EdgeRef, NodeRef)>, Vec<(EdgeRef, NodeRef)>) = arm_pairs
                     .into_iter()
                     .partition(|(edge, _)| match graph.edge_content(&edge).unwrap() {
                         QueryGraphDependency::Then => true,
-                        QueryGraphDependency::Else => false,
-                        _ => unreachable!(),
+                        _ => false,
                     });
 
                 let then_pair = then_pair.pop().unwrap();
@@ -202,6 +209,13 @@ impl Expressionista {
                     .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
                     .collect::<InterpretationResult<Vec<Expression>>>()?;
 
+                // All other children depend on the result of the arm that ran.
+                let exprs: Vec<Expression> = other_pairs
+                    .into_iter()
+                    .filter(|(_, child_node)| graph.is_direct_child(node, child_node))
+                    .map(|(_, node)| Self::build_expression(graph, &node, graph.incoming_edges(&node)))
+                    .collect::<InterpretationResult<_>>()?;
+
                 let node = graph.pluck_node(node);
                 let into_expr = Box::new(move |node: Node| {
                     let flow: Flow = node.try_into()?;
@@ -211,12 +225,35 @@ impl Expressionista {
                             then: vec![then_expr],
                             else_: else_expr,
                         }),
+                        _ => unreachable!(),
+                    }
+                });
+
+                let expr = Self::transform_node(graph, parent_edges, node, into_expr)?;
+
+                if exprs.is_empty() {
+                    Ok(expr)
+                } else {
+                    Ok(Expression::Let {
+                        bindings: vec![Binding { name: node_id, expr }],
+                        expressions: exprs,
+                    })
+                }
+            }
+            Node::Flow(Flow::Return(_)) => {
+                let node = graph.pluck_node(node);
+                let into_expr = Box::new(move |node: Node| {
+                    let flow: Flow = node.try_into()?;
+                    match flow {
+                        Flow::Return(projections) => Ok(Expression::Return {
+                            result: ExpressionResult::FixedResult(projections.unwrap_or_default()),
+                        }),
+                        _ => unreachable!(),
                     }
                 });
 
                 Self::transform_node(graph, parent_edges, node, into_expr)
             }
-            _ => unreachable!(),
         }
     }
 
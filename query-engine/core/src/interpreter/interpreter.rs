@@ -14,6 +14,9 @@ use prisma_models::prelude::*;
 pub enum ExpressionResult {
     Query(QueryResult),
     Computation(ComputationResult),
+
+    /// Record projections returned as-is by a `Return` flow node.
+    FixedResult(Vec<RecordProjection>),
     Empty,
 }
 
@@ -58,6 +61,10 @@ impl ExpressionResult {
                 _ => None,
             },
 
+            Self::FixedResult(projections) if projections.iter().all(|p| model_projection.matches(p)) => {
+                Some(projections.clone())
+            }
+
             _ => None,
         };
 
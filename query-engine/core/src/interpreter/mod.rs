@@ -4,7 +4,7 @@ mod expressionista;
 mod formatters;
 mod interpreter;
 
-pub(self) mod query_interpreters;
+pub(crate) mod query_interpreters;
 
 pub use error::*;
 pub use expression::*;
@@ -1,10 +1,15 @@
 use connector::QueryArguments;
-use prisma_models::{ManyRecords, RecordProjection};
+use prisma_models::{ManyRecords, PrismaValue, RecordProjection, ScalarFieldRef};
+use std::collections::HashSet;
 
+/// Paginates records in memory, per parent record. Records without a parent (top-level records) are paginated as one set.
+/// Nested reads only fall back to this if the connector can't paginate the related records in the database.
+/// Duplicates by the `distinct` fields are removed before paginating, keeping the first record of each parent.
 pub struct NestedPagination {
     skip: Option<i64>,
     take: Option<i64>,
     needs_reversing: bool,
+    distinct: Option<Vec<ScalarFieldRef>>,
 }
 
 impl NestedPagination {
@@ -13,6 +18,7 @@ impl NestedPagination {
             skip: args.skip.clone(),
             take: args.last.or(args.first).clone(),
             needs_reversing: args.last.is_some(),
+            distinct: args.distinct.clone(),
         }
     }
 
@@ -23,15 +29,14 @@ impl NestedPagination {
         // replacement for SQL order by
         // TODO: this must also handle secondary order bys
         many_records.records.sort_by_key(|r| {
-            let values: Vec<_> = r
-                .parent_id
-                .as_ref()
-                .expect("parent id must be set on all records in order to paginate")
-                .values()
-                .collect();
+            let values: Option<Vec<_>> = r.parent_id.as_ref().map(|parent_id| parent_id.values().collect());
             values
         });
 
+        if let Some(ref distinct) = self.distinct {
+            Self::remove_duplicates(many_records, distinct);
+        }
+
         if self.needs_reversing {
             many_records.records.reverse();
         }
@@ -66,6 +71,26 @@ impl NestedPagination {
     }
 
     fn must_apply_pagination(&self) -> bool {
-        self.take.or(self.skip).is_some()
+        self.take.or(self.skip).is_some() || self.distinct.is_some()
+    }
+
+    /// Keeps the first record of each parent for each combination of values of the distinct fields.
+    fn remove_duplicates(many_records: &mut ManyRecords, distinct: &[ScalarFieldRef]) {
+        let indexes: Vec<usize> = distinct
+            .iter()
+            .filter_map(|field| {
+                many_records
+                    .field_names
+                    .iter()
+                    .position(|name| name.as_str() == field.db_name())
+            })
+            .collect();
+
+        let mut seen: HashSet<(Option<RecordProjection>, Vec<PrismaValue>)> = HashSet::new();
+
+        many_records.records.retain(|record| {
+            let values = indexes.iter().map(|index| record.values[*index].clone()).collect();
+            seen.insert((record.parent_id.clone(), values))
+        });
     }
 }
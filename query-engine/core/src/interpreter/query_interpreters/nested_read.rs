@@ -109,6 +109,8 @@ pub async fn m2m<'a, 'b>(
     Ok(scalars)
 }
 
+/// Reads the related records of a to-one or one-to-many relation. Without a paginator, the connector paginates the
+/// related records of each parent with `get_related_records`, see `ReadOperations::supports_related_pagination`.
 // [DTODO] This is implemented in an inefficient fashion, e.g. too much Arc cloning going on.
 pub async fn one2m<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
@@ -117,7 +119,7 @@ pub async fn one2m<'a, 'b>(
     parent_result: Option<&'a ManyRecords>,
     query_args: QueryArguments,
     selected_fields: &SelectedFields,
-    paginator: NestedPagination,
+    paginator: Option<NestedPagination>,
 ) -> InterpretationResult<ManyRecords> {
     let parent_model_id = parent_field.model().primary_identifier();
     let parent_link_id = parent_field.linking_fields();
@@ -195,9 +197,12 @@ pub async fn one2m<'a, 'b>(
         args
     };
 
-    let mut scalars = tx
-        .get_many_records(&parent_field.related_model(), args, selected_fields)
-        .await?;
+    let mut scalars = if paginator.is_some() {
+        tx.get_many_records(&parent_field.related_model(), args, selected_fields)
+            .await?
+    } else {
+        tx.get_related_records(parent_field, args, selected_fields).await?
+    };
 
     let child_field_names = scalars.field_names.clone();
 
@@ -249,6 +254,63 @@ pub async fn one2m<'a, 'b>(
         ));
     }
 
-    paginator.apply_pagination(&mut scalars);
+    if let Some(paginator) = paginator {
+        paginator.apply_pagination(&mut scalars);
+    }
+
     Ok(scalars)
 }
+
+/// Counts the related records of all parent records, with one grouped query per relation field.
+/// Returns the counts of each parent record in the order of the given relation fields,
+/// parents without related records count zero.
+pub async fn count_related<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    relation_fields: &[RelationFieldRef],
+    parent_result: &'a ManyRecords,
+) -> InterpretationResult<Vec<(RecordProjection, Vec<usize>)>> {
+    let parent_model_id = match relation_fields.first() {
+        Some(rf) => rf.model().primary_identifier(),
+        None => return Ok(vec![]),
+    };
+
+    let parent_ids = parent_result.projections(&parent_model_id)?;
+    let mut counts: Vec<(RecordProjection, Vec<usize>)> = parent_ids
+        .into_iter()
+        .map(|id| (id, vec![0; relation_fields.len()]))
+        .collect();
+
+    if counts.is_empty() {
+        return Ok(counts);
+    }
+
+    for (index, rf) in relation_fields.iter().enumerate() {
+        let parent_link_id = rf.linking_fields();
+        let extractor = parent_model_id.clone().merge(parent_link_id.clone());
+        let idents = vec![parent_model_id.clone(), parent_link_id];
+
+        // Link values of each parent record, in the order of the parent records.
+        let parent_links: Vec<RecordProjection> = parent_result
+            .projections(&extractor)?
+            .into_iter()
+            .map(|projection| projection.split_into(&idents).pop().unwrap())
+            .collect();
+
+        let link_counts: HashMap<Vec<PrismaValue>, usize> = tx
+            .count_related_records(rf, &parent_links)
+            .await?
+            .into_iter()
+            .map(|(link, count)| (link.values().collect(), count))
+            .collect();
+
+        for ((_, parent_counts), link) in counts.iter_mut().zip(parent_links) {
+            let link_values: Vec<PrismaValue> = link.values().collect();
+
+            if let Some(count) = link_counts.get(&link_values) {
+                parent_counts[index] = *count;
+            }
+        }
+    }
+
+    Ok(counts)
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::interpreter::query_interpreters::nested_pagination::NestedPagination;
 use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
-use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
+use connector::{self, ConnectionLike, ReadOperations};
 use futures::future::{BoxFuture, FutureExt};
 use prisma_models::ManyRecords;
 
@@ -15,7 +15,9 @@ pub fn execute<'a, 'b>(
             ReadQuery::RecordQuery(q) => read_one(tx, q).await,
             ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
             ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
+            ReadQuery::RelatedRecordsCountQuery(q) => count_related(tx, q, parent_result).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
+            ReadQuery::GroupByRecordsQuery(q) => group_by(tx, q).await,
         }
     };
 
@@ -63,12 +65,23 @@ fn read_one<'conn, 'tx>(
 }
 
 /// Queries a set of records.
+/// If the connector can't make the records distinct, duplicates are removed in memory and the records are paginated afterwards.
 fn read_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
-    query: ManyRecordsQuery,
+    mut query: ManyRecordsQuery,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
-        let scalars = tx
+        let in_memory_distinct = query.args.distinct.is_some() && !tx.supports_distinct(&query.model, &query.args);
+        let paginator = NestedPagination::new_from_query_args(&query.args);
+
+        if in_memory_distinct {
+            query.args.first = None;
+            query.args.skip = None;
+            query.args.last = None;
+            query.args.distinct = None;
+        }
+
+        let mut scalars = tx
             .get_many_records(
                 &query.model,
                 query.args.clone(),
@@ -76,6 +89,10 @@ fn read_many<'a, 'b>(
             )
             .await?;
 
+        if in_memory_distinct {
+            paginator.apply_pagination(&mut scalars);
+        }
+
         let model_id = query.model.primary_identifier();
         let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
 
@@ -93,6 +110,7 @@ fn read_many<'a, 'b>(
 }
 
 /// Queries related records for a set of parent IDs.
+/// The related records are paginated per parent by the connector if it supports it, in memory otherwise.
 fn read_related<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: RelatedRecordsQuery,
@@ -102,13 +120,32 @@ fn read_related<'a, 'b>(
         let relation = query.parent_field.relation();
         let is_m2m = relation.is_many_to_many();
         let paginator = NestedPagination::new_from_query_args(&query.args);
+        let in_db_pagination = !is_m2m
+            && query.args.is_with_pagination()
+            && tx.supports_related_pagination(&query.parent_field, &query.args);
 
+        let connector_args = query.args.clone();
+
+        // Duplicates are removed per parent, together with the pagination.
+        // The records come back paginated and in order either way, so the serialization must not trim them again.
         query.args.first = None;
         query.args.skip = None;
         query.args.last = None;
+        query.args.distinct = None;
 
         let scalars = if is_m2m {
             nested_read::m2m(tx, &query, parent_result, paginator).await?
+        } else if in_db_pagination {
+            nested_read::one2m(
+                tx,
+                &query.parent_field,
+                query.parent_projections,
+                parent_result,
+                connector_args,
+                &query.selected_fields,
+                None,
+            )
+            .await?
         } else {
             nested_read::one2m(
                 tx,
@@ -117,7 +154,7 @@ fn read_related<'a, 'b>(
                 parent_result,
                 query.args.clone(),
                 &query.selected_fields,
-                paginator,
+                Some(paginator),
             )
             .await?
         };
@@ -139,15 +176,65 @@ fn read_related<'a, 'b>(
     fut.boxed()
 }
 
+/// Counts the related records of each parent record for all selected to-many relations.
+async fn count_related<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: RelatedRecordsCountQuery,
+    parent_result: Option<&'a ManyRecords>,
+) -> InterpretationResult<QueryResult> {
+    let parent_result = parent_result
+        .expect("[ID retrieval] No parent results present in the query graph for counting related records.");
+
+    let counts = nested_read::count_related(tx, &query.relation_fields, parent_result).await?;
+
+    Ok(QueryResult::RelationCounts(RelationCounts {
+        name: query.name,
+        fields: query.selection_order,
+        counts,
+    }))
+}
+
+/// Aggregates the records of a model (count, avg, sum, min, max).
 async fn aggregate<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: AggregateRecordsQuery,
 ) -> InterpretationResult<QueryResult> {
-    let result = tx.count_by_model(&query.model, QueryArguments::default()).await?;
-    Ok(QueryResult::Count(result))
+    let selection_order = query.selection_order;
+    let results = tx
+        .aggregate_records(&query.model, query.aggregators, query.args)
+        .await?;
+
+    Ok(QueryResult::RecordAggregation(RecordAggregation {
+        selection_order,
+        results: vec![results],
+        grouped: false,
+    }))
+}
+
+/// Groups the records of a model and aggregates each group.
+async fn group_by<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: GroupByRecordsQuery,
+) -> InterpretationResult<QueryResult> {
+    let selection_order = query.selection_order;
+    let results = tx
+        .group_by_records(
+            &query.model,
+            query.group_by,
+            query.aggregators,
+            query.args,
+            query.having,
+        )
+        .await?;
+
+    Ok(QueryResult::RecordAggregation(RecordAggregation {
+        selection_order,
+        results,
+        grouped: true,
+    }))
 }
 
-fn process_nested<'a, 'b>(
+pub(crate) fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
     parent_result: Option<&'a ManyRecords>,
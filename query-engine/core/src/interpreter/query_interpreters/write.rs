@@ -12,13 +12,24 @@ pub async fn execute<'a, 'b>(
 ) -> InterpretationResult<QueryResult> {
     match write_query {
         WriteQuery::CreateRecord(q) => create_one(tx, q).await,
+        WriteQuery::CreateManyRecords(q) => create_many(tx, q).await,
         WriteQuery::UpdateRecord(q) => update_one(tx, q).await,
+        WriteQuery::NativeUpsert(q) => native_upsert(tx, q).await,
         WriteQuery::DeleteRecord(q) => delete_one(tx, q).await,
         WriteQuery::UpdateManyRecords(q) => update_many(tx, q).await,
         WriteQuery::DeleteManyRecords(q) => delete_many(tx, q).await,
         WriteQuery::ConnectRecords(q) => connect(tx, q).await,
         WriteQuery::DisconnectRecords(q) => disconnect(tx, q).await,
-        WriteQuery::Raw { query, parameters } => execute_raw(tx, query, parameters).await,
+        WriteQuery::Raw {
+            raw_type: RawQueryType::Execute,
+            query,
+            parameters,
+        } => execute_raw(tx, query, parameters).await,
+        WriteQuery::Raw {
+            raw_type: RawQueryType::Query,
+            query,
+            parameters,
+        } => query_raw(tx, query, parameters).await,
     }
 }
 
@@ -28,6 +39,15 @@ async fn execute_raw<'a, 'b>(
     parameters: Vec<PrismaValue>,
 ) -> InterpretationResult<QueryResult> {
     let res = tx.execute_raw(query, parameters).await?;
+    Ok(QueryResult::Json(serde_json::Value::from(res)))
+}
+
+async fn query_raw<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    query: String,
+    parameters: Vec<PrismaValue>,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.query_raw(query, parameters).await?;
     Ok(QueryResult::Json(res))
 }
 
@@ -37,12 +57,29 @@ async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) ->
     Ok(QueryResult::Id(Some(res)))
 }
 
+async fn create_many<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: CreateManyRecords,
+) -> InterpretationResult<QueryResult> {
+    let res = tx.create_records(&q.model, q.args, q.skip_duplicates).await?;
+
+    Ok(QueryResult::Count(res))
+}
+
 async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
     let mut res = tx.update_records(&q.model, Filter::from(q.where_), q.args).await?;
 
     Ok(QueryResult::Id(res.pop()))
 }
 
+async fn native_upsert<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: NativeUpsert) -> InterpretationResult<QueryResult> {
+    let res = tx
+        .native_upsert_record(&q.model, q.conflict_field, q.create, q.update)
+        .await?;
+
+    Ok(QueryResult::Id(Some(res)))
+}
+
 async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) -> InterpretationResult<QueryResult> {
     // We need to ensure that we have a record finder, else we delete everything (conversion to empty filter).
     let finder = match q.where_ {
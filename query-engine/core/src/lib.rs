@@ -9,6 +9,7 @@ extern crate debug_stub_derive;
 pub mod error;
 pub mod executor;
 pub mod interpreter;
+pub mod metrics;
 pub mod query_ast;
 pub mod query_document;
 pub mod query_graph;
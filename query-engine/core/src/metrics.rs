@@ -0,0 +1,123 @@
+//! Prometheus metrics of the query executor, registered in the default registry of the `prometheus` crate.
+//! The server renders them together with the metrics of the connectors.
+use crate::{response_ir::Responses, schema::QuerySchemaRef, CoreError, Operation, SchemaQueryBuilder};
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge, HistogramVec, IntCounterVec, IntGauge,
+};
+use std::time::Instant;
+
+static REQUESTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "prisma_requests_total",
+        "Number of executed operations.",
+        &["operation", "model"]
+    )
+    .unwrap()
+});
+
+static REQUEST_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "prisma_request_duration_seconds",
+        "Duration of executed operations in seconds.",
+        &["operation", "model"]
+    )
+    .unwrap()
+});
+
+static ERRORS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "prisma_errors_total",
+        "Number of errors by user facing error code, `unknown` for errors without a code.",
+        &["code"]
+    )
+    .unwrap()
+});
+
+static ACTIVE_TRANSACTIONS: Lazy<IntGauge> =
+    Lazy::new(|| register_int_gauge!("prisma_active_transactions", "Number of currently open transactions.").unwrap());
+
+/// The labels an operation is recorded with: The query tag and model of model operations (e.g. `findMany` and
+/// `User`), the field name of other operations (e.g. `executeRaw`). Operations on unknown fields are recorded
+/// as `unknown` to keep the number of label values bounded.
+pub struct OperationLabels {
+    operation: String,
+    model: String,
+}
+
+impl OperationLabels {
+    pub fn new(operation: &Operation, query_schema: &QuerySchemaRef) -> Self {
+        let (selection, object) = match operation {
+            Operation::Read(selection) => (selection, query_schema.query()),
+            Operation::Write(selection) => (selection, query_schema.mutation()),
+        };
+
+        match object.find_field(selection.name()) {
+            Some(field) => match &field.query_builder {
+                Some(SchemaQueryBuilder::ModelQueryBuilder(builder)) => Self {
+                    operation: builder.tag.to_string(),
+                    model: builder.model.name.clone(),
+                },
+                _ => Self {
+                    operation: field.name.clone(),
+                    model: String::new(),
+                },
+            },
+            None => Self {
+                operation: "unknown".to_owned(),
+                model: String::new(),
+            },
+        }
+    }
+
+    fn values(&self) -> [&str; 2] {
+        [&self.operation, &self.model]
+    }
+}
+
+/// Records an executed operation and the errors of its responses.
+pub fn observe_responses(labels: &OperationLabels, started: Instant, result: &crate::Result<Responses>) {
+    observe_request(labels, started);
+
+    match result {
+        Ok(responses) => responses
+            .errors()
+            .iter()
+            .for_each(|error| observe_error_code(error.error_code())),
+        Err(err) => observe_error(err),
+    }
+}
+
+/// Records an executed operation.
+pub fn observe_request(labels: &OperationLabels, started: Instant) {
+    let elapsed = started.elapsed();
+
+    REQUESTS.with_label_values(&labels.values()).inc();
+    REQUEST_DURATION
+        .with_label_values(&labels.values())
+        .observe(elapsed.as_secs_f64());
+}
+
+pub fn observe_error(err: &CoreError) {
+    observe_error_code(err.error_code());
+}
+
+fn observe_error_code(code: Option<&str>) {
+    ERRORS.with_label_values(&[code.unwrap_or("unknown")]).inc();
+}
+
+/// Counts an open transaction for as long as it is alive.
+pub struct ActiveTransaction(());
+
+impl ActiveTransaction {
+    pub fn start() -> Self {
+        ACTIVE_TRANSACTIONS.inc();
+        Self(())
+    }
+}
+
+impl Drop for ActiveTransaction {
+    fn drop(&mut self) {
+        ACTIVE_TRANSACTIONS.dec();
+    }
+}
@@ -1,6 +1,6 @@
 //! Prisma read query AST
 use super::FilteredQuery;
-use connector::{filter::Filter, QueryArguments};
+use connector::{filter::Filter, Aggregator, QueryArguments};
 use prisma_models::prelude::*;
 use std::fmt::Display;
 
@@ -9,7 +9,9 @@ pub enum ReadQuery {
     RecordQuery(RecordQuery),
     ManyRecordsQuery(ManyRecordsQuery),
     RelatedRecordsQuery(RelatedRecordsQuery),
+    RelatedRecordsCountQuery(RelatedRecordsCountQuery),
     AggregateRecordsQuery(AggregateRecordsQuery),
+    GroupByRecordsQuery(GroupByRecordsQuery),
 }
 
 impl ReadQuery {
@@ -18,7 +20,9 @@ impl ReadQuery {
             ReadQuery::RecordQuery(x) => &x.name,
             ReadQuery::ManyRecordsQuery(x) => &x.name,
             ReadQuery::RelatedRecordsQuery(x) => &x.name,
+            ReadQuery::RelatedRecordsCountQuery(x) => &x.name,
             ReadQuery::AggregateRecordsQuery(x) => &x.name,
+            ReadQuery::GroupByRecordsQuery(x) => &x.name,
         }
     }
 
@@ -29,7 +33,9 @@ impl ReadQuery {
             ReadQuery::RecordQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::ManyRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
             ReadQuery::RelatedRecordsQuery(x) => x.selected_fields.contains_all_db_names(db_names),
+            ReadQuery::RelatedRecordsCountQuery(_x) => false,
             ReadQuery::AggregateRecordsQuery(_x) => false,
+            ReadQuery::GroupByRecordsQuery(_x) => false,
         }
     }
 
@@ -38,7 +44,9 @@ impl ReadQuery {
             ReadQuery::RecordQuery(x) => x.model.clone(),
             ReadQuery::ManyRecordsQuery(x) => x.model.clone(),
             ReadQuery::RelatedRecordsQuery(x) => x.parent_field.related_field().model().clone(),
+            ReadQuery::RelatedRecordsCountQuery(x) => x.model.clone(),
             ReadQuery::AggregateRecordsQuery(x) => x.model.clone(),
+            ReadQuery::GroupByRecordsQuery(x) => x.model.clone(),
         }
     }
 }
@@ -48,6 +56,8 @@ impl FilteredQuery for ReadQuery {
         match self {
             Self::RecordQuery(q) => q.get_filter(),
             Self::ManyRecordsQuery(q) => q.get_filter(),
+            Self::AggregateRecordsQuery(q) => q.get_filter(),
+            Self::GroupByRecordsQuery(q) => q.get_filter(),
             _ => unimplemented!(),
         }
     }
@@ -56,6 +66,8 @@ impl FilteredQuery for ReadQuery {
         match self {
             Self::RecordQuery(q) => q.set_filter(filter),
             Self::ManyRecordsQuery(q) => q.set_filter(filter),
+            Self::AggregateRecordsQuery(q) => q.set_filter(filter),
+            Self::GroupByRecordsQuery(q) => q.set_filter(filter),
             _ => unimplemented!(),
         }
     }
@@ -87,7 +99,28 @@ impl Display for ReadQuery {
                 q.parent_field.name,
                 q.selected_fields.names().collect::<Vec<_>>()
             ),
-            Self::AggregateRecordsQuery(q) => write!(f, "AggregateRecordsQuery: {}", q.name),
+            Self::RelatedRecordsCountQuery(q) => write!(
+                f,
+                "RelatedRecordsCountQuery(name: '{}', parent model: {}, relation fields: {:?})",
+                q.name,
+                q.model.name,
+                q.relation_fields.iter().map(|rf| rf.name.as_str()).collect::<Vec<_>>()
+            ),
+            Self::AggregateRecordsQuery(q) => write!(
+                f,
+                "AggregateRecordsQuery(name: '{}', model: {}, args: {:?}, aggregators: {:?})",
+                q.name, q.model.name, q.args, q.aggregators
+            ),
+            Self::GroupByRecordsQuery(q) => write!(
+                f,
+                "GroupByRecordsQuery(name: '{}', model: {}, group by: {:?}, args: {:?}, having: {:?}, aggregators: {:?})",
+                q.name,
+                q.model.name,
+                q.group_by.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+                q.args,
+                q.having,
+                q.aggregators
+            ),
         }
     }
 }
@@ -129,11 +162,30 @@ pub struct RelatedRecordsQuery {
     pub parent_projections: Option<Vec<RecordProjection>>,
 }
 
+/// Counts the related records of each parent record for a set of to-many relation fields of the parent model.
+#[derive(Debug, Clone)]
+pub struct RelatedRecordsCountQuery {
+    pub name: String,
+    pub alias: Option<String>,
+
+    /// The parent model the relation fields belong to.
+    pub model: ModelRef,
+
+    /// The counted relation fields, in the order of `selection_order`.
+    pub relation_fields: Vec<RelationFieldRef>,
+    pub selection_order: Vec<String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregateRecordsQuery {
     pub name: String,
     pub alias: Option<String>,
     pub model: ModelRef,
+    pub args: QueryArguments,
+    pub aggregators: Vec<Aggregator>,
+
+    /// Selected aggregation names, e.g. `count` or `avg`, with the selected field names for field based aggregations.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
 }
 
 impl FilteredQuery for RecordQuery {
@@ -155,3 +207,37 @@ impl FilteredQuery for ManyRecordsQuery {
         self.args.filter = Some(filter)
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct GroupByRecordsQuery {
+    pub name: String,
+    pub alias: Option<String>,
+    pub model: ModelRef,
+    pub args: QueryArguments,
+    pub group_by: Vec<ScalarFieldRef>,
+    pub having: Option<Filter>,
+    pub aggregators: Vec<Aggregator>,
+
+    /// Selected grouped fields and aggregation names, with the selected field names for field based aggregations.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+}
+
+impl FilteredQuery for AggregateRecordsQuery {
+    fn get_filter(&mut self) -> Option<&mut Filter> {
+        self.args.filter.as_mut()
+    }
+
+    fn set_filter(&mut self, filter: Filter) {
+        self.args.filter = Some(filter)
+    }
+}
+
+impl FilteredQuery for GroupByRecordsQuery {
+    fn get_filter(&mut self) -> Option<&mut Filter> {
+        self.args.filter.as_mut()
+    }
+
+    fn set_filter(&mut self, filter: Filter) {
+        self.args.filter = Some(filter)
+    }
+}
@@ -7,18 +7,28 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub enum WriteQuery {
     CreateRecord(CreateRecord),
+    CreateManyRecords(CreateManyRecords),
     UpdateRecord(UpdateRecord),
+    NativeUpsert(NativeUpsert),
     DeleteRecord(DeleteRecord),
     UpdateManyRecords(UpdateManyRecords),
     DeleteManyRecords(DeleteManyRecords),
     ConnectRecords(ConnectRecords),
     DisconnectRecords(DisconnectRecords),
     Raw {
+        raw_type: RawQueryType,
         query: String,
         parameters: Vec<PrismaValue>,
     },
 }
 
+/// Raw queries either return the resulting rows (`queryRaw`) or the number of affected rows (`executeRaw`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RawQueryType {
+    Query,
+    Execute,
+}
+
 impl WriteQuery {
     pub fn inject_projection_into_args(&mut self, projection: RecordProjection) {
         let keys = projection.fields().map(|dsf| dsf.name.clone()).collect();
@@ -51,35 +61,33 @@ impl WriteQuery {
 
         // Write operations only return IDs at the moment, so anything different
         // from the primary ID is automatically not returned.
-        // DeleteMany, Connect and Disconnect do not return anything.
+        // CreateMany, DeleteMany, Connect and Disconnect do not return anything.
         match self {
             Self::CreateRecord(_) => returns_id,
+            Self::CreateManyRecords(_) => false,
             Self::UpdateRecord(_) => returns_id,
+            Self::NativeUpsert(_) => returns_id,
             Self::DeleteRecord(_) => returns_id,
             Self::UpdateManyRecords(_) => returns_id,
             Self::DeleteManyRecords(_) => false,
             Self::ConnectRecords(_) => false,
             Self::DisconnectRecords(_) => false,
-            Self::Raw {
-                query: _,
-                parameters: _,
-            } => unimplemented!(),
+            Self::Raw { .. } => unimplemented!(),
         }
     }
 
     pub fn model(&self) -> ModelRef {
         match self {
             Self::CreateRecord(q) => Arc::clone(&q.model),
+            Self::CreateManyRecords(q) => Arc::clone(&q.model),
             Self::UpdateRecord(q) => Arc::clone(&q.model),
+            Self::NativeUpsert(q) => Arc::clone(&q.model),
             Self::DeleteRecord(q) => Arc::clone(&q.model),
             Self::UpdateManyRecords(q) => Arc::clone(&q.model),
             Self::DeleteManyRecords(q) => Arc::clone(&q.model),
             Self::ConnectRecords(q) => q.relation_field.model(),
             Self::DisconnectRecords(q) => q.relation_field.model(),
-            Self::Raw {
-                query: _,
-                parameters: _,
-            } => unimplemented!(),
+            Self::Raw { .. } => unimplemented!(),
         }
     }
 }
@@ -110,17 +118,33 @@ impl std::fmt::Display for WriteQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::CreateRecord(q) => write!(f, "CreateRecord(model: {}, args: {:?})", q.model.name, q.args,),
+            Self::CreateManyRecords(q) => write!(
+                f,
+                "CreateManyRecords(model: {}, records: {}, skip_duplicates: {})",
+                q.model.name,
+                q.args.len(),
+                q.skip_duplicates
+            ),
             Self::UpdateRecord(q) => write!(
                 f,
                 "UpdateRecord(model: {}, finder: {:?}, args: {:?})",
                 q.model.name, q.where_, q.args,
             ),
+            Self::NativeUpsert(q) => write!(
+                f,
+                "NativeUpsert(model: {}, conflict_field: {}, create: {:?}, update: {:?})",
+                q.model.name, q.conflict_field.name, q.create, q.update,
+            ),
             Self::DeleteRecord(q) => write!(f, "DeleteRecord: {}, {:?}", q.model.name, q.where_),
             Self::UpdateManyRecords(q) => write!(f, "UpdateManyRecords(model: {}, args: {:?})", q.model.name, q.args),
             Self::DeleteManyRecords(q) => write!(f, "DeleteManyRecords: {}", q.model.name),
             Self::ConnectRecords(_) => write!(f, "ConnectRecords"),
             Self::DisconnectRecords(_) => write!(f, "DisconnectRecords"),
-            Self::Raw { query, parameters } => write!(f, "Raw: {} ({:?})", query, parameters),
+            Self::Raw {
+                raw_type,
+                query,
+                parameters,
+            } => write!(f, "Raw({:?}): {} ({:?})", raw_type, query, parameters),
         }
     }
 }
@@ -131,6 +155,13 @@ pub struct CreateRecord {
     pub args: WriteArgs,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreateManyRecords {
+    pub model: ModelRef,
+    pub args: Vec<WriteArgs>,
+    pub skip_duplicates: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateRecord {
     pub model: ModelRef,
@@ -138,6 +169,16 @@ pub struct UpdateRecord {
     pub args: WriteArgs,
 }
 
+/// Creates the record, or updates it if a record with the same value for `conflict_field` already exists,
+/// in a single statement.
+#[derive(Debug, Clone)]
+pub struct NativeUpsert {
+    pub model: ModelRef,
+    pub conflict_field: ScalarFieldRef,
+    pub create: WriteArgs,
+    pub update: WriteArgs,
+}
+
 #[derive(Debug, Clone)]
 pub struct UpdateManyRecords {
     pub model: ModelRef,
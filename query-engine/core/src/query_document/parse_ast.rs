@@ -1,7 +1,7 @@
 //! Parsed query document tree. Naming is WIP.
 //! Structures represent parsed and validated parts of the query document, used by the query builders.
 use super::{QueryParserError, QueryParserResult};
-use crate::FieldRef;
+use crate::{FieldRef, RawQueryType};
 use prisma_models::{OrderBy, PrismaValue};
 use std::collections::BTreeMap;
 
@@ -24,8 +24,13 @@ pub struct ParsedField {
 }
 
 impl ParsedField {
-    pub fn is_raw_query(&self) -> bool {
-        self.name == "executeRaw"
+    /// The type of raw query if the field is one of the raw query fields.
+    pub fn raw_query_type(&self) -> Option<RawQueryType> {
+        match self.name.as_str() {
+            "executeRaw" => Some(RawQueryType::Execute),
+            "queryRaw" => Some(RawQueryType::Query),
+            _ => None,
+        }
     }
 }
 
@@ -33,6 +38,9 @@ impl ParsedField {
 pub struct ParsedArgument {
     pub name: String,
     pub value: ParsedInputValue,
+
+    /// Fields of an object argument the parser filled in from their defaults, i.e. that were not part of the query document.
+    pub defaulted_fields: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
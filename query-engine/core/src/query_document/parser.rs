@@ -15,7 +15,6 @@ pub struct QueryDocumentParser;
 
 // Todo:
 // - Use error collections instead of letting first error win.
-// - UUID ids are not encoded in any useful way in the schema.
 // - Alias handling in query names.
 impl QueryDocumentParser {
     /// Parses and validates a set of selections against a schema (output) object.
@@ -125,10 +124,28 @@ impl QueryDocumentParser {
                     None => ((schema_arg.name.clone(), QueryValue::Null), false),
                 };
 
+                let given_keys: Option<HashSet<String>> = match &selection_arg.1 {
+                    QueryValue::Object(obj) => Some(obj.keys().cloned().collect()),
+                    _ => None,
+                };
+
                 let result = Self::parse_input_value(selection_arg.1, &schema_arg.argument_type)
-                    .map(|value| ParsedArgument {
-                        name: schema_arg.name.clone(),
-                        value,
+                    .map(|value| {
+                        // Fields of an object argument that are in the parsed value but not in the document were filled in from defaults.
+                        let defaulted_fields = match (&value, given_keys) {
+                            (ParsedInputValue::Map(map), Some(given_keys)) => map
+                                .keys()
+                                .filter(|key| !given_keys.contains(key.as_str()))
+                                .cloned()
+                                .collect(),
+                            _ => vec![],
+                        };
+
+                        ParsedArgument {
+                            name: schema_arg.name.clone(),
+                            value,
+                            defaulted_fields,
+                        }
                     })
                     .map_err(|err| QueryParserError::ArgumentValidationError {
                         argument: schema_arg.name.clone(),
@@ -167,10 +184,29 @@ impl QueryDocumentParser {
             (QueryValue::List(values), InputType::List(l))  => Self::parse_list(values.clone(), &l).map(ParsedInputValue::List),
             (_, InputType::List(l))                         => Self::parse_list(vec![value], &l).map(ParsedInputValue::List),
             (QueryValue::Object(o), InputType::Object(obj)) => Self::parse_input_object(o.clone(), obj.into_arc()).map(ParsedInputValue::Map),
+            (_, InputType::Union(types))                    => Self::parse_union(value, types),
             (_, input_type)                                 => Err(QueryParserError::ValueTypeMismatchError { have: value, want: input_type.clone() }),
         }
     }
 
+    /// Parses the value as the first type of the union that accepts it.
+    /// Returns the error of the last type if none does.
+    pub fn parse_union(value: QueryValue, types: &[InputType]) -> QueryParserResult<ParsedInputValue> {
+        let mut error = None;
+
+        for typ in types {
+            match Self::parse_input_value(value.clone(), typ) {
+                Ok(parsed) => return Ok(parsed),
+                Err(err) => error = Some(err),
+            }
+        }
+
+        Err(error.unwrap_or_else(|| QueryParserError::ValueTypeMismatchError {
+            have: value,
+            want: InputType::Union(vec![]),
+        }))
+    }
+
     /// Attempts to parse given query value into a concrete PrismaValue based on given scalar type.
     #[rustfmt::skip]
     pub fn parse_scalar(value: QueryValue, scalar_type: &ScalarType) -> QueryParserResult<PrismaValue> {
@@ -178,10 +214,14 @@ impl QueryDocumentParser {
             (QueryValue::Null, _)                         => Ok(PrismaValue::Null),
             (QueryValue::String(s), ScalarType::String)   => Ok(PrismaValue::String(s)),
             (QueryValue::String(s), ScalarType::DateTime) => Self::parse_datetime(s.as_str()).map(PrismaValue::DateTime),
+            (QueryValue::String(s), ScalarType::Json)     => Self::parse_json(&s).map(|_| PrismaValue::Json(s)),
             (QueryValue::String(s), ScalarType::JsonList) => Self::parse_json_list(&s),
             (QueryValue::String(s), ScalarType::UUID)     => Self::parse_uuid(s.as_str()).map(PrismaValue::Uuid),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
             (QueryValue::Int(i), ScalarType::Float)       => Ok(PrismaValue::Float(Decimal::from(i))),
             (QueryValue::Int(i), ScalarType::Int)         => Ok(PrismaValue::Int(i)),
+            (QueryValue::Int(i), ScalarType::BigInt)      => Ok(PrismaValue::BigInt(i)),
+            (QueryValue::String(s), ScalarType::BigInt)   => Self::parse_big_int(s.as_str()).map(PrismaValue::BigInt),
             (QueryValue::Float(f), ScalarType::Float)     => Ok(PrismaValue::Float(f)),
             (QueryValue::Float(f), ScalarType::Int)       => {
                 Ok(PrismaValue::Int(f.to_i64().unwrap()))
@@ -228,6 +268,17 @@ impl QueryDocumentParser {
         serde_json::from_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid json: {}", err)))
     }
 
+    /// BigInts are also accepted as strings, as clients can't represent all of them as numbers.
+    pub fn parse_big_int(s: &str) -> QueryParserResult<i64> {
+        s.parse()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid BigInt: {}", err)))
+    }
+
+    pub fn parse_bytes(s: &str) -> QueryParserResult<Vec<u8>> {
+        prisma_value::decode_bytes(s)
+            .map_err(|_| QueryParserError::ValueParseError(format!("Invalid base64 encoded bytes: {}", s)))
+    }
+
     pub fn parse_uuid(s: &str) -> QueryParserResult<Uuid> {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
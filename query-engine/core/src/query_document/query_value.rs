@@ -1,4 +1,4 @@
-use prisma_value::{stringify_date, PrismaValue};
+use prisma_value::{encode_bytes, stringify_date, PrismaValue};
 use rust_decimal::Decimal;
 use std::collections::BTreeMap;
 
@@ -35,6 +35,9 @@ impl From<PrismaValue> for QueryValue {
             PrismaValue::Int(i) => Self::Int(i),
             PrismaValue::Null => Self::Null,
             PrismaValue::Uuid(u) => Self::String(u.to_hyphenated().to_string()),
+            PrismaValue::Json(s) => Self::String(s),
+            PrismaValue::Bytes(b) => Self::String(encode_bytes(&b)),
+            PrismaValue::BigInt(i) => Self::Int(i),
         }
     }
 }
@@ -13,23 +13,23 @@ pub fn after_graph_completion(graph: &QueryGraph) -> QueryGraphResult<()> {
     only_allow_related_parents_edges(graph)
 }
 
-/// For an If flow node: Only allow `Then` and `Else` edges. Disallow more than 2 edges.
+/// For an If flow node: Allow at most one `Then` and one `Else` edge.
+/// Other edges are allowed, they depend on the result of the arm that ran.
 fn if_flow_edge_rules(graph: &QueryGraph, edge: &EdgeRef) -> QueryGraphResult<()> {
     let source_node = graph.edge_source(edge);
     let source_node_content = graph.node_content(&source_node).unwrap();
 
     if let Node::Flow(Flow::If(_)) = source_node_content {
-        match graph.edge_content(edge).unwrap() {
-            QueryGraphDependency::Then | QueryGraphDependency::Else => Ok(()),
-            x => Err(QueryGraphError::InvarianceViolation(format!(
-                "Invalid edge '{}' for If node {}.",
-                x,
-                source_node.id()
-            ))),
-        }?;
+        let arms = graph
+            .child_pairs(&source_node)
+            .into_iter()
+            .filter(|(edge, _)| match graph.edge_content(edge).unwrap() {
+                QueryGraphDependency::Then | QueryGraphDependency::Else => true,
+                _ => false,
+            })
+            .count();
 
-        let pairs = graph.child_pairs(&source_node);
-        if pairs.len() > 2 {
+        if arms > 2 {
             return Err(QueryGraphError::InvarianceViolation(
                 "'If' node has invalid amound of children (min 1, max 2).".into(),
             ));
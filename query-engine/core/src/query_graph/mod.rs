@@ -58,13 +58,22 @@ impl From<Flow> for Node {
 pub enum Flow {
     /// Expresses a conditional control flow in the graph.
     /// Possible outgoing edges are `then` and `else`, each at most once, with `then` required to be present.
+    /// Any other outgoing edge is evaluated after the `If` and depends on the result of the arm that ran.
     If(Box<dyn FnOnce() -> bool + Send + Sync + 'static>),
+
+    /// Returns a fixed set of record projections, usually injected by a parent node.
+    /// Used as the last node of an `If` arm to hand the result of that arm to the children of the `If`.
+    Return(Option<Vec<RecordProjection>>),
 }
 
 impl Flow {
     pub fn default_if() -> Self {
         Self::If(Box::new(|| true))
     }
+
+    pub fn default_return() -> Self {
+        Self::Return(None)
+    }
 }
 
 // Current limitation: We need to narrow it down to ID diffs for Hash and EQ.
@@ -435,7 +444,10 @@ impl QueryGraph {
     ///
     /// Any edge existing between `parent` and `child` will change direction and will point from `child` to `parent` instead.
     ///
-    /// **Important exception**: If a parent node is a `Flow` node, we need to completely remove the edge to the flow node and rewire it to the child.
+    /// **Important exception**: If a parent node is a `Flow` node and the edge is a `Then` or `Else` edge, we need to completely remove
+    /// the edge to the flow node and rewire it to the child.
+    ///
+    /// Parents of `parent` that depend on `child` themselves (e.g. an `If` on the result of `child`) are left untouched.
     ///
     /// ## Example transformation
     /// Given the marked pairs `[(A, B), (B, C), (B, D)]` and a graph (depicting the state before the transformation):
@@ -505,11 +517,20 @@ impl QueryGraph {
             for parent_edge in parent_edges {
                 let parent_of_parent_node = self.edge_source(&parent_edge);
 
+                if self.is_ancestor(&child_node, &parent_of_parent_node) {
+                    continue;
+                }
+
+                let is_flow_edge = match self.edge_content(&parent_edge).unwrap() {
+                    QueryGraphDependency::Then | QueryGraphDependency::Else => true,
+                    _ => false,
+                };
+
                 match self
                     .node_content(&parent_of_parent_node)
                     .expect("Expected marked nodes to be non-empty.")
                 {
-                    Node::Flow(_) => {
+                    Node::Flow(_) if is_flow_edge => {
                         let content = self
                             .remove_edge(parent_edge)
                             .expect("Expected edges between marked nodes to be non-empty.");
@@ -1,5 +1,5 @@
 use super::*;
-use crate::{query_document::*, query_graph::*, schema::*, IrSerializer};
+use crate::{query_document::*, query_graph::*, schema::*, IrSerializer, RawQueryType};
 use prisma_value::PrismaValue;
 
 // TODO: Think about if this is really necessary here, or if the whole code should move into
@@ -11,6 +11,7 @@ pub struct QueryGraphBuilder {
 pub enum QueryType {
     Graph(QueryGraph),
     Raw {
+        raw_type: RawQueryType,
         query: String,
         parameters: Vec<PrismaValue>,
     },
@@ -96,17 +97,19 @@ impl QueryGraphBuilder {
         let parsed_field = parsed_object.fields.pop().unwrap();
         let result_info = Self::derive_serializer(&selections.pop().unwrap(), &parsed_field);
 
-        let query_type = match &parsed_field.schema_field.clone().query_builder {
-            Some(builder) => Ok(QueryType::Graph(builder.build(parsed_field)?)),
-            None if parsed_field.is_raw_query() => {
+        let raw_query_type = parsed_field.raw_query_type();
+        let query_type = match (&parsed_field.schema_field.clone().query_builder, raw_query_type) {
+            (Some(builder), _) => Ok(QueryType::Graph(builder.build(parsed_field)?)),
+            (None, Some(raw_type)) => {
                 let raw_args = RawArgs::from(parsed_field.arguments);
 
                 Ok(QueryType::Raw {
+                    raw_type,
                     query: raw_args.query,
                     parameters: raw_args.parameters,
                 })
             }
-            None => Err(QueryGraphBuilderError::SchemaError(format!(
+            (None, None) => Err(QueryGraphBuilderError::SchemaError(format!(
                 "Expected attached query builder on {} object, root level field '{}'.",
                 object.name(),
                 parsed_field.name
@@ -3,9 +3,15 @@ use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
-use std::{collections::BTreeMap, convert::TryInto};
+use connector::{
+    filter::{AggregationFilter, AggregationFunction, Filter, QueryMode, ScalarCondition},
+    RelationCompare, ScalarCompare, ScalarListCompare,
+};
+use prisma_models::{DataSourceFieldRef, Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryInto,
+};
 
 static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::NotIn,
@@ -21,6 +27,11 @@ static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::Contains,
     FilterOp::StartsWith,
     FilterOp::EndsWith,
+    FilterOp::Search,
+    FilterOp::HasSome,
+    FilterOp::HasEvery,
+    FilterOp::Has,
+    FilterOp::IsEmpty,
     FilterOp::Some,
     FilterOp::None,
     FilterOp::Every,
@@ -30,6 +41,9 @@ static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::Field, // Needs to be last
 ];
 
+/// Operations modifying the filters of another field, split off before the filters are extracted.
+static FIELD_MODIFIERS: &'static [FilterOp] = &[FilterOp::Mode, FilterOp::Path];
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 enum FilterOp {
     In,
@@ -45,12 +59,19 @@ enum FilterOp {
     NotStartsWith,
     EndsWith,
     NotEndsWith,
+    Search,
+    Has,
+    HasSome,
+    HasEvery,
+    IsEmpty,
     Some,
     None,
     Every,
     NestedAnd,
     NestedOr,
     NestedNot,
+    Mode,
+    Path,
     Field,
 }
 
@@ -80,12 +101,19 @@ impl FilterOp {
             FilterOp::NotStartsWith => "_not_starts_with",
             FilterOp::EndsWith => "_ends_with",
             FilterOp::NotEndsWith => "_not_ends_with",
+            FilterOp::Search => "_search",
+            FilterOp::Has => "_has",
+            FilterOp::HasSome => "_has_some",
+            FilterOp::HasEvery => "_has_every",
+            FilterOp::IsEmpty => "_is_empty",
             FilterOp::Some => "_some",
             FilterOp::None => "_none",
             FilterOp::Every => "_every",
             FilterOp::NestedAnd => "AND",
             FilterOp::NestedOr => "OR",
             FilterOp::NestedNot => "NOT",
+            FilterOp::Mode => "_mode",
+            FilterOp::Path => "_path",
             FilterOp::Field => "",
         }
     }
@@ -96,11 +124,17 @@ pub fn extract_filter(
     model: &ModelRef,
     match_suffix: bool,
 ) -> QueryGraphBuilderResult<Filter> {
+    let (modifiers, value_map) = if match_suffix {
+        extract_field_modifiers(value_map, model)?
+    } else {
+        (FieldModifiers::default(), value_map)
+    };
+
     let filters = value_map
         .into_iter()
         .map(|(key, value): (String, ParsedInputValue)| {
             let op = if match_suffix {
-                FilterOp::find_op(key.as_str()).unwrap()
+                find_field_op(&key, model)?
             } else {
                 FilterOp::Field
             };
@@ -132,7 +166,7 @@ pub fn extract_filter(
                     // Always try to resolve regular fields first. If that fails, try to resolve compound fields.
                     match model.fields().find_from_all(&field_name) {
                         Ok(field) => match field {
-                            Field::Scalar(field) => handle_scalar_field(field, value, &op),
+                            Field::Scalar(field) => handle_scalar_field(field, value, &op, &modifiers),
                             Field::Relation(field) => handle_relation_field(field, value, &op, match_suffix),
                         },
                         Err(_) => utils::resolve_compound_field(&field_name, &model)
@@ -150,15 +184,224 @@ pub fn extract_filter(
     Ok(Filter::and(filters))
 }
 
+/// Extracts the filter on groups of records (`having`) of a group by query.
+/// Next to regular scalar field filters on the fields the records are grouped by, groups can be filtered
+/// on `_count` (e.g. `_count_gt`) and on field based aggregations (e.g. `_avg: { fieldA_lt: 5 }`).
+pub fn extract_having_filter(
+    value_map: BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+    group_by: &[ScalarFieldRef],
+) -> QueryGraphBuilderResult<Filter> {
+    let (modifiers, value_map) = extract_field_modifiers(value_map, model)?;
+
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            let op = find_field_op(&key, model)?;
+
+            match op {
+                op if (op == FilterOp::NestedAnd || op == FilterOp::NestedOr || op == FilterOp::NestedNot) => {
+                    let value: QueryGraphBuilderResult<Vec<Filter>> = match value {
+                        ParsedInputValue::List(values) => values
+                            .into_iter()
+                            .map(|val| extract_having_filter(val.try_into()?, model, group_by))
+                            .collect(),
+
+                        ParsedInputValue::Map(map) => extract_having_filter(map, model, group_by).map(|res| vec![res]),
+
+                        _ => unreachable!(),
+                    };
+
+                    value.map(|value| match op {
+                        FilterOp::NestedAnd => Filter::and(value),
+                        FilterOp::NestedOr => Filter::or(value),
+                        FilterOp::NestedNot => Filter::not(value),
+                        _ => unreachable!(),
+                    })
+                }
+                op => {
+                    let name = key.trim_end_matches(op.suffix());
+
+                    match (name, op) {
+                        ("_count", op) => Ok(Filter::from(AggregationFilter {
+                            function: AggregationFunction::Count,
+                            condition: aggregation_condition(&key, &op, value.try_into()?)?,
+                        })),
+                        ("_avg", FilterOp::Field) => {
+                            handle_aggregation_filters(AggregationFunction::Average, value, model)
+                        }
+                        ("_sum", FilterOp::Field) => handle_aggregation_filters(AggregationFunction::Sum, value, model),
+                        ("_min", FilterOp::Field) => handle_aggregation_filters(AggregationFunction::Min, value, model),
+                        ("_max", FilterOp::Field) => handle_aggregation_filters(AggregationFunction::Max, value, model),
+                        (field_name, op) => {
+                            let field = model.fields().find_from_scalar(field_name)?;
+
+                            // Other fields have no single value per group, the database rejects filtering on them.
+                            if !group_by.contains(&field) {
+                                return Err(QueryGraphBuilderError::InputError(format!(
+                                    "Field '{}' used in having must be present in the fields to group by.",
+                                    field.name
+                                )));
+                            }
+
+                            handle_scalar_field(&field, value, &op, &modifiers)
+                        }
+                    }
+                }
+            }
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
+
+    Ok(Filter::and(filters))
+}
+
+fn handle_aggregation_filters<F>(
+    function: F,
+    value: ParsedInputValue,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<Filter>
+where
+    F: Fn(DataSourceFieldRef) -> AggregationFunction,
+{
+    let value_map: ParsedInputMap = value.try_into()?;
+
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value)| {
+            let op = find_filter_op(&key)?;
+            let field = model.fields().find_from_scalar(key.trim_end_matches(op.suffix()))?;
+
+            Ok(Filter::from(AggregationFilter {
+                function: function(field.data_source_field().clone()),
+                condition: aggregation_condition(&key, &op, value.try_into()?)?,
+            }))
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
+
+    Ok(Filter::and(filters))
+}
+
+fn find_filter_op(key: &str) -> QueryGraphBuilderResult<FilterOp> {
+    FilterOp::find_op(key).ok_or_else(|| QueryGraphBuilderError::InputError(format!("Unknown filter '{}'.", key)))
+}
+
+/// Like `find_filter_op`, but keys naming a field of the model always filter on that field,
+/// e.g. `tags_has` filters on a field `tags_has` instead of the list field `tags`.
+fn find_field_op(key: &str, model: &ModelRef) -> QueryGraphBuilderResult<FilterOp> {
+    if model.fields().find_from_all(key).is_ok() {
+        Ok(FilterOp::Field)
+    } else {
+        find_filter_op(key)
+    }
+}
+
+fn aggregation_condition(key: &str, op: &FilterOp, value: PrismaValue) -> QueryGraphBuilderResult<ScalarCondition> {
+    let condition = match (op, value) {
+        (FilterOp::In, PrismaValue::Null) => ScalarCondition::Equals(PrismaValue::Null),
+        (FilterOp::In, PrismaValue::List(values)) => ScalarCondition::In(values),
+        (FilterOp::NotIn, PrismaValue::Null) => ScalarCondition::NotEquals(PrismaValue::Null),
+        (FilterOp::NotIn, PrismaValue::List(values)) => ScalarCondition::NotIn(values),
+        (FilterOp::Not, val) => ScalarCondition::NotEquals(val),
+        (FilterOp::Lt, val) => ScalarCondition::LessThan(val),
+        (FilterOp::Lte, val) => ScalarCondition::LessThanOrEquals(val),
+        (FilterOp::Gt, val) => ScalarCondition::GreaterThan(val),
+        (FilterOp::Gte, val) => ScalarCondition::GreaterThanOrEquals(val),
+        (FilterOp::Field, val) => ScalarCondition::Equals(val),
+        (_, _) => {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Filter '{}' is not supported on aggregations.",
+                key
+            )))
+        }
+    };
+
+    Ok(condition)
+}
+
+/// Modifiers of all filters on a field, keyed by field name: The comparison modes of string fields
+/// (e.g. `name_mode: insensitive`) and the paths of JSON fields (e.g. `meta_path: ["address", "city"]`).
+#[derive(Debug, Default)]
+struct FieldModifiers {
+    modes: HashMap<String, QueryMode>,
+    json_paths: HashMap<String, Vec<String>>,
+}
+
+impl FieldModifiers {
+    fn query_mode(&self, field: &ScalarFieldRef) -> QueryMode {
+        self.modes.get(&field.name).copied().unwrap_or_default()
+    }
+
+    fn json_path(&self, field: &ScalarFieldRef) -> Option<Vec<String>> {
+        self.json_paths.get(&field.name).cloned()
+    }
+}
+
+/// Splits the field modifiers off the filter map. Returns the modifiers, next to the remaining filters.
+/// Keys naming a field of the model are never modifiers, e.g. `display_mode` filters on a field `display_mode`.
+fn extract_field_modifiers(
+    value_map: BTreeMap<String, ParsedInputValue>,
+    model: &ModelRef,
+) -> QueryGraphBuilderResult<(FieldModifiers, BTreeMap<String, ParsedInputValue>)> {
+    let mut modifiers = FieldModifiers::default();
+    let mut filters = BTreeMap::new();
+
+    for (key, value) in value_map {
+        let modifier = FIELD_MODIFIERS
+            .iter()
+            .copied()
+            .find(|op| key.ends_with(op.suffix()) && model.fields().find_from_all(&key).is_err());
+
+        match modifier {
+            Some(FilterOp::Mode) => {
+                let value: PrismaValue = value.try_into()?;
+                let mode = match value {
+                    PrismaValue::Enum(ref mode) if mode == "insensitive" => QueryMode::Insensitive,
+                    _ => QueryMode::Default,
+                };
+
+                modifiers
+                    .modes
+                    .insert(key.trim_end_matches(FilterOp::Mode.suffix()).to_owned(), mode);
+            }
+            Some(FilterOp::Path) => {
+                let value: PrismaValue = value.try_into()?;
+                let path = match value {
+                    PrismaValue::List(segments) => segments.into_iter().map(|segment| segment.to_string()).collect(),
+                    _ => {
+                        return Err(QueryGraphBuilderError::InputError(format!(
+                            "Filter '{}' must be a list of JSON path segments.",
+                            key
+                        )))
+                    }
+                };
+
+                modifiers
+                    .json_paths
+                    .insert(key.trim_end_matches(FilterOp::Path.suffix()).to_owned(), path);
+            }
+            _ => {
+                filters.insert(key, value);
+            }
+        }
+    }
+
+    Ok((modifiers, filters))
+}
+
 fn handle_scalar_field(
     field: &ScalarFieldRef,
     value: ParsedInputValue,
     op: &FilterOp,
+    modifiers: &FieldModifiers,
 ) -> QueryGraphBuilderResult<Filter> {
+    if field.is_list {
+        return handle_scalar_list_field(field, value, op);
+    }
+
     let value: PrismaValue = value.try_into()?;
     let dsf = field.data_source_field();
 
-    Ok(match (op, value) {
+    let filter = match (op, value) {
         (FilterOp::In, PrismaValue::Null) => dsf.equals(PrismaValue::Null),
         (FilterOp::In, PrismaValue::List(values)) => dsf.is_in(values),
         (FilterOp::NotIn, PrismaValue::Null) => dsf.not_equals(PrismaValue::Null),
@@ -174,8 +417,38 @@ fn handle_scalar_field(
         (FilterOp::NotStartsWith, val) => dsf.not_starts_with(val),
         (FilterOp::EndsWith, val) => dsf.ends_with(val),
         (FilterOp::NotEndsWith, val) => dsf.not_ends_with(val),
+        (FilterOp::Search, val) => dsf.search(val),
         (FilterOp::Field, val) => dsf.equals(val),
         (_, _) => unreachable!(),
+    };
+
+    let filter = match (modifiers.json_path(field), filter) {
+        (Some(path), Filter::Scalar(scalar_filter)) => Filter::Scalar(scalar_filter.at_json_path(path)),
+        (_, filter) => filter,
+    };
+
+    Ok(match (modifiers.query_mode(field), filter) {
+        (QueryMode::Insensitive, Filter::Scalar(scalar_filter)) => Filter::Scalar(scalar_filter.insensitive()),
+        (_, filter) => filter,
+    })
+}
+
+fn handle_scalar_list_field(
+    field: &ScalarFieldRef,
+    value: ParsedInputValue,
+    op: &FilterOp,
+) -> QueryGraphBuilderResult<Filter> {
+    let value: PrismaValue = value.try_into()?;
+
+    Ok(match (op, value) {
+        (FilterOp::Has, val) => field.contains_element(val),
+        (FilterOp::HasSome, PrismaValue::List(values)) => field.contains_some_element(values),
+        (FilterOp::HasEvery, PrismaValue::List(values)) => field.contains_every_element(values),
+        (FilterOp::IsEmpty, PrismaValue::Boolean(true)) => field.contains_none(),
+        (FilterOp::IsEmpty, PrismaValue::Boolean(false)) => Filter::not(vec![field.contains_none()]),
+        (FilterOp::Field, PrismaValue::List(values)) => field.equals_list(values),
+        (FilterOp::Field, PrismaValue::Null) => field.contains_none(),
+        (_, _) => unreachable!(),
     })
 }
 
@@ -3,15 +3,18 @@ use crate::{
     query_document::{ParsedArgument, ParsedInputMap},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::QueryArguments;
-use prisma_models::{ModelRef, PrismaValue, ScalarFieldRef};
+use connector::{
+    filter::{Filter, ScalarCondition, ScalarFilter},
+    QueryArguments,
+};
+use prisma_models::{ModelRef, OrderBy, OrderByTarget, PrismaValue, ScalarFieldRef};
 use std::convert::TryInto;
 
 /// Expects the caller to know that it is structurally guaranteed that query arguments can be extracted,
 /// e.g. that the query schema guarantees that required fields are present.
 /// Errors occur if conversions fail unexpectedly.
 pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> QueryGraphBuilderResult<QueryArguments> {
-    arguments
+    let query_arguments = arguments
         .into_iter()
         .fold(Ok(QueryArguments::default()), |result, arg| {
             if let Ok(res) = result {
@@ -42,7 +45,12 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
                     }),
 
                     "orderBy" => Ok(QueryArguments {
-                        order_by: Some(arg.value.try_into()?),
+                        order_by: extract_order_by(arg.value)?,
+                        ..res
+                    }),
+
+                    "distinct" => Ok(QueryArguments {
+                        distinct: extract_distinct(arg.value, model)?,
                         ..res
                     }),
 
@@ -62,7 +70,115 @@ pub fn extract_query_args(arguments: Vec<ParsedArgument>, model: &ModelRef) -> Q
             } else {
                 result
             }
+        })?;
+
+    with_relevance_searches(query_arguments)
+}
+
+/// Relevance orderings rank the records by the full-text search on the ordered field,
+/// so the search query is taken from the `{field}_search` filter of the same query.
+fn with_relevance_searches(mut query_arguments: QueryArguments) -> QueryGraphBuilderResult<QueryArguments> {
+    for order_by in query_arguments.order_by.iter_mut() {
+        if let OrderByTarget::Relevance { field, search } = &mut order_by.target {
+            let query = query_arguments
+                .filter
+                .as_ref()
+                .and_then(|filter| find_search_query(filter, field));
+
+            match query {
+                Some(query) => *search = Some(query),
+                None => {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "Ordering by the relevance of '{}' requires a search filter on '{}'.",
+                        field.name, field.name
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(query_arguments)
+}
+
+/// Finds the full-text search query on the given field in the conjunctions of the filter.
+fn find_search_query(filter: &Filter, field: &ScalarFieldRef) -> Option<String> {
+    match filter {
+        Filter::And(filters) => filters.iter().find_map(|filter| find_search_query(filter, field)),
+        Filter::Scalar(ScalarFilter {
+            field: dsf,
+            condition: ScalarCondition::Search(PrismaValue::String(query)),
+            ..
+        }) if dsf == field.data_source_field() => Some(query.clone()),
+        _ => None,
+    }
+}
+
+/// Extracts the list of order bys. A field may only be ordered by once.
+fn extract_order_by(value: ParsedInputValue) -> QueryGraphBuilderResult<Vec<OrderBy>> {
+    let values = match value {
+        ParsedInputValue::List(values) => values,
+        ParsedInputValue::Single(PrismaValue::Null) => vec![],
+        value => vec![value],
+    };
+
+    let order_by = values
+        .into_iter()
+        .map(|value| {
+            let order_by: OrderBy = value.try_into()?;
+            Ok(order_by)
+        })
+        .collect::<QueryGraphBuilderResult<Vec<OrderBy>>>()?;
+
+    for (index, ob) in order_by.iter().enumerate() {
+        if order_by[..index].iter().any(|other| other.same_target(ob)) {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Field '{}' is used more than once in orderBy.",
+                order_by_name(ob)
+            )));
+        }
+    }
+
+    Ok(order_by)
+}
+
+/// The name of the ordered value as used in the orderBy enum, without the sort order (e.g. `author__name`).
+fn order_by_name(order_by: &OrderBy) -> String {
+    let mut parts: Vec<&str> = order_by.path.iter().map(|rf| rf.name.as_str()).collect();
+
+    match order_by.target {
+        OrderByTarget::Field(ref field) => parts.push(field.name.as_str()),
+        OrderByTarget::Count => parts.push("count"),
+        OrderByTarget::Relevance { ref field, .. } => {
+            parts.push(field.name.as_str());
+            parts.push("relevance");
+        }
+    };
+
+    parts.join(crate::ORDER_BY_SEPARATOR)
+}
+
+/// Extracts the fields to make the records distinct on. An empty list doesn't make the records distinct.
+fn extract_distinct(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Option<Vec<ScalarFieldRef>>> {
+    let value: PrismaValue = value.try_into()?;
+    let values = match value {
+        PrismaValue::List(values) => values,
+        PrismaValue::Null => vec![],
+        value => vec![value],
+    };
+
+    let fields = values
+        .into_iter()
+        .map(|value| match value {
+            PrismaValue::Enum(field_name) => model.fields().find_from_scalar(&field_name).map_err(Into::into),
+            _ => unreachable!(),
         })
+        .collect::<QueryGraphBuilderResult<Vec<ScalarFieldRef>>>()?;
+
+    if fields.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(fields))
+    }
 }
 
 fn extract_cursor(
@@ -1,6 +1,7 @@
 use super::*;
 use crate::{query_document::ParsedField, AggregateRecordsQuery, ReadQuery};
-use prisma_models::ModelRef;
+use connector::Aggregator;
+use prisma_models::{ModelRef, ScalarFieldRef};
 
 pub struct AggregateRecordsBuilder {
     field: ParsedField,
@@ -18,11 +19,62 @@ impl Builder<ReadQuery> for AggregateRecordsBuilder {
         let name = self.field.name;
         let alias = self.field.alias;
         let model = self.model;
+        let args = extractors::extract_query_args(self.field.arguments, &model)?;
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+        let selection_order = collect_selection_tree(&nested_fields);
+
+        let aggregators = nested_fields
+            .into_iter()
+            .map(|field| resolve_query(field, &model))
+            .collect::<QueryGraphBuilderResult<_>>()?;
 
         Ok(ReadQuery::AggregateRecordsQuery(AggregateRecordsQuery {
             name,
             alias,
             model,
+            args,
+            aggregators,
+            selection_order,
         }))
     }
 }
+
+/// Collects the selected aggregation names, including the selected fields of field based aggregations.
+pub(super) fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, Option<Vec<String>>)> {
+    fields
+        .iter()
+        .map(|field| {
+            (
+                field.name.clone(),
+                field
+                    .nested_fields
+                    .as_ref()
+                    .map(|nested_object| nested_object.fields.iter().map(|f| f.name.clone()).collect()),
+            )
+        })
+        .collect()
+}
+
+/// Resolves the given field as an aggregation query.
+/// Group by queries select the aggregations with a leading underscore (e.g. `_count`).
+pub(super) fn resolve_query(field: ParsedField, model: &ModelRef) -> QueryGraphBuilderResult<Aggregator> {
+    let query = match field.name.trim_start_matches('_') {
+        "count" => Aggregator::Count,
+        "avg" => Aggregator::Average(resolve_fields(model, field)?),
+        "sum" => Aggregator::Sum(resolve_fields(model, field)?),
+        "min" => Aggregator::Min(resolve_fields(model, field)?),
+        "max" => Aggregator::Max(resolve_fields(model, field)?),
+        _ => unreachable!(),
+    };
+
+    Ok(query)
+}
+
+fn resolve_fields(model: &ModelRef, field: ParsedField) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+    let fields = field.nested_fields.map(|obj| obj.fields).unwrap_or_else(Vec::new);
+
+    fields
+        .into_iter()
+        .map(|f| model.fields().find_from_scalar(&f.name).map_err(Into::into))
+        .collect()
+}
@@ -0,0 +1,121 @@
+use super::*;
+use crate::{
+    query_document::{ParsedField, ParsedInputMap, ParsedInputValue},
+    GroupByRecordsQuery, ReadQuery,
+};
+use prisma_models::{ModelRef, PrismaValue, ScalarFieldRef};
+use std::convert::TryInto;
+
+/// The aggregations of a group are prefixed with an underscore to set them apart from the fields of the model,
+/// which can't start with one.
+const AGGREGATIONS: &[&str] = &["_count", "_avg", "_sum", "_min", "_max"];
+
+pub struct GroupByRecordsBuilder {
+    field: ParsedField,
+    model: ModelRef,
+}
+
+impl GroupByRecordsBuilder {
+    pub fn new(field: ParsedField, model: ModelRef) -> Self {
+        Self { field, model }
+    }
+}
+
+impl Builder<ReadQuery> for GroupByRecordsBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let model = self.model;
+        let mut arguments = self.field.arguments;
+
+        let group_by = match arguments.iter().position(|arg| arg.name == "by") {
+            Some(pos) => extract_group_by(arguments.remove(pos).value, &model)?,
+            None => vec![],
+        };
+
+        let having = match arguments.iter().position(|arg| arg.name == "having") {
+            Some(pos) => {
+                let value: Option<ParsedInputMap> = arguments.remove(pos).value.try_into()?;
+                match value {
+                    Some(map) => Some(extractors::extract_having_filter(map, &model, &group_by)?),
+                    None => None,
+                }
+            }
+            None => None,
+        };
+
+        let args = extractors::extract_query_args(arguments, &model)?;
+        let nested_fields = self.field.nested_fields.unwrap().fields;
+        let selection_order = super::aggregate::collect_selection_tree(&nested_fields);
+
+        if group_by.is_empty() {
+            return Err(QueryGraphBuilderError::InputError(
+                "At least one field to group by is required.".to_owned(),
+            ));
+        }
+
+        for order_by in args.order_by.iter() {
+            match order_by.own_field() {
+                Some(field) if group_by.contains(field) => (),
+                Some(field) => {
+                    return Err(QueryGraphBuilderError::InputError(format!(
+                        "Field '{}' used in orderBy must be present in the fields to group by.",
+                        field.name
+                    )))
+                }
+                None => {
+                    return Err(QueryGraphBuilderError::InputError(
+                        "Groups can only be ordered by the fields they are grouped by.".to_owned(),
+                    ))
+                }
+            }
+        }
+
+        let (aggregations, fields): (Vec<ParsedField>, Vec<ParsedField>) = nested_fields
+            .into_iter()
+            .partition(|field| AGGREGATIONS.contains(&field.name.as_str()));
+
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !group_by.iter().any(|sf| sf.name == field.name))
+        {
+            return Err(QueryGraphBuilderError::InputError(format!(
+                "Field '{}' is selected but not present in the fields to group by.",
+                field.name
+            )));
+        }
+
+        let aggregators = aggregations
+            .into_iter()
+            .map(|field| super::aggregate::resolve_query(field, &model))
+            .collect::<QueryGraphBuilderResult<_>>()?;
+
+        Ok(ReadQuery::GroupByRecordsQuery(GroupByRecordsQuery {
+            name,
+            alias,
+            model,
+            args,
+            group_by,
+            having,
+            aggregators,
+            selection_order,
+        }))
+    }
+}
+
+fn extract_group_by(value: ParsedInputValue, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ScalarFieldRef>> {
+    let value: PrismaValue = value.try_into()?;
+
+    match value {
+        PrismaValue::List(values) => values
+            .into_iter()
+            .map(|value| match value {
+                PrismaValue::Enum(field_name) => model.fields().find_from_scalar(&field_name).map_err(Into::into),
+                _ => unreachable!(),
+            })
+            .collect(),
+
+        PrismaValue::Enum(field_name) => Ok(vec![model.fields().find_from_scalar(&field_name)?]),
+        _ => unreachable!(),
+    }
+}
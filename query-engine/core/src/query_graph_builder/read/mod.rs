@@ -1,15 +1,20 @@
 mod aggregate;
+mod group_by;
 mod many;
 mod one;
 mod related;
+mod related_count;
 
 pub use aggregate::*;
+pub use group_by::*;
 pub use many::*;
 pub use one::*;
 pub use related::*;
+pub use related_count::*;
 
 use super::*;
 use crate::{query_document::ParsedField, ReadQuery};
+use connector::QueryArguments;
 use prisma_models::{
     Field, ModelRef, RelationFieldRef, SelectedField, SelectedFields, SelectedRelationField, SelectedScalarField,
 };
@@ -19,7 +24,9 @@ pub enum ReadQueryBuilder {
     ReadOneRecordBuilder(ReadOneRecordBuilder),
     ReadManyRecordsBuilder(ReadManyRecordsBuilder),
     ReadRelatedRecordsBuilder(ReadRelatedRecordsBuilder),
+    RelatedRecordsCountBuilder(RelatedRecordsCountBuilder),
     AggregateRecordsBuilder(AggregateRecordsBuilder),
+    GroupByRecordsBuilder(GroupByRecordsBuilder),
 }
 
 impl Builder<ReadQuery> for ReadQueryBuilder {
@@ -28,7 +35,9 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
             ReadQueryBuilder::ReadOneRecordBuilder(b) => b.build(),
             ReadQueryBuilder::ReadManyRecordsBuilder(b) => b.build(),
             ReadQueryBuilder::ReadRelatedRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::RelatedRecordsCountBuilder(b) => b.build(),
             ReadQueryBuilder::AggregateRecordsBuilder(b) => b.build(),
+            ReadQueryBuilder::GroupByRecordsBuilder(b) => b.build(),
         }
     }
 }
@@ -46,9 +55,11 @@ pub fn collect_selection_order(from: &[ParsedField]) -> Vec<String> {
 
 /// Creates SelectedFields from a query selection.
 /// Automatically adds model IDs to the selected fields as well.
+/// Relation counts (`_count`) are resolved by a nested query and don't select any fields.
 pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> SelectedFields {
     let selected_fields = from
         .iter()
+        .filter(|selected_field| selected_field.name != RELATION_COUNT_FIELD)
         .map(|selected_field| {
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
@@ -71,6 +82,12 @@ pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> Select
 pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
         .filter_map(|selected_field| {
+            if selected_field.name == RELATION_COUNT_FIELD {
+                return Some(ReadQueryBuilder::RelatedRecordsCountBuilder(
+                    RelatedRecordsCountBuilder::new(Arc::clone(model), selected_field),
+                ));
+            }
+
             let model_field = model.fields().find_from_all(&selected_field.name).unwrap();
             match model_field {
                 Field::Scalar(_) => None,
@@ -106,8 +123,27 @@ pub fn merge_relation_selections(
     }
 
     for nested in nested_queries {
-        if let ReadQuery::RelatedRecordsQuery(ref rq) = nested {
-            selected_fields.add_all(rq.parent_field.linking_fields().into_iter());
+        match nested {
+            ReadQuery::RelatedRecordsQuery(ref rq) => {
+                selected_fields.add_all(rq.parent_field.linking_fields().into_iter());
+            }
+            ReadQuery::RelatedRecordsCountQuery(ref cq) => {
+                for rf in cq.relation_fields.iter() {
+                    selected_fields.add_all(rf.linking_fields().into_iter());
+                }
+            }
+            _ => (),
+        }
+    }
+
+    selected_fields.deduplicate()
+}
+
+/// Adds the fields the records are made distinct on, as duplicates may be removed in memory.
+pub fn merge_distinct_selections(mut selected_fields: SelectedFields, args: &QueryArguments) -> SelectedFields {
+    if let Some(ref distinct) = args.distinct {
+        for field in distinct {
+            selected_fields.add_scalar(Arc::clone(field));
         }
     }
 
@@ -0,0 +1,42 @@
+use super::*;
+use crate::{query_document::ParsedField, ReadQuery, RelatedRecordsCountQuery};
+use prisma_models::{ModelRef, RelationFieldRef, Result as DomainResult};
+
+/// Name of the output field selecting the related record counts of a record.
+pub const RELATION_COUNT_FIELD: &str = "_count";
+
+pub struct RelatedRecordsCountBuilder {
+    /// The model the counted relations belong to.
+    model: ModelRef,
+
+    /// The `_count` field as parsed field in the query document.
+    field: ParsedField,
+}
+
+impl RelatedRecordsCountBuilder {
+    pub fn new(model: ModelRef, field: ParsedField) -> Self {
+        Self { model, field }
+    }
+}
+
+impl Builder<ReadQuery> for RelatedRecordsCountBuilder {
+    fn build(self) -> QueryGraphBuilderResult<ReadQuery> {
+        let name = self.field.name;
+        let alias = self.field.alias;
+        let sub_selections = self.field.nested_fields.unwrap().fields;
+        let selection_order = collect_selection_order(&sub_selections);
+
+        let relation_fields = sub_selections
+            .iter()
+            .map(|selected_field| self.model.fields().find_from_relation_fields(&selected_field.name))
+            .collect::<DomainResult<Vec<RelationFieldRef>>>()?;
+
+        Ok(ReadQuery::RelatedRecordsCountQuery(RelatedRecordsCountQuery {
+            name,
+            alias,
+            model: self.model,
+            relation_fields,
+            selection_order,
+        }))
+    }
+}
@@ -0,0 +1,402 @@
+use super::*;
+use crate::{
+    query_ast::*,
+    query_graph::{Flow, Node, NodeRef, QueryGraph, QueryGraphDependency},
+    InputAssertions, ParsedInputMap, ParsedInputValue,
+};
+use connector::{Filter, IdFilter};
+use prisma_models::{ModelProjection, ModelRef, RelationFieldRef};
+use std::{convert::TryInto, sync::Arc};
+
+/// Handles nested connect or create cases.
+/// Every `connectOrCreate` input reads the child by its unique `where` input and either
+/// connects the existing child or creates a new one from the `create` input.
+/// The resulting graph depends on where the relation is inlined.
+/// Information on the graph shapes can be found on the individual handlers.
+pub fn connect_nested_connect_or_create(
+    graph: &mut QueryGraph,
+    parent_node: NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    value: ParsedInputValue,
+    child_model: &ModelRef,
+) -> QueryGraphBuilderResult<()> {
+    let relation = parent_relation_field.relation();
+
+    for value in utils::coerce_vec(value) {
+        let mut value: ParsedInputMap = value.try_into()?;
+
+        let where_input = value.remove("where").expect("where argument is missing");
+        let create_input = value.remove("create").expect("create argument is missing");
+
+        if relation.is_many_to_many() {
+            handle_many_to_many(
+                graph,
+                &parent_node,
+                parent_relation_field,
+                where_input,
+                create_input,
+                child_model,
+            )?;
+        } else if parent_relation_field.relation_is_inlined_in_parent() {
+            handle_inlined_on_parent(
+                graph,
+                &parent_node,
+                parent_relation_field,
+                where_input,
+                create_input,
+                child_model,
+            )?;
+        } else {
+            handle_inlined_on_child(
+                graph,
+                &parent_node,
+                parent_relation_field,
+                where_input,
+                create_input,
+                child_model,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles a many-to-many nested connect or create.
+/// Both arms of the if flow connect the child to the parent, either the existing or the newly created one.
+///
+/// ```text
+///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ┐
+/// ┌──      Parent       ─────────────────────────┐
+/// │  └ ─ ─ ─ ─ ─ ─ ─ ─ ┘                         │
+/// │           │                                  │
+/// │           ▼                                  │
+/// │  ┌─────────────────┐                         │
+/// │  │   Read Child    │────────┐                │
+/// │  └─────────────────┘        │                │
+/// │           │                 │                │
+/// │           ▼                 │                │
+/// │  ┌─────────────────┐        │                │
+/// │  │   If (exists)   │────────┼───────┐        │
+/// │  └─────────────────┘        │       │        │
+/// │      then │                 │  else │        │
+/// │           ▼                 │       ▼        │
+/// │  ┌─────────────────┐        │  ┌─────────────────┐
+/// ├─▶│     Connect     │◀───────┘  │  Create Child   │
+/// │  └─────────────────┘           └─────────────────┘
+/// │                                         │
+/// │                                         ▼
+/// │                                ┌─────────────────┐
+/// └───────────────────────────────▶│     Connect     │
+///                                  └─────────────────┘
+/// ```
+fn handle_many_to_many(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    where_input: ParsedInputValue,
+    create_input: ParsedInputValue,
+    child_model: &ModelRef,
+) -> QueryGraphBuilderResult<()> {
+    let filter = extract_unique_filter(where_input, child_model)?;
+    let read_query = utils::read_ids_infallible(child_model.clone(), child_model.primary_identifier(), filter);
+    let read_node = graph.create_node(read_query);
+
+    graph.create_edge(parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
+
+    let if_node = insert_exists_check(graph, &read_node, child_model, true)?;
+    let connect_existing_node =
+        connect::connect_records_node(graph, parent_node, &read_node, parent_relation_field, 1)?;
+
+    graph.create_edge(&if_node, &connect_existing_node, QueryGraphDependency::Then)?;
+
+    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_input.try_into()?)?;
+
+    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    connect::connect_records_node(graph, parent_node, &create_node, parent_relation_field, 1)?;
+
+    Ok(())
+}
+
+/// Handles one-to-many and one-to-one nested connect or creates with the relation inlined on the child.
+/// The parent ID is injected into the update of the existing child or into the create of the new child.
+///
+/// For one-to-one relations, an existing child of the parent is disconnected first (if the parent is not a create),
+/// and the relation of the existing child to another parent is checked (if the parent side is required).
+/// See `insert_existing_1to1_related_model_checks` for the shape of the checks.
+///
+/// ```text
+///    ┌ ─ ─ ─ ─ ─ ─ ─ ─ ┐
+/// ┌──      Parent       ─────────────────────────┐
+/// │  └ ─ ─ ─ ─ ─ ─ ─ ─ ┘                         │
+/// │           │                                  │
+/// │           ▼                                  │
+/// │  ┌─────────────────┐                         │
+/// │  │   Read Child    │────────┐                │
+/// │  └─────────────────┘        │                │
+/// │           │                 │                │
+/// │           ▼                 │                │
+/// │  ┌─────────────────┐        │                │
+/// │  │   If (exists)   │────────┼───────┐        │
+/// │  └─────────────────┘        │       │        │
+/// │      then │                 │  else │        │
+/// │           ▼                 │       ▼        │
+/// │  ┌─────────────────┐        │  ┌─────────────────┐
+/// └─▶│  Update Child   │◀───────┘  │  Create Child   │
+///    └─────────────────┘           └─────────────────┘
+/// ```
+fn handle_inlined_on_child(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    where_input: ParsedInputValue,
+    create_input: ParsedInputValue,
+    child_model: &ModelRef,
+) -> QueryGraphBuilderResult<()> {
+    let child_relation_field = parent_relation_field.related_field();
+    let child_model_identifier = child_model.primary_identifier();
+    let child_link = child_relation_field.linking_fields();
+
+    if parent_relation_field.relation().is_one_to_one() {
+        let parent_is_create = utils::node_is_create(graph, parent_node);
+
+        // Build-time check, same as for a nested connect.
+        if parent_relation_field.is_required && child_relation_field.is_required {
+            return Err(QueryGraphBuilderError::RelationViolation(
+                (parent_relation_field).into(),
+            ));
+        }
+
+        if !parent_is_create {
+            utils::insert_existing_1to1_related_model_checks(graph, parent_node, parent_relation_field)?;
+        }
+    }
+
+    let filter = extract_unique_filter(where_input, child_model)?;
+    let read_query = utils::read_ids_infallible(child_model.clone(), child_link.clone(), filter);
+    let read_node = graph.create_node(read_query);
+
+    graph.create_edge(parent_node, &read_node, QueryGraphDependency::ExecutionOrder)?;
+
+    // The existing child might be connected to another parent which requires the relation.
+    if parent_relation_field.relation().is_one_to_one() && parent_relation_field.is_required {
+        utils::insert_existing_1to1_related_model_checks(graph, &read_node, &child_relation_field)?;
+    }
+
+    let if_node = insert_exists_check(graph, &read_node, child_model, true)?;
+    let update_node = utils::update_records_node_placeholder(graph, Filter::empty(), Arc::clone(child_model));
+
+    graph.create_edge(&if_node, &update_node, QueryGraphDependency::Then)?;
+    graph.create_edge(
+        &read_node,
+        &update_node,
+        QueryGraphDependency::ParentProjection(child_model_identifier, Box::new(move |mut update_node, mut child_ids| {
+            let child_id = match child_ids.pop() {
+                Some(id) => Ok(id),
+                None => Err(QueryGraphBuilderError::AssertionError(format!(
+                    "[Query Graph] Expected a valid child ID to be present for a nested connect or create, updating the existing child."
+                ))),
+            }?;
+
+            if let Node::Query(Query::Write(ref mut wq)) = update_node {
+                wq.add_filter(child_id.filter());
+            }
+
+            Ok(update_node)
+        })),
+    )?;
+
+    inject_parent_link(graph, parent_node, &update_node, parent_relation_field)?;
+
+    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_input.try_into()?)?;
+
+    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    inject_parent_link(graph, parent_node, &create_node, parent_relation_field)?;
+
+    Ok(())
+}
+
+/// Handles one-to-many and one-to-one nested connect or creates with the relation inlined on the parent.
+/// As the parent operation requires the child ID, the child is read (and created if it doesn't exist yet) upfront.
+/// Both arms of the if flow return the linking fields of the child: The existing child as read by the `where` input,
+/// or the newly created child. The parent then gets the child injected from the result of the if flow.
+///
+/// For one-to-one relations, the existing child might be connected to another parent, which is disconnected first
+/// (or the connect fails if that parent requires the relation).
+/// See `insert_existing_1to1_related_model_checks` for the shape of the checks.
+///
+/// ```text
+///    ┌─────────────────┐
+///    │   Read Child    │─────────┐
+///    └─────────────────┘         │
+///             │                  │
+///             ▼                  │
+///    ┌─────────────────┐         │
+/// ┌──│   If (exists)   │─────────┼───────┐
+/// │  └─────────────────┘         │       │
+/// │       then │                 │  else │
+/// │            ▼                 │       ▼
+/// │  ┌─────────────────┐         │  ┌─────────────────┐
+/// │  │     Return      │◀────────┘  │  Create Child   │
+/// │  └─────────────────┘            └─────────────────┘
+/// │                                          │
+/// │                                          ▼
+/// │                                 ┌─────────────────┐
+/// │                                 │     Return      │
+/// │                                 └─────────────────┘
+/// │  ┌ ─ ─ ─ ─ ─ ─ ─ ─ ┐
+/// └─▶      Parent
+///    └ ─ ─ ─ ─ ─ ─ ─ ─ ┘
+/// ```
+fn handle_inlined_on_parent(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+    where_input: ParsedInputValue,
+    create_input: ParsedInputValue,
+    child_model: &ModelRef,
+) -> QueryGraphBuilderResult<()> {
+    let parent_link = parent_relation_field.linking_fields();
+    let child_link = parent_relation_field.related_field().linking_fields();
+
+    let filter = extract_unique_filter(where_input, child_model)?;
+    let read_query = utils::read_ids_infallible(child_model.clone(), child_link.clone(), filter);
+    let read_node = graph.create_node(read_query);
+
+    // The existing child might be connected to another parent.
+    if parent_relation_field.relation().is_one_to_one() {
+        utils::insert_existing_1to1_related_model_checks(graph, &read_node, &parent_relation_field.related_field())?;
+    }
+
+    let if_node = insert_exists_check(graph, &read_node, child_model, true)?;
+    let return_existing_node = graph.create_node(Flow::default_return());
+
+    graph.create_edge(&if_node, &return_existing_node, QueryGraphDependency::Then)?;
+    insert_return_projection(graph, &read_node, &return_existing_node, child_link.clone())?;
+
+    let create_node = create::create_record_node(graph, Arc::clone(child_model), create_input.try_into()?)?;
+    let return_created_node = graph.create_node(Flow::default_return());
+
+    graph.create_edge(&if_node, &create_node, QueryGraphDependency::Else)?;
+    insert_return_projection(graph, &create_node, &return_created_node, child_link.clone())?;
+
+    graph.create_edge(
+        &if_node,
+        parent_node,
+        QueryGraphDependency::ParentProjection(child_link, Box::new(move |mut parent_node, mut child_links| {
+            let child_link = match child_links.pop() {
+                Some(link) => Ok(link),
+                None => Err(QueryGraphBuilderError::AssertionError(format!(
+                    "[Query Graph] Expected a valid child ID to be present for a nested connect or create, connecting the parent."
+                ))),
+            }?;
+
+            if let Node::Query(Query::Write(ref mut wq)) = parent_node {
+                wq.inject_projection_into_args(parent_link.assimilate(child_link)?);
+            }
+
+            Ok(parent_node)
+        })),
+    )?;
+
+    // The child has to be read (and created) before the parent operation runs.
+    graph.mark_nodes(parent_node, &read_node);
+
+    Ok(())
+}
+
+/// Inserts an if flow node after `read_node` that checks whether the child has been found (`exists`)
+/// or has not been found (`!exists`).
+fn insert_exists_check(
+    graph: &mut QueryGraph,
+    read_node: &NodeRef,
+    child_model: &ModelRef,
+    exists: bool,
+) -> QueryGraphBuilderResult<NodeRef> {
+    let if_node = graph.create_node(Flow::default_if());
+
+    graph.create_edge(
+        read_node,
+        &if_node,
+        QueryGraphDependency::ParentProjection(
+            child_model.primary_identifier(),
+            Box::new(move |if_node, child_ids| {
+                if let Node::Flow(Flow::If(_)) = if_node {
+                    Ok(Node::Flow(Flow::If(Box::new(move || child_ids.is_empty() != exists))))
+                } else {
+                    Ok(if_node)
+                }
+            }),
+        ),
+    )?;
+
+    Ok(if_node)
+}
+
+/// Returns the `projection` of the records of `source_node` from `return_node`.
+fn insert_return_projection(
+    graph: &mut QueryGraph,
+    source_node: &NodeRef,
+    return_node: &NodeRef,
+    projection: ModelProjection,
+) -> QueryGraphBuilderResult<()> {
+    graph.create_edge(
+        source_node,
+        return_node,
+        QueryGraphDependency::ParentProjection(
+            projection,
+            Box::new(move |return_node, projections| {
+                if let Node::Flow(Flow::Return(_)) = return_node {
+                    Ok(Node::Flow(Flow::Return(Some(projections))))
+                } else {
+                    Ok(return_node)
+                }
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// Injects the linking fields of the parent into the child write (inlined relation on the child).
+fn inject_parent_link(
+    graph: &mut QueryGraph,
+    parent_node: &NodeRef,
+    child_node: &NodeRef,
+    parent_relation_field: &RelationFieldRef,
+) -> QueryGraphBuilderResult<()> {
+    let parent_link = parent_relation_field.linking_fields();
+    let child_link = parent_relation_field.related_field().linking_fields();
+
+    graph.create_edge(
+        parent_node,
+        child_node,
+        QueryGraphDependency::ParentProjection(
+            parent_link,
+            Box::new(move |mut child_node, mut parent_links| {
+                let parent_link = match parent_links.pop() {
+                    Some(link) => Ok(link),
+                    None => Err(QueryGraphBuilderError::AssertionError(format!(
+                        "[Query Graph] Expected a valid parent ID to be present for a nested connect or create."
+                    ))),
+                }?;
+
+                if let Node::Query(Query::Write(ref mut wq)) = child_node {
+                    wq.inject_projection_into_args(child_link.assimilate(parent_link)?);
+                }
+
+                Ok(child_node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+fn extract_unique_filter(where_input: ParsedInputValue, child_model: &ModelRef) -> QueryGraphBuilderResult<Filter> {
+    let where_input: ParsedInputMap = where_input.try_into()?;
+
+    where_input.assert_size(1)?;
+    where_input.assert_non_null()?;
+
+    extract_filter(where_input, child_model, false)
+}
@@ -1,4 +1,5 @@
 mod connect_nested;
+mod connect_or_create_nested;
 mod create_nested;
 mod delete_nested;
 mod disconnect_nested;
@@ -14,6 +15,7 @@ use crate::{
     ParsedInputMap,
 };
 use connect_nested::*;
+use connect_or_create_nested::*;
 use create_nested::*;
 use delete_nested::*;
 use prisma_models::RelationFieldRef;
@@ -35,6 +37,9 @@ pub fn connect_nested_query(
             "upsert" => connect_nested_upsert(graph, parent, &parent_relation_field, value)?,
             "delete" => connect_nested_delete(graph, &parent, &parent_relation_field, value, &child_model)?,
             "connect" => connect_nested_connect(graph, parent, &parent_relation_field, value, &child_model)?,
+            "connectOrCreate" => {
+                connect_nested_connect_or_create(graph, parent, &parent_relation_field, value, &child_model)?
+            }
             "disconnect" => connect_nested_disconnect(graph, parent, &parent_relation_field, value, &child_model)?,
             "set" => connect_nested_set(graph, &parent, &parent_relation_field, value, &child_model)?,
             "updateMany" => connect_nested_update_many(graph, &parent, &parent_relation_field, value, &child_model)?,
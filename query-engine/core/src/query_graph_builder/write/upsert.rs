@@ -2,11 +2,12 @@ use super::*;
 use crate::{
     query_ast::*,
     query_graph::{Flow, Node, QueryGraph, QueryGraphDependency},
-    ArgumentListLookup, InputAssertions, ParsedField, ParsedInputMap, ReadOneRecordBuilder,
+    ArgumentListLookup, InputAssertions, ParsedField, ParsedInputMap, ParsedInputValue, ReadOneRecordBuilder,
 };
 use connector::IdFilter;
-use prisma_models::ModelRef;
+use prisma_models::{Field, ModelRef, ScalarFieldRef};
 use std::{convert::TryInto, sync::Arc};
+use write_args_parser::*;
 
 pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedField) -> QueryGraphBuilderResult<()> {
     let where_arg: ParsedInputMap = field.arguments.lookup("where").unwrap().value.try_into()?;
@@ -14,17 +15,28 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
     where_arg.assert_size(1)?;
     where_arg.assert_non_null()?;
 
+    let create_arg = field.arguments.lookup("create").unwrap();
+    let defaulted_fields = create_arg.defaulted_fields;
+    let create_map: ParsedInputMap = create_arg.value.try_into()?;
+    let update_map: ParsedInputMap = field.arguments.lookup("update").unwrap().value.try_into()?;
+
+    let create_args = WriteArgsParser::from(&model, create_map.clone())?;
+    let update_args = WriteArgsParser::from(&model, update_map.clone())?;
+
+    if let Some(conflict_field) =
+        native_upsert_conflict_field(&model, &where_arg, &create_args, &update_args, &defaulted_fields)
+    {
+        return native_upsert_record(graph, model, field, conflict_field, create_args, update_args);
+    }
+
     let filter = extract_filter(where_arg, &model, false)?;
     let model_id = model.primary_identifier();
 
-    let create_argument = field.arguments.lookup("create").unwrap();
-    let update_argument = field.arguments.lookup("update").unwrap();
-
     let read_parent_records = utils::read_ids_infallible(model.clone(), model_id.clone(), filter.clone());
     let read_parent_records_node = graph.create_node(read_parent_records);
 
-    let create_node = create::create_record_node(graph, Arc::clone(&model), create_argument.value.try_into()?)?;
-    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_argument.value.try_into()?)?;
+    let create_node = create::create_record_node(graph, Arc::clone(&model), create_map)?;
+    let update_node = update::update_record_node(graph, filter, Arc::clone(&model), update_map)?;
 
     let read_query = ReadOneRecordBuilder::new(field, Arc::clone(&model)).build()?;
     let read_node_create = graph.create_node(Query::Read(read_query.clone()));
@@ -99,3 +111,118 @@ pub fn upsert_record(graph: &mut QueryGraph, model: ModelRef, mut field: ParsedF
 
     Ok(())
 }
+
+/// Upserts the record with a single native upsert statement instead of reading the record first,
+/// which would race with concurrent writes of the same record.
+fn native_upsert_record(
+    graph: &mut QueryGraph,
+    model: ModelRef,
+    field: ParsedField,
+    conflict_field: ScalarFieldRef,
+    create_args: WriteArgsParser,
+    update_args: WriteArgsParser,
+) -> QueryGraphBuilderResult<()> {
+    let mut create = create_args.args;
+    let mut update = update_args.args;
+
+    create.add_datetimes(Arc::clone(&model));
+    update.update_datetimes(Arc::clone(&model));
+
+    let upsert_node = graph.create_node(Query::Write(WriteQuery::NativeUpsert(NativeUpsert {
+        model: Arc::clone(&model),
+        conflict_field,
+        create,
+        update,
+    })));
+
+    let read_query = ReadOneRecordBuilder::new(field, Arc::clone(&model)).build()?;
+    let read_node = graph.create_node(Query::Read(read_query));
+
+    graph.add_result_node(&read_node);
+    graph.create_edge(
+        &upsert_node,
+        &read_node,
+        QueryGraphDependency::ParentProjection(
+            model.primary_identifier(),
+            Box::new(move |mut read_node, mut parent_ids| {
+                let parent_id = match parent_ids.pop() {
+                    Some(pid) => Ok(pid),
+                    None => Err(QueryGraphBuilderError::AssertionError(format!(
+                        "Expected a valid parent ID to be present for native upsert follow-up read query."
+                    ))),
+                }?;
+
+                if let Node::Query(Query::Read(ReadQuery::RecordQuery(ref mut rq))) = read_node {
+                    rq.add_filter(parent_id.filter());
+                };
+
+                Ok(read_node)
+            }),
+        ),
+    )?;
+
+    Ok(())
+}
+
+/// Returns the field to conflict on if the upsert can be done natively. That is the case if:
+/// - The `where` targets a single unique scalar field, which `create` sets to the same value and `update` leaves untouched.
+/// - There are no nested writes.
+/// - `create` sets no other unique criterion of the model. The native statement could otherwise conflict on that
+///   criterion instead, and MySQL would update the record that conflicts there. Fields the parser filled in from
+///   their default (e.g. a fresh `@default(cuid())` id) don't count, but values given by the client always do.
+fn native_upsert_conflict_field(
+    model: &ModelRef,
+    where_arg: &ParsedInputMap,
+    create_args: &WriteArgsParser,
+    update_args: &WriteArgsParser,
+    defaulted_fields: &[String],
+) -> Option<ScalarFieldRef> {
+    if !create_args.nested.is_empty() || !update_args.nested.is_empty() {
+        return None;
+    }
+
+    let (field_name, where_value) = match where_arg.iter().next() {
+        Some((field_name, ParsedInputValue::Single(value))) => (field_name, value),
+        _ => return None,
+    };
+
+    let conflict_field = model.fields().find_from_scalar(field_name).ok()?;
+    let create_value = create_args
+        .args
+        .get_field_value(conflict_field.db_name())
+        .and_then(|expr| expr.as_value());
+
+    if !conflict_field.unique() || create_value != Some(where_value) {
+        return None;
+    }
+
+    if update_args.args.has_arg_for(conflict_field.db_name()) {
+        return None;
+    }
+
+    let other_unique_fields = model
+        .fields()
+        .scalar()
+        .into_iter()
+        .filter(|sf| sf.unique())
+        .chain(model.fields().id().unwrap_or_default())
+        .chain(
+            model
+                .unique_indexes()
+                .into_iter()
+                .flat_map(|index| index.fields())
+                .filter_map(|field| match field {
+                    Field::Scalar(sf) => Some(sf),
+                    Field::Relation(_) => None,
+                }),
+        )
+        .filter(|sf| sf.name != conflict_field.name);
+
+    for sf in other_unique_fields {
+        if create_args.args.has_arg_for(sf.db_name()) && !defaulted_fields.contains(&sf.name) {
+            return None;
+        }
+    }
+
+    Some(conflict_field)
+}
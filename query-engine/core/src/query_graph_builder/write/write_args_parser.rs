@@ -1,7 +1,11 @@
 use super::*;
-use crate::query_document::{ParsedInputMap, ParsedInputValue};
-use connector::WriteArgs;
+use crate::{
+    query_document::{ParsedInputMap, ParsedInputValue},
+    InputAssertions,
+};
+use connector::{WriteArgs, WriteExpression};
 use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use rust_decimal::Decimal;
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
@@ -36,10 +40,16 @@ impl WriteArgsParser {
 
                         args.args.insert(sf.db_name().clone(), set_value)
                     }
-                    Field::Scalar(sf) => {
-                        let value: PrismaValue = v.try_into()?;
-                        args.args.insert(sf.db_name().clone(), value)
-                    }
+                    Field::Scalar(sf) => match v {
+                        ParsedInputValue::Map(map) => {
+                            let expr = Self::parse_write_operation(map)?;
+                            args.args.insert(sf.db_name().clone(), expr)
+                        }
+                        v => {
+                            let value: PrismaValue = v.try_into()?;
+                            args.args.insert(sf.db_name().clone(), value)
+                        }
+                    },
 
                     Field::Relation(ref rf) => {
                         args.nested.push((Arc::clone(rf), v.try_into()?));
@@ -50,4 +60,36 @@ impl WriteArgsParser {
             },
         )
     }
+
+    /// Parses the atomic operations input of a numeric field (e.g. `{ increment: 1 }`).
+    /// Exactly one operation is allowed per field, dividing by zero is rejected.
+    fn parse_write_operation(map: ParsedInputMap) -> QueryGraphBuilderResult<WriteExpression> {
+        map.assert_size(1)?;
+
+        let (operation, value) = map.into_iter().next().unwrap();
+        let value: PrismaValue = value.try_into()?;
+
+        let is_zero = match value {
+            PrismaValue::Int(i) => i == 0,
+            PrismaValue::Float(ref f) => *f == Decimal::new(0, 0),
+            _ => false,
+        };
+
+        if operation == "divide" && is_zero {
+            return Err(QueryGraphBuilderError::InputError(
+                "Division by zero is not allowed.".to_owned(),
+            ));
+        }
+
+        let expr = match operation.as_str() {
+            "set" => WriteExpression::Value(value),
+            "increment" => WriteExpression::Add(value),
+            "decrement" => WriteExpression::Subtract(value),
+            "multiply" => WriteExpression::Multiply(value),
+            "divide" => WriteExpression::Divide(value),
+            _ => unreachable!("Unknown write operation: {}", operation),
+        };
+
+        Ok(expr)
+    }
 }
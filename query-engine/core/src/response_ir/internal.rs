@@ -1,8 +1,9 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    CoreError, EnumType, QueryResult, RecordSelection,
+    CoreError, EnumType, QueryResult, RecordAggregation, RecordSelection, RelationCounts,
 };
+use connector::{AggregationResult, AggregationRow};
 use indexmap::IndexMap;
 use prisma_models::{InternalEnum, PrismaValue, RecordProjection};
 use rust_decimal::prelude::ToPrimitive;
@@ -40,6 +41,8 @@ pub fn serialize_internal(
 ) -> crate::Result<CheckedItemsWithParents> {
     match result {
         QueryResult::RecordSelection(rs) => serialize_record_selection(rs, typ, is_list, is_optional),
+        QueryResult::RecordAggregation(ra) => serialize_aggregation(ra, typ),
+        QueryResult::RelationCounts(rc) => Ok(serialize_relation_counts(rc)),
 
         QueryResult::Count(c) => {
             // Todo needs a real implementation
@@ -59,6 +62,121 @@ pub fn serialize_internal(
     }
 }
 
+fn serialize_aggregation(
+    record_aggregation: RecordAggregation,
+    typ: &OutputTypeRef,
+) -> crate::Result<CheckedItemsWithParents> {
+    let ordering = record_aggregation.selection_order;
+    let grouped = record_aggregation.grouped;
+    let aggregate_object_type = typ
+        .as_object_type()
+        .expect("Invariant violation: Aggregations are always serialized into objects.");
+
+    let items = record_aggregation
+        .results
+        .into_iter()
+        .map(|row| serialize_aggregation_row(row, &ordering, &aggregate_object_type, grouped))
+        .collect::<crate::Result<Vec<Item>>>()?;
+
+    // Plain aggregations always yield exactly one row, grouped aggregations a list of rows.
+    let item = if typ.is_list() {
+        Item::list(items)
+    } else {
+        items.into_iter().next().unwrap_or_else(Item::null)
+    };
+
+    let mut result = CheckedItemsWithParents::new();
+    result.insert(None, item);
+
+    Ok(result)
+}
+
+fn serialize_aggregation_row(
+    row: AggregationRow,
+    ordering: &[(String, Option<Vec<String>>)],
+    aggregate_object_type: &ObjectTypeStrongRef,
+    grouped: bool,
+) -> crate::Result<Item> {
+    // Group by outputs prefix the aggregations with an underscore (e.g. `_count`) to set them apart from the fields.
+    let prefix = if grouped { "_" } else { "" };
+
+    // Flatten all results into `<name>` (fields, count) and `<aggregation>_<field name>` (e.g. avg) keys.
+    let mut flattened = HashMap::with_capacity(ordering.len());
+
+    for result in row {
+        let (key, item) = match result {
+            AggregationResult::Field(field, value) => {
+                let output_field = aggregate_object_type.find_field(&field.name).unwrap();
+                let item = serialize_scalar(value, &output_field.field_type)?;
+
+                (field.name.clone(), item)
+            }
+            AggregationResult::Count(count) => {
+                (format!("{}count", prefix), Item::Value(PrismaValue::Int(count as i64)))
+            }
+            AggregationResult::Average(field, value) => {
+                serialize_aggregated_field(&aggregate_object_type, &format!("{}avg", prefix), &field.name, value)?
+            }
+            AggregationResult::Sum(field, value) => {
+                serialize_aggregated_field(&aggregate_object_type, &format!("{}sum", prefix), &field.name, value)?
+            }
+            AggregationResult::Min(field, value) => {
+                serialize_aggregated_field(&aggregate_object_type, &format!("{}min", prefix), &field.name, value)?
+            }
+            AggregationResult::Max(field, value) => {
+                serialize_aggregated_field(&aggregate_object_type, &format!("{}max", prefix), &field.name, value)?
+            }
+        };
+
+        flattened.insert(key, item);
+    }
+
+    // Reorder the results based on the original query selection.
+    let mut map = Map::with_capacity(ordering.len());
+
+    for (name, fields) in ordering {
+        match fields {
+            Some(fields) => {
+                let nested = fields.iter().fold(Map::new(), |mut acc, field| {
+                    let item = flattened
+                        .remove(&format!("{}_{}", name, field))
+                        .unwrap_or_else(Item::null);
+
+                    acc.insert(field.clone(), item);
+                    acc
+                });
+
+                map.insert(name.clone(), Item::Map(nested));
+            }
+            None => {
+                let item = flattened.remove(name).unwrap_or_else(Item::null);
+                map.insert(name.clone(), item);
+            }
+        }
+    }
+
+    Ok(Item::Map(map))
+}
+
+/// Serializes a single field based aggregation result with the type of the corresponding nested output field.
+/// Unwraps are safe due to query validation.
+fn serialize_aggregated_field(
+    aggregate_object_type: &ObjectTypeStrongRef,
+    aggregation: &str,
+    field_name: &str,
+    value: PrismaValue,
+) -> crate::Result<(String, Item)> {
+    let nested_type = aggregate_object_type
+        .find_field(aggregation)
+        .and_then(|field| field.field_type.as_object_type())
+        .unwrap();
+
+    let field = nested_type.find_field(field_name).unwrap();
+    let item = serialize_scalar(value, &field.field_type)?;
+
+    Ok((format!("{}_{}", aggregation, field_name), item))
+}
+
 fn serialize_record_selection(
     record_selection: RecordSelection,
     typ: &OutputTypeRef,
@@ -247,6 +365,25 @@ fn write_nested_items(
     });
 }
 
+/// Serializes the related record counts into one `_count` object per parent record.
+fn serialize_relation_counts(relation_counts: RelationCounts) -> CheckedItemsWithParents {
+    let fields = relation_counts.fields;
+
+    relation_counts
+        .counts
+        .into_iter()
+        .map(|(parent_id, counts)| {
+            let mut map = Map::with_capacity(fields.len());
+
+            for (field_name, count) in fields.iter().zip(counts) {
+                map.insert(field_name.clone(), Item::Value(PrismaValue::Int(count as i64)));
+            }
+
+            (Some(parent_id), Item::Ref(ItemRef::new(Item::Map(map))))
+        })
+        .collect()
+}
+
 /// Processes nested results into a more ergonomic structure of { <nested field name> -> { parent ID -> item (list, map, ...) } }.
 fn process_nested_results(
     nested: Vec<QueryResult>,
@@ -259,12 +396,18 @@ fn process_nested_results(
     // Unwraps are safe due to query validation.
     for nested_result in nested {
         // todo Workaround, tb changed with flat reads.
-        if let QueryResult::RecordSelection(ref rs) = nested_result {
-            let name = rs.name.clone();
-            let field = enclosing_type.find_field(&name).unwrap();
-            let result = serialize_internal(nested_result, &field.field_type, false, false)?;
+        match nested_result {
+            QueryResult::RecordSelection(ref rs) => {
+                let name = rs.name.clone();
+                let field = enclosing_type.find_field(&name).unwrap();
+                let result = serialize_internal(nested_result, &field.field_type, false, false)?;
 
-            nested_mapping.insert(name, result);
+                nested_mapping.insert(name, result);
+            }
+            QueryResult::RelationCounts(rc) => {
+                nested_mapping.insert(rc.name.clone(), serialize_relation_counts(rc));
+            }
+            _ => (),
         }
     }
 
@@ -326,6 +469,10 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::Boolean, PrismaValue::Boolean(b)) => PrismaValue::Boolean(b),
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
+        (ScalarType::Json, PrismaValue::Json(j)) => PrismaValue::Json(j),
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+        (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+        (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
 
         (st, pv) => {
             return Err(CoreError::SerializationError(format!(
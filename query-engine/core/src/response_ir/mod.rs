@@ -11,7 +11,7 @@
 mod internal;
 mod utils;
 
-use crate::{ExpressionResult, OutputType, OutputTypeRef, QueryResult, QueryValue};
+use crate::{ExpressionResult, OutputType, OutputTypeRef, QueryResult, QueryValue, RecordSelection};
 use indexmap::IndexMap;
 use internal::*;
 use prisma_models::PrismaValue;
@@ -94,6 +94,13 @@ pub struct ResponseError {
     user_facing_error: user_facing_errors::Error,
 }
 
+impl ResponseError {
+    /// The user facing error code, `None` if the error is unknown.
+    pub fn error_code(&self) -> Option<&str> {
+        self.user_facing_error.error_code()
+    }
+}
+
 impl From<user_facing_errors::Error> for ResponseError {
     fn from(err: user_facing_errors::Error) -> ResponseError {
         ResponseError {
@@ -148,6 +155,10 @@ impl Responses {
         self.errors.push(error.into());
     }
 
+    pub fn errors(&self) -> &[ResponseError] {
+        &self.errors
+    }
+
     pub fn get_data(&self, key: impl AsRef<str>) -> Option<&Item> {
         self.data.get(key.as_ref())
     }
@@ -292,7 +303,21 @@ impl IrSerializer {
             }
 
             ExpressionResult::Empty => panic!("Domain logic error: Attempted to serialize empty result."),
-            ExpressionResult::Computation(_) => panic!("Domain logic error: Attempted to serialize non-query result."),
+            ExpressionResult::Computation(_) | ExpressionResult::FixedResult(_) => {
+                panic!("Domain logic error: Attempted to serialize non-query result.")
+            }
         }
     }
+
+    /// Serializes a chunk of records of a streamed list query, returning the serialized records.
+    pub fn serialize_records(&self, selection: RecordSelection) -> crate::Result<Vec<Item>> {
+        let result = serialize_internal(QueryResult::RecordSelection(selection), &self.output_type, false, false)?;
+
+        Ok(result
+            .into_iter()
+            .next()
+            .and_then(|(_, item)| item.into_list())
+            .map(|list| list.into_iter().collect())
+            .unwrap_or_default())
+    }
 }
@@ -1,4 +1,4 @@
-use connector::QueryArguments;
+use connector::{AggregationRow, QueryArguments};
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
 #[derive(Debug, Clone)]
@@ -6,6 +6,8 @@ pub enum QueryResult {
     Id(Option<RecordProjection>),
     Count(usize),
     RecordSelection(RecordSelection),
+    RecordAggregation(RecordAggregation),
+    RelationCounts(RelationCounts),
     Unit,
     Json(serde_json::Value),
 }
@@ -32,3 +34,29 @@ pub struct RecordSelection {
     /// Model projection that can be used to retrieve the IDs of the contained records.
     pub model_id: ModelProjection,
 }
+
+#[derive(Debug, Clone)]
+pub struct RecordAggregation {
+    /// Ordered list of selected aggregations, with the ordered list of selected fields for field based aggregations.
+    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+
+    /// Actual aggregation results, one row per group of records.
+    /// Aggregations without grouping always yield exactly one row.
+    pub results: Vec<AggregationRow>,
+
+    /// Whether the results are groups of a group by query, whose aggregations are prefixed with an underscore
+    /// (e.g. `_count`) to set them apart from the grouped fields.
+    pub grouped: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RelationCounts {
+    /// Name of the query.
+    pub name: String,
+
+    /// Ordered list of selected relation names.
+    pub fields: Vec<String>,
+
+    /// Related record counts of each parent record, in the order of the selected relations.
+    pub counts: Vec<(RecordProjection, Vec<usize>)>,
+}
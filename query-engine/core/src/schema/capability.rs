@@ -1,4 +1,5 @@
 //! This serves as a skeleton for future capability work.
+use prisma_models::{ScalarField, TypeIdentifier};
 
 #[derive(Debug)]
 pub struct SupportedCapabilities {
@@ -12,18 +13,24 @@ impl SupportedCapabilities {
 
     pub fn has(&self, capability: ConnectorCapability) -> bool {
         match capability {
-            ConnectorCapability::ScalarLists(l) => self
-                .capabilities
-                .iter()
-                .find(|_| match l {
-                    ScalarListsCapability::EmbeddedScalarLists => true,
-                    ScalarListsCapability::NonEmbeddedScalarList => true,
+            // Scalar lists of any kind.
+            ConnectorCapability::ScalarLists(ScalarListsCapability::ScalarLists) => {
+                self.capabilities.iter().any(|c| match c {
+                    ConnectorCapability::ScalarLists(_) => true,
                     _ => false,
                 })
-                .is_some(),
-            capability => self.capabilities.iter().find(|c| **c == capability).is_some(),
+            }
+            capability => self.capabilities.contains(&capability),
         }
     }
+
+    /// String fields can be full-text searched natively, or with a `@@fulltext` index on the field.
+    pub fn can_search(&self, field: &ScalarField) -> bool {
+        field.type_identifier == TypeIdentifier::String
+            && !field.is_list
+            && (self.has(ConnectorCapability::FullTextSearch)
+                || (self.has(ConnectorCapability::FullTextIndex) && field.model().fulltext_index_on(field).is_some()))
+    }
 }
 
 #[allow(dead_code)]
@@ -43,6 +50,9 @@ pub enum ConnectorCapability {
     MongoJoinRelationLinks,
     RelationLinkList,
     RelationLinkTable,
+    InsensitiveFilters, // String filters with `mode: insensitive`.
+    FullTextSearch,     // Native full-text search on string fields, without dedicated indices.
+    FullTextIndex,      // Full-text search on string fields with a `@@fulltext` index on the field.
 }
 
 #[derive(Debug, PartialEq)]
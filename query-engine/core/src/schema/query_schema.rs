@@ -209,12 +209,14 @@ pub enum QueryTag {
     FindOne,
     FindMany,
     CreateOne,
+    CreateMany,
     UpdateOne,
     UpdateMany,
     DeleteOne,
     DeleteMany,
     UpsertOne,
     Aggregate,
+    GroupBy,
 }
 
 impl fmt::Display for QueryTag {
@@ -223,12 +225,14 @@ impl fmt::Display for QueryTag {
             QueryTag::FindOne => "findOne",
             QueryTag::FindMany => "findMany",
             QueryTag::CreateOne => "createOne",
+            QueryTag::CreateMany => "createMany",
             QueryTag::UpdateOne => "updateOne",
             QueryTag::UpdateMany => "updateMany",
             QueryTag::DeleteOne => "deleteOne",
             QueryTag::DeleteMany => "deleteMany",
             QueryTag::UpsertOne => "upsertOne",
             QueryTag::Aggregate => "aggregate",
+            QueryTag::GroupBy => "groupBy",
         };
 
         s.fmt(f)
@@ -294,6 +298,9 @@ pub enum InputType {
     Object(InputObjectTypeRef),
     Opt(Box<InputType>),
     Scalar(ScalarType),
+
+    /// Accepts a value of any of the contained types, tried in order.
+    Union(Vec<InputType>),
 }
 
 impl InputType {
@@ -309,6 +316,10 @@ impl InputType {
         InputType::Object(containing)
     }
 
+    pub fn union(types: Vec<InputType>) -> InputType {
+        InputType::Union(types)
+    }
+
     pub fn string() -> InputType {
         InputType::Scalar(ScalarType::String)
     }
@@ -340,6 +351,14 @@ impl InputType {
     pub fn uuid() -> InputType {
         InputType::Scalar(ScalarType::UUID)
     }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
+
+    pub fn big_int() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
 }
 
 #[derive(Debug)]
@@ -392,6 +411,14 @@ impl OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
+    pub fn big_int() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
@@ -432,6 +459,8 @@ pub enum ScalarType {
     Json,
     JsonList,
     UUID,
+    Bytes,
+    BigInt,
 }
 
 impl From<EnumType> for OutputType {
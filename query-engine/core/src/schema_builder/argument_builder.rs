@@ -1,4 +1,5 @@
 use super::*;
+use prisma_models::dml;
 
 pub struct ArgumentBuilder<'a> {
     input_type_builder: Weak<InputTypeBuilder<'a>>,
@@ -39,6 +40,35 @@ impl<'a> ArgumentBuilder<'a> {
         }
     }
 
+    /// Builds "data" and "skipDuplicates" arguments intended for the create many field.
+    /// Records are created without nested writes, so models with required relations inlined into them are excluded.
+    pub fn create_many_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
+        let has_required_inlined_relation = model
+            .fields()
+            .relation()
+            .iter()
+            .any(|rf| rf.is_required && rf.is_inlined_on_enclosing_model());
+
+        if has_required_inlined_relation {
+            return None;
+        }
+
+        let input_object_type = self.input_type_builder.into_arc().create_many_input_type(model);
+
+        if input_object_type.into_arc().is_empty() {
+            None
+        } else {
+            Some(vec![
+                argument("data", InputType::list(InputType::object(input_object_type)), None),
+                argument(
+                    "skipDuplicates",
+                    InputType::opt(InputType::boolean()),
+                    Some(dml::DefaultValue::Single(dml::ScalarValue::Boolean(false))),
+                ),
+            ])
+        }
+    }
+
     /// Builds "where" (unique) argument intended for the delete field.
     pub fn delete_arguments(&self, model: ModelRef) -> Option<Vec<Argument>> {
         self.where_unique_argument(model).map(|arg| vec![arg])
@@ -12,6 +12,7 @@ struct StaticFilterArguments {
     pub inclusion: &'static [FilterArgument],
     pub alphanumeric: &'static [FilterArgument],
     pub string: &'static [FilterArgument],
+    pub scalar_list: &'static [FilterArgument],
     pub multi_relation: &'static [FilterArgument],
     pub one_relation: &'static [FilterArgument],
 }
@@ -85,6 +86,25 @@ static FILTER_ARGUMENTS: StaticFilterArguments = StaticFilterArguments {
         }, // All values not ending with the given string.
     ],
 
+    scalar_list: &[
+        FilterArgument {
+            suffix: "",
+            is_list: true,
+        }, // All lists equal to the given list.
+        FilterArgument {
+            suffix: "_has",
+            is_list: false,
+        }, // All lists containing the given value.
+        FilterArgument {
+            suffix: "_has_some",
+            is_list: true,
+        }, // All lists containing at least one of the given values.
+        FilterArgument {
+            suffix: "_has_every",
+            is_list: true,
+        }, // All lists containing all of the given values.
+    ],
+
     multi_relation: &[
         FilterArgument {
             suffix: "_every",
@@ -111,17 +131,19 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
 
     let filters = match field {
         ModelField::Relation(_) if field.is_list() => vec![&args.multi_relation],
-        ModelField::Scalar(_) if field.is_list() => vec![],
+        ModelField::Scalar(_) if field.is_list() => vec![&args.scalar_list],
         ModelField::Relation(_) => vec![&args.one_relation],
         ModelField::Scalar(sf) => match sf.type_identifier {
-            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
             TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
             TypeIdentifier::Boolean => vec![&args.base],
             TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
             TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Json => vec![],
+            TypeIdentifier::Json => vec![&args.base],
+            TypeIdentifier::Bytes => vec![&args.base],
+            TypeIdentifier::BigInt => vec![&args.base, &args.inclusion, &args.alphanumeric],
         },
     };
 
@@ -131,3 +153,14 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
         .flatten()
         .collect()
 }
+
+/// Filters applicable to the result of an aggregation (e.g. `count_gt` or `avg: { field_lt: ... }`).
+pub fn get_aggregation_filters<'a>() -> Vec<&'a FilterArgument> {
+    let args = &FILTER_ARGUMENTS;
+
+    vec![&args.base, &args.inclusion, &args.alphanumeric]
+        .into_iter()
+        .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
+        .flatten()
+        .collect()
+}
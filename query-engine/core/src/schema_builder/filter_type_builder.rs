@@ -1,4 +1,5 @@
 use super::*;
+use prisma_models::{InternalEnum, InternalEnumValue};
 
 /// Filter object and scalar filter object type builder.
 #[derive(Debug)]
@@ -62,6 +63,134 @@ impl<'a> FilterObjectTypeBuilder<'a> {
         weak_ref
     }
 
+    /// Builds the filter object used to filter groups of records (e.g. `UserScalarWhereWithAggregatesInput`).
+    /// Next to the scalar fields the groups are filtered on, it allows filtering on `_count`
+    /// and the field based aggregations of the group (e.g. `_avg`).
+    pub fn scalar_filter_with_aggregates_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let object_name = format!("{}ScalarWhereWithAggregatesInput", model.name);
+        return_cached!(self.get_cache(), &object_name);
+
+        let input_object = Arc::new(init_input_object_type(object_name.clone()));
+        self.cache(object_name, Arc::clone(&input_object));
+
+        let weak_ref = Arc::downgrade(&input_object);
+        let mut input_fields = vec![
+            input_field(
+                "AND",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "OR",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+            input_field(
+                "NOT",
+                InputType::opt(InputType::list(InputType::object(Weak::clone(&weak_ref)))),
+                None,
+            ),
+        ];
+
+        let fields: Vec<ScalarFieldRef> = model.fields().scalar_non_list();
+        let mut fields: Vec<InputField> = fields.into_iter().flat_map(|f| self.map_input_field(f)).collect();
+        let mut count_fields = Self::map_aggregation_input_fields("_count", InputType::int());
+
+        input_fields.append(&mut fields);
+        input_fields.append(&mut count_fields);
+
+        let numeric_fields = ObjectTypeBuilder::collect_aggregation_fields(&model, false);
+        let comparable_fields = ObjectTypeBuilder::collect_aggregation_fields(&model, true);
+
+        append_opt(
+            &mut input_fields,
+            self.aggregation_filter_field("_avg", &model, &numeric_fields, |_| InputType::float()),
+        );
+
+        append_opt(
+            &mut input_fields,
+            self.aggregation_filter_field("_sum", &model, &numeric_fields, |sf| {
+                self.map_required_input_type(Arc::clone(sf))
+            }),
+        );
+
+        append_opt(
+            &mut input_fields,
+            self.aggregation_filter_field("_min", &model, &comparable_fields, |sf| {
+                self.map_required_input_type(Arc::clone(sf))
+            }),
+        );
+
+        append_opt(
+            &mut input_fields,
+            self.aggregation_filter_field("_max", &model, &comparable_fields, |sf| {
+                self.map_required_input_type(Arc::clone(sf))
+            }),
+        );
+
+        input_object.set_fields(input_fields);
+        weak_ref
+    }
+
+    /// Builds a field based aggregation filter field (e.g. `_avg`) and its object type (e.g. `UserAvgAggregateWhereInput`).
+    /// Returns `None` if the model has no fields that can be aggregated that way.
+    fn aggregation_filter_field<F>(
+        &self,
+        aggregation: &str,
+        model: &ModelRef,
+        fields: &[ScalarFieldRef],
+        type_mapping: F,
+    ) -> Option<InputField>
+    where
+        F: Fn(&ScalarFieldRef) -> InputType,
+    {
+        if fields.is_empty() {
+            return None;
+        }
+
+        let object_name = format!(
+            "{}{}AggregateWhereInput",
+            model.name,
+            capitalize(aggregation.trim_start_matches('_'))
+        );
+        let object = match self.get_cache().get(&object_name) {
+            Some(cached) => cached,
+            None => {
+                let input_object = Arc::new(init_input_object_type(object_name.clone()));
+                let input_fields = fields
+                    .iter()
+                    .flat_map(|sf| Self::map_aggregation_input_fields(&sf.name, type_mapping(sf)))
+                    .collect();
+
+                input_object.set_fields(input_fields);
+                self.cache(object_name, Arc::clone(&input_object));
+
+                Arc::downgrade(&input_object)
+            }
+        };
+
+        Some(input_field(
+            aggregation,
+            InputType::opt(InputType::object(object)),
+            None,
+        ))
+    }
+
+    fn map_aggregation_input_fields(name: &str, typ: InputType) -> Vec<InputField> {
+        get_aggregation_filters()
+            .into_iter()
+            .map(|arg| {
+                let field_name = format!("{}{}", name, arg.suffix);
+
+                if arg.is_list {
+                    input_field(field_name, InputType::opt(InputType::list(typ.clone())), None)
+                } else {
+                    input_field(field_name, InputType::opt(typ.clone()), None)
+                }
+            })
+            .collect()
+    }
+
     pub fn filter_object_type(&self, model: ModelRef) -> InputObjectTypeRef {
         if self.capabilities.has(ConnectorCapability::MongoJoinRelationLinks) {
             self.build_mongo_filter_object(model)
@@ -124,11 +253,27 @@ impl<'a> FilterObjectTypeBuilder<'a> {
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
-        get_field_filters(&ModelField::Scalar(Arc::clone(&field))) // wip: take a look at required signatures
+        // Scalar lists are native arrays, only the connectors storing them that way can filter them.
+        let embedded_lists = ConnectorCapability::ScalarLists(ScalarListsCapability::EmbeddedScalarLists);
+        if field.is_list && !self.capabilities.has(embedded_lists) {
+            return vec![];
+        }
+
+        // Fields of the model always take precedence over the filters of other fields named like them.
+        let model = field.model();
+        let is_free = |name: &str| model.fields().find_from_all(name).is_err();
+
+        let mut input_fields: Vec<InputField> = get_field_filters(&ModelField::Scalar(Arc::clone(&field))) // wip: take a look at required signatures
             .into_iter()
+            .filter(|arg| arg.suffix.is_empty() || is_free(&format!("{}{}", field.name, arg.suffix)))
             .map(|arg| {
                 let field_name = format!("{}{}", field.name, arg.suffix);
-                let mapped = self.map_required_input_type(Arc::clone(&field));
+
+                // Scalar list filters are built from the type of the list elements.
+                let mapped = match self.map_required_input_type(Arc::clone(&field)) {
+                    InputType::List(inner) if field.is_list => *inner,
+                    mapped => mapped,
+                };
 
                 if arg.is_list {
                     input_field(field_name, InputType::opt(InputType::list(mapped)), None)
@@ -136,7 +281,61 @@ impl<'a> FilterObjectTypeBuilder<'a> {
                     input_field(field_name, InputType::opt(mapped), None)
                 }
             })
-            .collect()
+            .collect();
+
+        let is_empty_name = format!("{}_is_empty", field.name);
+        if field.is_list && is_free(&is_empty_name) {
+            input_fields.push(input_field(is_empty_name, InputType::opt(InputType::boolean()), None));
+        }
+
+        // String fields can be searched with the full-text search of the database (`<field>_search: "fox & dog"`).
+        // MySQL and SQLite only search fields with a `@@fulltext` index on them.
+        let search_name = format!("{}_search", field.name);
+        if self.capabilities.can_search(&field) && is_free(&search_name) {
+            input_fields.push(input_field(search_name, InputType::opt(InputType::string()), None));
+        }
+
+        // String filters can be made case insensitive with `<field>_mode: insensitive`.
+        let mode_name = format!("{}_mode", field.name);
+        if field.type_identifier == TypeIdentifier::String
+            && !field.is_list
+            && self.capabilities.has(ConnectorCapability::InsensitiveFilters)
+            && is_free(&mode_name)
+        {
+            input_fields.push(input_field(
+                mode_name,
+                InputType::opt(Self::query_mode_enum().into()),
+                None,
+            ));
+        }
+
+        // JSON filters can compare a nested value with `<field>_path: ["key", "0"]`.
+        let path_name = format!("{}_path", field.name);
+        if field.type_identifier == TypeIdentifier::Json && !field.is_list && is_free(&path_name) {
+            input_fields.push(input_field(
+                path_name,
+                InputType::opt(InputType::list(InputType::string())),
+                None,
+            ));
+        }
+
+        input_fields
+    }
+
+    /// The comparison modes of string filters.
+    fn query_mode_enum() -> EnumType {
+        let values = vec!["default", "insensitive"]
+            .into_iter()
+            .map(|name| InternalEnumValue {
+                name: name.to_owned(),
+                database_name: None,
+            })
+            .collect();
+
+        EnumType::Internal(InternalEnum {
+            name: "QueryMode".to_owned(),
+            values,
+        })
     }
 
     /// Maps relations to (filter) input fields.
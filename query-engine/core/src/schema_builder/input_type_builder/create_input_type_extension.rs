@@ -17,6 +17,30 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
         self.cache(name, Arc::clone(&input_object));
 
         // Compute input fields for scalar fields.
+        let mut fields = self.scalar_input_fields_for_create(Arc::clone(&model));
+
+        // Compute input fields for relational fields.
+        let mut relational_fields = self.relation_input_fields_create(Arc::clone(&model), parent_field.as_ref());
+        fields.append(&mut relational_fields);
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds the create many input type (<x>CreateManyInput), which only has the scalar fields of the create input type.
+    fn create_many_input_type(&self, model: ModelRef) -> InputObjectTypeRef {
+        let name = format!("{}CreateManyInput", model.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_fields = self.scalar_input_fields_for_create(Arc::clone(&model));
+        let input_object = Arc::new(input_object_type(name.clone(), input_fields));
+
+        self.cache(name, Arc::clone(&input_object));
+        Arc::downgrade(&input_object)
+    }
+
+    #[rustfmt::skip]
+    fn scalar_input_fields_for_create(&self, model: ModelRef) -> Vec<InputField> {
         let scalar_fields: Vec<ScalarFieldRef> = model
             .fields()
             .scalar()
@@ -24,7 +48,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             .filter(|f|  Self::field_should_be_kept_for_create_input_type(&f))
             .collect();
 
-        let mut fields = self.scalar_input_fields(
+        self.scalar_input_fields(
             model.name.clone(),
             "Create",
             scalar_fields,
@@ -38,14 +62,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                 }
             },
             true
-        );
-
-        // Compute input fields for relational fields.
-        let mut relational_fields = self.relation_input_fields_create(Arc::clone(&model), parent_field.as_ref());
-        fields.append(&mut relational_fields);
-
-        input_object.set_fields(fields);
-        Arc::downgrade(&input_object)
+        )
     }
 
     /// For create input types only. Compute input fields for relational fields.
@@ -91,6 +108,7 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
                             let mut fields = vec![self.nested_create_input_field(Arc::clone(&rf))];
                             let nested_connect = self.nested_connect_input_field(Arc::clone(&rf));
                             append_opt(&mut fields, nested_connect);
+                            append_opt(&mut fields, self.nested_connect_or_create_field(Arc::clone(&rf)));
 
                             input_object.set_fields(fields);
                             Arc::downgrade(&input_object)
@@ -117,6 +135,53 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
         input_field("create", input_object, None)
     }
 
+    /// Builds "connectOrCreate" field for nested creates and updates (on relation fields).
+    fn nested_connect_or_create_field(&self, field: RelationFieldRef) -> Option<InputField> {
+        self.nested_connect_or_create_input_object(Arc::clone(&field))
+            .map(|input_object| {
+                let input_type = Self::wrap_list_input_object_type(input_object, field.is_list);
+                input_field("connectOrCreate", input_type, None)
+            })
+    }
+
+    /// Builds "<x>CreateOrConnectWithout<y>Input" input object types.
+    fn nested_connect_or_create_input_object(&self, parent_field: RelationFieldRef) -> Option<InputObjectTypeRef> {
+        let related_model = parent_field.related_model();
+
+        if related_model.is_embedded {
+            return None;
+        }
+
+        let where_object = self.where_unique_object_type(&related_model);
+        let create_object = self.create_input_type(Arc::clone(&related_model), Some(Arc::clone(&parent_field)));
+
+        if where_object.into_arc().is_empty() || create_object.into_arc().is_empty() {
+            return None;
+        }
+
+        let type_name = format!(
+            "{}CreateOrConnectWithout{}Input",
+            related_model.name,
+            capitalize(&parent_field.related_field().name)
+        );
+
+        match self.get_cache().get(&type_name) {
+            None => {
+                let input_object = Arc::new(init_input_object_type(type_name.clone()));
+                self.cache(type_name, Arc::clone(&input_object));
+
+                let fields = vec![
+                    input_field("where", InputType::object(where_object), None),
+                    input_field("create", InputType::object(create_object), None),
+                ];
+
+                input_object.set_fields(fields);
+                Some(Arc::downgrade(&input_object))
+            }
+            x => x,
+        }
+    }
+
     fn field_should_be_kept_for_create_input_type(field: &ScalarFieldRef) -> bool {
         !field.is_auto_generated_int_id
     }
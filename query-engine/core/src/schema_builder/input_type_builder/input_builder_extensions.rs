@@ -15,6 +15,8 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::UUID => InputType::uuid(),
             TypeIdentifier::DateTime => InputType::date_time(),
             TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Bytes => InputType::bytes(),
+            TypeIdentifier::BigInt => InputType::big_int(),
             TypeIdentifier::Enum(_) => self.map_enum_input_type(&field),
         };
 
@@ -37,11 +37,61 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
             model.name.clone(),
             "Update",
             model.fields().scalar(),
-            |f: ScalarFieldRef| self.map_optional_input_type(f),
+            |f: ScalarFieldRef| self.map_update_input_type(f),
             false,
         )
     }
 
+    /// Maps a scalar field to its update input type.
+    /// Int, BigInt and Float fields accept atomic operations as an alternative to the plain value,
+    /// except for fields of the primary identifier.
+    fn map_update_input_type(&self, field: ScalarFieldRef) -> InputType {
+        let is_numeric = match field.type_identifier {
+            TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float => true,
+            _ => false,
+        };
+
+        let is_identifier = field.model().primary_identifier().get(&field.name).is_some();
+        let typ = self.map_required_input_type(Arc::clone(&field));
+
+        if is_numeric && !field.is_list && !is_identifier {
+            let operations_object = self.field_update_operations_object_type(&field, typ.clone());
+            InputType::opt(InputType::union(vec![typ, InputType::object(operations_object)]))
+        } else {
+            InputType::opt(typ)
+        }
+    }
+
+    /// Builds "<x>FieldUpdateOperationsInput" input object types, e.g. "IntFieldUpdateOperationsInput".
+    fn field_update_operations_object_type(&self, field: &ScalarFieldRef, typ: InputType) -> InputObjectTypeRef {
+        let type_name = match field.type_identifier {
+            TypeIdentifier::Int => "Int",
+            TypeIdentifier::BigInt => "BigInt",
+            _ => "Float",
+        };
+
+        let name = format!("{}FieldUpdateOperationsInput", type_name);
+        return_cached!(self.get_cache(), &name);
+
+        let mut fields = vec![
+            input_field("set", InputType::opt(typ.clone()), None),
+            input_field("increment", InputType::opt(typ.clone()), None),
+            input_field("decrement", InputType::opt(typ.clone()), None),
+            input_field("multiply", InputType::opt(typ.clone()), None),
+        ];
+
+        // Integer division rounds differently per database (truncated on Postgres and SQLite, rounded on MySQL),
+        // so only floats can be divided.
+        if field.type_identifier == TypeIdentifier::Float {
+            fields.push(input_field("divide", InputType::opt(typ), None));
+        }
+
+        let input_object = Arc::new(input_object_type(name.clone(), fields));
+        self.cache(name, Arc::clone(&input_object));
+
+        Arc::downgrade(&input_object)
+    }
+
     /// For update input types only. Compute input fields for relational fields.
     /// This recurses into create_input_type (via nested_create_input_field).
     /// Todo: This code is fairly similar to "create" relation computation. Let's see if we can dry it up.
@@ -92,6 +142,7 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
                             let mut fields = vec![self.nested_create_input_field(Arc::clone(&rf))];
 
                             append_opt(&mut fields, self.nested_connect_input_field(Arc::clone(&rf)));
+                            append_opt(&mut fields, self.nested_connect_or_create_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_set_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_disconnect_input_field(Arc::clone(&rf)));
                             append_opt(&mut fields, self.nested_delete_input_field(Arc::clone(&rf)));
@@ -1,5 +1,6 @@
 use super::*;
-use prisma_models::OrderBy;
+use prisma_models::{InternalEnum, InternalEnumValue, OrderBy, OrderByTarget};
+use std::collections::HashSet;
 
 #[derive(Debug)]
 pub struct ObjectTypeBuilder<'a> {
@@ -72,7 +73,7 @@ impl<'a> ObjectTypeBuilder<'a> {
 
     /// This assumes that the cache has already been initialized.
     fn compute_fields(&self, model: &ModelRef) -> Vec<Field> {
-        model
+        let mut fields: Vec<Field> = model
             .fields()
             .all
             .iter()
@@ -81,7 +82,52 @@ impl<'a> ObjectTypeBuilder<'a> {
                 ModelField::Relation(_) => self.with_relations,
             })
             .map(|f| self.map_field(f))
-            .collect()
+            .collect();
+
+        append_opt(&mut fields, self.relation_count_field(model));
+        fields
+    }
+
+    /// Builds the `_count` field of a model, selecting the number of related records
+    /// of each to-many relation. Models without to-many relations don't have the field.
+    fn relation_count_field(&self, model: &ModelRef) -> Option<Field> {
+        if !self.with_relations {
+            return None;
+        }
+
+        let relation_fields: Vec<_> = model
+            .fields()
+            .relation()
+            .into_iter()
+            .filter(|rf| rf.is_list && !rf.related_model().is_embedded)
+            .collect();
+
+        if relation_fields.is_empty() {
+            return None;
+        }
+
+        Some(field(
+            "_count",
+            vec![],
+            OutputType::object(self.relation_count_object_type(model, &relation_fields)),
+            None,
+        ))
+    }
+
+    /// Builds the relation count object type for given model (e.g. UserCountOutputType).
+    fn relation_count_object_type(&self, model: &ModelRef, relation_fields: &[RelationFieldRef]) -> ObjectTypeRef {
+        let name = format!("{}CountOutputType", capitalize(&model.name));
+        return_cached!(self.get_cache(), &name);
+
+        let fields = relation_fields
+            .iter()
+            .map(|rf| field(rf.name.clone(), vec![], OutputType::int(), None))
+            .collect();
+
+        let object_type = Arc::new(object_type(&name, fields, None));
+
+        self.cache(name, Arc::clone(&object_type));
+        Arc::downgrade(&object_type)
     }
 
     pub fn map_field(&self, model_field: &ModelField) -> Field {
@@ -104,16 +150,7 @@ impl<'a> ObjectTypeBuilder<'a> {
                     related_model_obj
                 }
             }
-            ModelField::Scalar(sf) => match sf.type_identifier {
-                TypeIdentifier::String => OutputType::string(),
-                TypeIdentifier::Float => OutputType::float(),
-                TypeIdentifier::Boolean => OutputType::boolean(),
-                TypeIdentifier::Enum(_) => Self::map_enum_field(sf).into(),
-                TypeIdentifier::Json => OutputType::json(),
-                TypeIdentifier::DateTime => OutputType::date_time(),
-                TypeIdentifier::UUID => OutputType::uuid(),
-                TypeIdentifier::Int => OutputType::int(),
-            },
+            ModelField::Scalar(sf) => Self::map_scalar_output_type(sf),
         };
 
         if model_field.is_scalar() && model_field.is_list() {
@@ -125,12 +162,27 @@ impl<'a> ObjectTypeBuilder<'a> {
         }
     }
 
+    fn map_scalar_output_type(sf: &ScalarFieldRef) -> OutputType {
+        match sf.type_identifier {
+            TypeIdentifier::String => OutputType::string(),
+            TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Boolean => OutputType::boolean(),
+            TypeIdentifier::Enum(_) => Self::map_enum_field(sf).into(),
+            TypeIdentifier::Json => OutputType::json(),
+            TypeIdentifier::DateTime => OutputType::date_time(),
+            TypeIdentifier::UUID => OutputType::uuid(),
+            TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::Bytes => OutputType::bytes(),
+            TypeIdentifier::BigInt => OutputType::big_int(),
+        }
+    }
+
     /// Builds "many records where" arguments based on the given model and field.
     pub fn many_records_field_arguments(&self, field: &ModelField) -> Vec<Argument> {
         match field {
             ModelField::Scalar(_) => vec![],
             ModelField::Relation(rf) if rf.is_list && !rf.related_model().is_embedded => {
-                self.many_records_arguments(&rf.related_model())
+                self.find_many_arguments(&rf.related_model())
             }
             ModelField::Relation(rf) if rf.is_list && rf.related_model().is_embedded => vec![],
             ModelField::Relation(rf) if !rf.is_list => vec![],
@@ -155,6 +207,14 @@ impl<'a> ObjectTypeBuilder<'a> {
         ]
     }
 
+    /// Builds the arguments of find many fields, which are the "many records where" arguments and "distinct".
+    pub fn find_many_arguments(&self, model: &ModelRef) -> Vec<Argument> {
+        let mut arguments = self.many_records_arguments(model);
+        arguments.push(self.distinct_argument(model));
+
+        arguments
+    }
+
     /// Builds "where" argument.
     pub fn where_argument(&self, model: &ModelRef) -> Argument {
         let where_object = self
@@ -165,29 +225,96 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("where", InputType::opt(InputType::object(where_object)), None)
     }
 
-    // Builds "orderBy" argument.
+    // Builds "orderBy" argument. Accepts a single value or a list of values, ordered by precedence.
+    // Next to the scalar fields of the model, records can be ordered by the scalar fields of to-one
+    // related records (e.g. `author__name_ASC`) and by the number of to-many related records (e.g. `comments__count_DESC`).
+    // String fields that can be full-text searched can also be ordered by their search relevance
+    // (e.g. `title__relevance_DESC`), which requires a search filter on the field.
+    // Scalar fields keep their names, all other targets are composed with the fixed `__` separator, so adding a field
+    // never renames existing values. A scalar field whose name equals a composed name is rejected.
     pub fn order_by_argument(&self, model: &ModelRef) -> Argument {
-        let enum_values: Vec<_> = model
+        let mut order_targets: Vec<(String, Option<String>, Vec<RelationFieldRef>, OrderByTarget)> = model
             .fields()
             .scalar_non_list()
-            .iter()
-            .map(|field| {
-                vec![
-                    (
-                        format!("{}_{}", field.name, SortOrder::Ascending.abbreviated()),
-                        OrderBy {
-                            field: field.clone(),
-                            sort_order: SortOrder::Ascending,
-                        },
-                    ),
-                    (
-                        format!("{}_{}", field.name, SortOrder::Descending.abbreviated()),
-                        OrderBy {
-                            field: field.clone(),
-                            sort_order: SortOrder::Descending,
-                        },
-                    ),
-                ]
+            .into_iter()
+            .map(|field| (field.name.clone(), None, vec![], OrderByTarget::Field(field)))
+            .collect();
+
+        for field in model.fields().scalar_non_list() {
+            if self.capabilities.can_search(&field) {
+                order_targets.push((
+                    field.name.clone(),
+                    Some("relevance".to_owned()),
+                    vec![],
+                    OrderByTarget::Relevance { field, search: None },
+                ));
+            }
+        }
+
+        if self.with_relations {
+            let relation_fields = model
+                .fields()
+                .relation()
+                .into_iter()
+                .filter(|rf| !rf.related_model().is_embedded);
+
+            for rf in relation_fields {
+                if rf.is_list {
+                    order_targets.push((
+                        rf.name.clone(),
+                        Some("count".to_owned()),
+                        vec![Arc::clone(&rf)],
+                        OrderByTarget::Count,
+                    ));
+                } else {
+                    for field in rf.related_model().fields().scalar_non_list() {
+                        order_targets.push((
+                            rf.name.clone(),
+                            Some(field.name.clone()),
+                            vec![Arc::clone(&rf)],
+                            OrderByTarget::Field(field),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut taken_names: HashSet<String> = HashSet::new();
+        let order_targets: Vec<(String, Vec<RelationFieldRef>, OrderByTarget)> = order_targets
+            .into_iter()
+            .map(|(head, tail, path, target)| {
+                let name = match tail {
+                    None => head,
+                    Some(tail) => format!("{}{}{}", head, ORDER_BY_SEPARATOR, tail),
+                };
+
+                if !taken_names.insert(name.clone()) {
+                    panic!(
+                        "The orderBy value `{}` of model `{}` is ambiguous: A field of the model has the same name as a related field, relation count or relevance ordering. Rename the field.",
+                        name, model.name
+                    );
+                }
+
+                (name, path, target)
+            })
+            .collect();
+
+        let enum_values: Vec<_> = order_targets
+            .into_iter()
+            .map(|(name, path, target)| {
+                vec![SortOrder::Ascending, SortOrder::Descending]
+                    .into_iter()
+                    .map(|sort_order| {
+                        (
+                            format!("{}_{}", name, sort_order.abbreviated()),
+                            OrderBy {
+                                path: path.clone(),
+                                target: target.clone(),
+                                sort_order,
+                            },
+                        )
+                    })
+                    .collect::<Vec<_>>()
             })
             .flatten()
             .collect();
@@ -195,7 +322,7 @@ impl<'a> ObjectTypeBuilder<'a> {
         let enum_name = format!("{}OrderByInput", model.name);
         let enum_type = order_by_enum_type(enum_name, enum_values);
 
-        argument("orderBy", InputType::opt(enum_type.into()), None)
+        argument("orderBy", InputType::opt(InputType::list(enum_type.into())), None)
     }
 
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
@@ -230,11 +357,202 @@ impl<'a> ObjectTypeBuilder<'a> {
         return_cached!(self.get_cache(), &name);
 
         let object = ObjectTypeStrongRef::new(ObjectType::new(&name, Some(ModelRef::clone(model))));
-        let fields = vec![field("count", vec![], OutputType::int(), None)];
+        let numeric_fields = Self::collect_aggregation_fields(model, false);
+        let comparable_fields = Self::collect_aggregation_fields(model, true);
+
+        let mut fields = vec![field("count", vec![], OutputType::int(), None)];
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("avg", model, &numeric_fields, |_| OutputType::float()),
+        );
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("sum", model, &numeric_fields, Self::map_scalar_output_type),
+        );
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("min", model, &comparable_fields, Self::map_scalar_output_type),
+        );
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("max", model, &comparable_fields, Self::map_scalar_output_type),
+        );
+
+        object.set_fields(fields);
+        self.cache(name, ObjectTypeStrongRef::clone(&object));
+
+        ObjectTypeStrongRef::downgrade(&object)
+    }
+
+    /// Builds the group by output object type for given model (e.g. UserGroupByOutputType).
+    /// Contains the fields the records have been grouped by next to the aggregations of each group,
+    /// which are prefixed with an underscore (e.g. `_count`) as model fields can't start with one.
+    pub fn group_by_output_object_type(&self, model: &ModelRef) -> ObjectTypeRef {
+        let name = format!("{}GroupByOutputType", capitalize(&model.name));
+        return_cached!(self.get_cache(), &name);
+
+        let object = ObjectTypeStrongRef::new(ObjectType::new(&name, Some(ModelRef::clone(model))));
+        let numeric_fields = Self::collect_aggregation_fields(model, false);
+        let comparable_fields = Self::collect_aggregation_fields(model, true);
+
+        // Fields that are not grouped by are not selectable, hence all of them are optional.
+        let mut fields: Vec<Field> = model
+            .fields()
+            .scalar_non_list()
+            .iter()
+            .map(|sf| {
+                field(
+                    sf.name.clone(),
+                    vec![],
+                    OutputType::opt(Self::map_scalar_output_type(sf)),
+                    None,
+                )
+            })
+            .collect();
+
+        fields.push(field("_count", vec![], OutputType::int(), None));
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("_avg", model, &numeric_fields, |_| OutputType::float()),
+        );
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("_sum", model, &numeric_fields, Self::map_scalar_output_type),
+        );
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("_min", model, &comparable_fields, Self::map_scalar_output_type),
+        );
+
+        append_opt(
+            &mut fields,
+            self.aggregation_field("_max", model, &comparable_fields, Self::map_scalar_output_type),
+        );
 
         object.set_fields(fields);
         self.cache(name, ObjectTypeStrongRef::clone(&object));
 
         ObjectTypeStrongRef::downgrade(&object)
     }
+
+    /// Builds the arguments of a group by query. Cursors are not supported on groups.
+    pub fn group_by_arguments(&self, model: &ModelRef) -> Vec<Argument> {
+        let having_object = self
+            .filter_object_type_builder
+            .into_arc()
+            .scalar_filter_with_aggregates_object_type(Arc::clone(model));
+
+        vec![
+            self.by_argument(model),
+            self.where_argument(model),
+            argument("having", InputType::opt(InputType::object(having_object)), None),
+            self.order_by_argument(model),
+            argument("skip", InputType::opt(InputType::int()), None),
+            argument("first", InputType::opt(InputType::int()), None),
+        ]
+    }
+
+    /// Builds the "by" argument listing the fields to group by (e.g. `by: [name, email]`).
+    pub fn by_argument(&self, model: &ModelRef) -> Argument {
+        argument("by", InputType::list(self.scalar_field_enum_type(model).into()), None)
+    }
+
+    /// Builds the "distinct" argument listing the fields records must have distinct values for (e.g. `distinct: [name]`).
+    pub fn distinct_argument(&self, model: &ModelRef) -> Argument {
+        argument(
+            "distinct",
+            InputType::opt(InputType::list(self.scalar_field_enum_type(model).into())),
+            None,
+        )
+    }
+
+    /// Builds the enum of the scalar fields of the model (e.g. `UserScalarFieldEnum`).
+    fn scalar_field_enum_type(&self, model: &ModelRef) -> EnumType {
+        let values = model
+            .fields()
+            .scalar_non_list()
+            .iter()
+            .map(|sf| InternalEnumValue {
+                name: sf.name.clone(),
+                database_name: None,
+            })
+            .collect();
+
+        EnumType::Internal(InternalEnum {
+            name: format!("{}ScalarFieldEnum", capitalize(&model.name)),
+            values,
+        })
+    }
+
+    /// Builds a field based aggregation field (e.g. `avg`) and its object type (e.g. `UserAvgAggregateOutputType`).
+    /// Returns `None` if the model has no fields that can be aggregated that way.
+    fn aggregation_field<F>(
+        &self,
+        name: &str,
+        model: &ModelRef,
+        fields: &[ScalarFieldRef],
+        type_mapping: F,
+    ) -> Option<Field>
+    where
+        F: Fn(&ScalarFieldRef) -> OutputType,
+    {
+        if fields.is_empty() {
+            None
+        } else {
+            let object_type = OutputType::object(self.aggregation_field_object_type(name, model, fields, type_mapping));
+
+            Some(field(name, vec![], OutputType::opt(object_type), None))
+        }
+    }
+
+    fn aggregation_field_object_type<F>(
+        &self,
+        aggregation: &str,
+        model: &ModelRef,
+        fields: &[ScalarFieldRef],
+        type_mapping: F,
+    ) -> ObjectTypeRef
+    where
+        F: Fn(&ScalarFieldRef) -> OutputType,
+    {
+        let name = format!(
+            "{}{}AggregateOutputType",
+            capitalize(&model.name),
+            capitalize(aggregation.trim_start_matches('_'))
+        );
+        return_cached!(self.get_cache(), &name);
+
+        // Aggregating an empty set of records yields null for all field based aggregations.
+        let fields = fields
+            .iter()
+            .map(|sf| field(sf.name.clone(), vec![], OutputType::opt(type_mapping(sf)), None))
+            .collect();
+
+        let object = Arc::new(object_type(name.clone(), fields, None));
+        self.cache(name, Arc::clone(&object));
+
+        Arc::downgrade(&object)
+    }
+
+    /// Collects the non-list scalar fields of the model that can be aggregated.
+    /// Numeric fields can be aggregated in any way, date times only compared (min / max).
+    pub fn collect_aggregation_fields(model: &ModelRef, include_comparable: bool) -> Vec<ScalarFieldRef> {
+        model
+            .fields()
+            .scalar_non_list()
+            .into_iter()
+            .filter(|sf| match sf.type_identifier {
+                TypeIdentifier::Int | TypeIdentifier::Float => true,
+                TypeIdentifier::DateTime => include_comparable,
+                _ => false,
+            })
+            .collect()
+    }
 }
@@ -140,6 +140,7 @@ impl<'a> QuerySchemaBuilder<'a> {
                 let mut vec = vec![
                     self.all_items_field(Arc::clone(&m)),
                     self.aggregation_field(Arc::clone(&m)),
+                    self.group_by_field(Arc::clone(&m)),
                 ];
 
                 append_opt(&mut vec, self.single_item_field(Arc::clone(&m)));
@@ -161,6 +162,7 @@ impl<'a> QuerySchemaBuilder<'a> {
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
+                append_opt(&mut vec, self.create_many_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.delete_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.update_item_field(Arc::clone(&model)));
                 append_opt(&mut vec, self.upsert_item_field(Arc::clone(&model)));
@@ -175,6 +177,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
         if self.enable_raw_queries {
             fields.push(self.create_execute_raw_field());
+            fields.push(self.create_query_raw_field());
         }
 
         let strong_ref = Arc::new(object_type("Mutation", fields, None));
@@ -225,7 +228,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds a "multiple" query arity items field (e.g. "users", "posts", ...) for given model.
     fn all_items_field(&self, model: ModelRef) -> Field {
-        let args = self.object_type_builder.many_records_arguments(&model);
+        let args = self.object_type_builder.find_many_arguments(&model);
         let field_name = self.pluralize_internal(
             camel_case(pluralize(model.name.clone())),
             format!("findMany{}", model.name.clone()),
@@ -253,6 +256,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
     /// Builds an "aggregate" query field (e.g. "aggregateUser") for given model.
     fn aggregation_field(&self, model: ModelRef) -> Field {
+        let args = self.object_type_builder.many_records_arguments(&model);
         let field_name = self.pluralize_internal(
             format!("aggregate{}", model.name.clone()), // Has no legacy counterpart.
             format!("aggregate{}", model.name.clone()),
@@ -260,7 +264,7 @@ impl<'a> QuerySchemaBuilder<'a> {
 
         field(
             field_name,
-            vec![],
+            args,
             OutputType::object(self.object_type_builder.aggregation_object_type(&model)),
             Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
                 Arc::clone(&model),
@@ -276,22 +280,55 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
-    fn create_execute_raw_field(&self) -> Field {
+    /// Builds a "group by" query field (e.g. "groupByUser") for given model.
+    fn group_by_field(&self, model: ModelRef) -> Field {
+        let args = self.object_type_builder.group_by_arguments(&model);
+        let field_name = self.pluralize_internal(
+            format!("groupBy{}", model.name.clone()), // Has no legacy counterpart.
+            format!("groupBy{}", model.name.clone()),
+        );
+
         field(
-            "executeRaw",
-            vec![
-                argument("query", InputType::string(), None),
-                argument(
-                    "parameters",
-                    InputType::opt(InputType::json_list()),
-                    Some(dml::DefaultValue::Single(dml::ScalarValue::String("[]".into()))),
-                ),
-            ],
-            OutputType::json(),
-            None,
+            field_name,
+            args,
+            OutputType::list(OutputType::object(
+                self.object_type_builder.group_by_output_object_type(&model),
+            )),
+            Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+                Arc::clone(&model),
+                QueryTag::GroupBy,
+                Box::new(|model, parsed_field| {
+                    let mut graph = QueryGraph::new();
+                    let query = GroupByRecordsBuilder::new(parsed_field, model).build()?;
+
+                    graph.create_node(Query::Read(query));
+                    Ok(graph)
+                }),
+            ))),
         )
     }
 
+    /// Builds the "executeRaw" field, which executes a raw statement and returns the number of affected rows.
+    fn create_execute_raw_field(&self) -> Field {
+        field("executeRaw", Self::raw_arguments(), OutputType::int(), None)
+    }
+
+    /// Builds the "queryRaw" field, which executes a raw query and returns the resulting rows.
+    fn create_query_raw_field(&self) -> Field {
+        field("queryRaw", Self::raw_arguments(), OutputType::json(), None)
+    }
+
+    fn raw_arguments() -> Vec<Argument> {
+        vec![
+            argument("query", InputType::string(), None),
+            argument(
+                "parameters",
+                InputType::opt(InputType::json_list()),
+                Some(dml::DefaultValue::Single(dml::ScalarValue::String("[]".into()))),
+            ),
+        ]
+    }
+
     /// Builds a create mutation field (e.g. createUser) for given model.
     fn create_item_field(&self, model: ModelRef) -> Field {
         let args = self
@@ -321,6 +358,34 @@ impl<'a> QuerySchemaBuilder<'a> {
         )
     }
 
+    /// Builds a create many mutation field (e.g. createManyUsers) for given model.
+    fn create_many_field(&self, model: ModelRef) -> Option<Field> {
+        self.argument_builder
+            .create_many_arguments(Arc::clone(&model))
+            .map(|args| {
+                let field_name = self.pluralize_internal(
+                    format!("createMany{}", pluralize(model.name.clone())),
+                    format!("createMany{}", model.name.clone()),
+                );
+
+                field(
+                    field_name,
+                    args,
+                    OutputType::object(self.object_type_builder.batch_payload_object_type()),
+                    Some(SchemaQueryBuilder::ModelQueryBuilder(ModelQueryBuilder::new(
+                        Arc::clone(&model),
+                        QueryTag::CreateMany,
+                        Box::new(|model, parsed_field| {
+                            let mut graph = QueryGraph::new();
+
+                            write::create_many_records(&mut graph, model, parsed_field)?;
+                            Ok(graph)
+                        }),
+                    ))),
+                )
+            })
+    }
+
     /// Builds a delete mutation field (e.g. deleteUser) for given model.
     fn delete_item_field(&self, model: ModelRef) -> Option<Field> {
         self.argument_builder.delete_arguments(Arc::clone(&model)).map(|args| {
@@ -5,6 +5,9 @@ use once_cell::sync::OnceCell;
 use prisma_models::{dml, ModelRef, OrderBy};
 use std::sync::Arc;
 
+/// Separates the parts of composed orderBy values, e.g. `author__name` or `comments__count`.
+pub const ORDER_BY_SEPARATOR: &str = "__";
+
 /// Object type convenience wrapper function.
 pub fn object_type<T>(name: T, fields: Vec<Field>, model: Option<ModelRef>) -> ObjectType
 where
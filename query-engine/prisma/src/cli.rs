@@ -10,7 +10,7 @@ use crate::error::PrismaError;
 use crate::request_handlers::{graphql::*, PrismaRequest, RequestHandler};
 use crate::{
     data_model_loader::{load_configuration, load_data_model_components},
-    dmmf, PrismaResult,
+    dmmf, exec_loader, PrismaResult,
 };
 use crate::{CliOpt, PrismaOpt, Subcommand};
 
@@ -21,6 +21,13 @@ pub struct ExecuteRequest {
     legacy: bool,
 }
 
+pub struct ExplainRequest {
+    query: String,
+    explain: bool,
+    enable_raw_queries: bool,
+    legacy: bool,
+}
+
 pub struct DmmfRequest {
     build_mode: BuildMode,
     enable_raw_queries: bool,
@@ -30,6 +37,7 @@ pub enum CliCommand {
     Dmmf(DmmfRequest),
     GetConfig(String),
     ExecuteRequest(ExecuteRequest),
+    Explain(ExplainRequest),
 }
 
 impl TryFrom<&PrismaOpt> for CliCommand {
@@ -64,6 +72,12 @@ impl TryFrom<&PrismaOpt> for CliCommand {
                     enable_raw_queries: opts.enable_raw_queries,
                     legacy: input.legacy,
                 })),
+                CliOpt::Explain(input) => Ok(CliCommand::Explain(ExplainRequest {
+                    query: input.query.clone(),
+                    explain: input.explain,
+                    enable_raw_queries: opts.enable_raw_queries,
+                    legacy: input.legacy,
+                })),
             },
         }
     }
@@ -75,6 +89,7 @@ impl CliCommand {
             CliCommand::Dmmf(request) => Self::dmmf(request),
             CliCommand::GetConfig(input) => Self::get_config(input),
             CliCommand::ExecuteRequest(request) => Self::execute_request(request).await,
+            CliCommand::Explain(request) => Self::explain(request).await,
         }
     }
 
@@ -83,7 +98,10 @@ impl CliCommand {
 
         // temporary code duplication
         let internal_data_model = template.build("".into());
-        let capabilities = SupportedCapabilities::empty();
+        let capabilities = match v2components.data_sources.first() {
+            Some(data_source) => exec_loader::capabilities(&**data_source),
+            None => SupportedCapabilities::empty(),
+        };
 
         let schema_builder = QuerySchemaBuilder::new(
             &internal_data_model,
@@ -137,4 +155,22 @@ impl CliCommand {
 
         Ok(())
     }
+
+    async fn explain(request: ExplainRequest) -> PrismaResult<()> {
+        let ctx = PrismaContext::builder()
+            .legacy(request.legacy)
+            .enable_raw_queries(request.enable_raw_queries)
+            .build()
+            .await?;
+
+        let body = GraphQlBody::Single(request.query.into());
+        let explanation = explain_graphql_query(body, &ctx, request.explain).await?;
+
+        // The graph is printed as is, it is unreadable with escaped line breaks.
+        println!("{}\n", explanation.query_graph);
+        println!("{}", serde_json::to_string_pretty(&explanation.statements)?);
+        println!("{}", serde_json::to_string_pretty(&explanation.response)?);
+
+        Ok(())
+    }
 }
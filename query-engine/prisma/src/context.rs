@@ -1,8 +1,5 @@
-use crate::{data_model_loader::*, exec_loader, PrismaError, PrismaResult};
-use query_core::{
-    schema::{QuerySchemaRef, SupportedCapabilities},
-    BuildMode, QueryExecutor, QuerySchemaBuilder,
-};
+use crate::{data_model_loader::*, exec_loader, transactions::TransactionRegistry, PrismaError, PrismaResult};
+use query_core::{schema::QuerySchemaRef, BuildMode, QueryExecutor, QuerySchemaBuilder};
 // use prisma_models::InternalDataModelRef;
 use prisma_models::DatamodelConverter;
 use std::sync::Arc;
@@ -20,6 +17,9 @@ pub struct PrismaContext {
 
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+
+    /// Open interactive transactions.
+    transactions: TransactionRegistry,
 }
 
 pub struct ContextBuilder {
@@ -108,7 +108,7 @@ impl PrismaContext {
 
         // Construct query schema
         let build_mode = if legacy { BuildMode::Legacy } else { BuildMode::Modern };
-        let capabilities = SupportedCapabilities::empty(); // todo connector capabilities.
+        let capabilities = exec_loader::capabilities(&**data_source);
 
         let schema_builder =
             QuerySchemaBuilder::new(&internal_data_model, &capabilities, build_mode, enable_raw_queries);
@@ -120,6 +120,7 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            transactions: TransactionRegistry::for_source(&**data_source),
         })
     }
 
@@ -140,6 +141,10 @@ impl PrismaContext {
         &self.dm
     }
 
+    pub fn transactions(&self) -> &TransactionRegistry {
+        &self.transactions
+    }
+
     pub fn primary_connector(&self) -> &'static str {
         self.executor.primary_connector()
     }
@@ -52,6 +52,18 @@ impl<'a> DMMFTypeRenderer<'a> {
 
                 type_info
             }
+            InputType::Union(ref types) => {
+                // The type info is taken from the first type, the other types are only rendered into the schema.
+                let (first, rest) = types
+                    .split_first()
+                    .expect("Expected input unions to contain at least one type.");
+
+                for typ in rest {
+                    self.render_input_type(typ, ctx);
+                }
+
+                self.render_input_type(first, ctx)
+            }
             InputType::Scalar(ScalarType::Enum(et)) => {
                 et.into_renderer().render(ctx);
                 let type_info = DMMFTypeInfo {
@@ -72,6 +84,8 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
@@ -145,6 +159,8 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
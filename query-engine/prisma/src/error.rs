@@ -40,6 +40,9 @@ pub enum PrismaError {
 
     #[fail(display = "{}", _0)]
     QueryConversionError(String),
+
+    #[fail(display = "Transaction error: {}", _0)]
+    TransactionError(String),
 }
 
 impl PrismaError {
@@ -4,7 +4,10 @@ use datamodel::{
     configuration::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Source,
 };
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::{
+    executor::{InterpretingExecutor, QueryExecutor},
+    schema::{ConnectorCapability, ScalarListsCapability, SupportedCapabilities},
+};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
@@ -32,6 +35,24 @@ pub async fn load(
     }
 }
 
+/// The capabilities of the connector of the data source, deciding which parts of the query schema are available.
+pub fn capabilities(source: &(dyn Source + Send + Sync)) -> SupportedCapabilities {
+    let capabilities = match source.connector_type() {
+        POSTGRES_SOURCE_NAME => vec![
+            ConnectorCapability::ScalarLists(ScalarListsCapability::EmbeddedScalarLists),
+            ConnectorCapability::InsensitiveFilters,
+            ConnectorCapability::FullTextSearch,
+        ],
+        MYSQL_SOURCE_NAME | SQLITE_SOURCE_NAME => vec![
+            ConnectorCapability::InsensitiveFilters,
+            ConnectorCapability::FullTextIndex,
+        ],
+        _ => vec![],
+    };
+
+    SupportedCapabilities { capabilities }
+}
+
 #[cfg(feature = "sql")]
 async fn sqlite(
     source: &(dyn Source + Send + Sync),
@@ -26,6 +26,7 @@ mod request_handlers;
 mod server;
 #[cfg(test)]
 mod tests;
+mod transactions;
 mod utilities;
 
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -69,6 +70,18 @@ pub struct ExecuteRequestInput {
     pub legacy: bool,
 }
 
+#[derive(Debug, Clone, StructOpt)]
+pub struct ExplainInput {
+    /// GraphQL query to dry-run
+    pub query: String,
+    /// Include the query plans of the SQL statements
+    #[structopt(long)]
+    pub explain: bool,
+    /// Run in the legacy GraphQL mode
+    #[structopt(long)]
+    pub legacy: bool,
+}
+
 #[derive(Debug, StructOpt, Clone)]
 pub enum CliOpt {
     /// Output the DMMF from the loaded data model.
@@ -77,6 +90,8 @@ pub enum CliOpt {
     GetConfig(GetConfigInput),
     /// Executes one request and then terminates.
     ExecuteRequest(ExecuteRequestInput),
+    /// Executes one request in a transaction that is rolled back, printing the query graph and SQL statements.
+    Explain(ExplainInput),
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -94,7 +109,7 @@ pub struct PrismaOpt {
     /// Runs all queries in a transaction, including all the reads.
     #[structopt(long)]
     always_force_transactions: bool,
-    /// Enables raw SQL queries with executeRaw mutation
+    /// Enables raw SQL queries with the executeRaw and queryRaw mutations
     #[structopt(long)]
     enable_raw_queries: bool,
     #[structopt(subcommand)]
@@ -1,11 +1,12 @@
 use super::protocol_adapter::GraphQLProtocolAdapter;
 use crate::{context::PrismaContext, PrismaError, PrismaRequest, PrismaResponse, PrismaResult, RequestHandler};
 use async_trait::async_trait;
-use futures::{future, FutureExt};
+use futures::{channel::mpsc, future, FutureExt};
 use graphql_parser as gql;
 use indexmap::IndexMap;
 use query_core::{
-    response_ir, BatchDocument, CompactedDocument, CoreError, Item, Operation, QueryDocument, QueryValue, Responses,
+    response_ir, BatchDocument, CompactedDocument, CoreError, Explanation, Item, Operation, QueryDocument, QueryValue,
+    Responses,
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, convert::TryFrom, panic::AssertUnwindSafe, sync::Arc};
@@ -15,13 +16,17 @@ use std::{collections::HashMap, convert::TryFrom, panic::AssertUnwindSafe, sync:
 pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    #[serde(default)]
+    variables: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MultiQuery {
     batch: Vec<SingleQuery>,
+    /// Runs all operations of the batch in one transaction, rolling all of them back if one fails.
+    #[serde(default)]
+    transaction: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -54,7 +59,7 @@ impl TryFrom<GraphQlBody> for QueryDocument {
         match body {
             GraphQlBody::Single(body) => {
                 let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
+                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables)?;
 
                 Ok(QueryDocument::Single(operation))
             }
@@ -64,7 +69,7 @@ impl TryFrom<GraphQlBody> for QueryDocument {
                     .into_iter()
                     .map(|body| {
                         let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
+                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables)
                     })
                     .collect();
 
@@ -74,6 +79,9 @@ impl TryFrom<GraphQlBody> for QueryDocument {
     }
 }
 
+/// Header carrying the id of the interactive transaction a request runs in.
+pub const TRANSACTION_ID_HEADER: &str = "x-transaction-id";
+
 pub struct GraphQlRequestHandler;
 
 #[allow(unused_variables)]
@@ -88,8 +96,20 @@ impl RequestHandler for GraphQlRequestHandler {
         let request = req.into();
         debug!("Incoming GraphQL query: {:?}", request.body);
 
+        let tx_id = request.headers.get(TRANSACTION_ID_HEADER).cloned();
+        let transactional = match &request.body {
+            GraphQlBody::Multi(batch) => batch.transaction,
+            GraphQlBody::Single(_) => false,
+        };
+
         match QueryDocument::try_from(request.body) {
-            Ok(QueryDocument::Single(query)) => handle_single_query(query, ctx.clone()).await,
+            Ok(QueryDocument::Single(query)) => handle_single_query(query, ctx.clone(), tx_id).await,
+            Ok(QueryDocument::Multi(BatchDocument::Multi(batch))) if tx_id.is_some() => {
+                handle_batch_in_transaction(batch, ctx, tx_id).await
+            }
+            Ok(QueryDocument::Multi(BatchDocument::Multi(batch))) if transactional => {
+                handle_transactional_batch(batch, ctx).await
+            }
             Ok(QueryDocument::Multi(batch)) => match batch.compact() {
                 BatchDocument::Multi(batch) => handle_batch(batch, ctx).await,
                 BatchDocument::Compact(compacted) => handle_compacted(compacted, ctx).await,
@@ -104,10 +124,10 @@ impl RequestHandler for GraphQlRequestHandler {
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>, tx_id: Option<String>) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let responses = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let responses = match AssertUnwindSafe(handle_graphql_query(query, &*ctx, tx_id.as_deref()))
         .catch_unwind()
         .await
     {
@@ -134,7 +154,7 @@ async fn handle_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> Pris
     let mut futures = Vec::with_capacity(queries.len());
 
     for operation in queries.into_iter() {
-        futures.push(tokio::spawn(handle_single_query(operation, ctx.clone())));
+        futures.push(tokio::spawn(handle_single_query(operation, ctx.clone(), None)));
     }
 
     let responses = future::join_all(futures)
@@ -146,6 +166,48 @@ async fn handle_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> Pris
     PrismaResponse::Multi(responses)
 }
 
+/// Operations of one transaction run one after the other, in the order of the batch.
+async fn handle_batch_in_transaction(
+    queries: Vec<Operation>,
+    ctx: &Arc<PrismaContext>,
+    tx_id: Option<String>,
+) -> PrismaResponse {
+    let mut responses = Vec::with_capacity(queries.len());
+
+    for operation in queries.into_iter() {
+        responses.push(handle_single_query(operation, ctx.clone(), tx_id.clone()).await);
+    }
+
+    PrismaResponse::Multi(responses)
+}
+
+/// All operations run in one transaction. If one of them fails, they are all rolled back and the response is the
+/// single error of the failed operation.
+async fn handle_transactional_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+    use user_facing_errors::Error;
+
+    let result = AssertUnwindSafe(ctx.executor.execute_batch(queries, Arc::clone(ctx.query_schema())))
+        .catch_unwind()
+        .await;
+
+    match result {
+        Ok(Ok(responses)) => PrismaResponse::Multi(responses.into_iter().map(PrismaResponse::Single).collect()),
+        Ok(Err(err)) => {
+            let mut responses = response_ir::Responses::default();
+            responses.insert_error(err);
+            PrismaResponse::Single(responses)
+        }
+        // panicked
+        Err(err) => {
+            let mut responses = response_ir::Responses::default();
+            let error = Error::from_panic_payload(&err);
+
+            responses.insert_error(error);
+            PrismaResponse::Single(responses)
+        }
+    }
+}
+
 async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
     use user_facing_errors::Error;
 
@@ -155,7 +217,7 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx, None))
         .catch_unwind()
         .await
     {
@@ -217,7 +279,58 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<response_ir::Responses> {
+/// Streams the records of a single `findMany` query into the sink, one serialized record at a time.
+/// The records are read from the database in chunks of `chunk_size` records.
+pub async fn stream_graphql_query(
+    body: GraphQlBody,
+    ctx: &PrismaContext,
+    chunk_size: usize,
+    sink: mpsc::Sender<Item>,
+) -> PrismaResult<()> {
+    let operation = match QueryDocument::try_from(body)? {
+        QueryDocument::Single(operation) => operation,
+        QueryDocument::Multi(_) => {
+            return Err(PrismaError::UnsupportedFeatureError(
+                "Batched streaming",
+                "Only single queries can be streamed.".to_owned(),
+            ))
+        }
+    };
+
+    ctx.executor
+        .stream(operation, Arc::clone(ctx.query_schema()), chunk_size, sink)
+        .await?;
+
+    Ok(())
+}
+
+/// Dry-runs a single query, see `QueryExecutor::explain`.
+pub async fn explain_graphql_query(body: GraphQlBody, ctx: &PrismaContext, explain: bool) -> PrismaResult<Explanation> {
+    let operation = match QueryDocument::try_from(body)? {
+        QueryDocument::Single(operation) => operation,
+        QueryDocument::Multi(_) => {
+            return Err(PrismaError::UnsupportedFeatureError(
+                "Batched dry runs",
+                "Only single queries can be explained.".to_owned(),
+            ))
+        }
+    };
+
+    Ok(ctx
+        .executor
+        .explain(operation, Arc::clone(ctx.query_schema()), explain)
+        .await?)
+}
+
+async fn handle_graphql_query(
+    query_doc: Operation,
+    ctx: &PrismaContext,
+    tx_id: Option<&str>,
+) -> PrismaResult<response_ir::Responses> {
+    if let Some(tx_id) = tx_id {
+        return ctx.transactions().execute(tx_id, query_doc).await;
+    }
+
     ctx.executor
         .execute(query_doc, Arc::clone(ctx.query_schema()))
         .await
@@ -1,10 +1,12 @@
 use crate::{error::PrismaError, PrismaResult};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, Field, FragmentDefinition, OperationDefinition, Selection as GqlSelection, SelectionSet,
+    Type, Value, VariableDefinition,
 };
+use indexmap::IndexMap;
 use query_core::query_document::*;
 use rust_decimal::{prelude::FromPrimitive, Decimal};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -14,30 +16,54 @@ use std::collections::BTreeMap;
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are replaced by the JSON values given for them, which are checked against the declared variable
+///   type. The values are validated against the query schema like any other argument value afterwards.
+///   Arguments referencing variables that are neither given nor have a default value are omitted.
+/// - Fragment spreads and inline fragments are expanded into the selection set they are used in, fields with the
+///   same response key are merged. Type conditions are not checked, as the query schema has no abstract types.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
+/// - Directives, which are ignored.
 /// - Subscription queries.
 /// - Query names are ignored
-pub struct GraphQLProtocolAdapter;
+pub struct GraphQLProtocolAdapter<'a> {
+    fragments: &'a HashMap<String, FragmentDefinition>,
+    declared_variables: HashSet<String>,
+    variables: HashMap<String, QueryValue>,
+}
+
+impl<'a> GraphQLProtocolAdapter<'a> {
+    pub fn convert(
+        gql_doc: Document,
+        operation: Option<String>,
+        variables: HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Operation> {
+        let mut fragments = HashMap::new();
+        let mut definitions = Vec::with_capacity(gql_doc.definitions.len());
+
+        for def in gql_doc.definitions {
+            match def {
+                Definition::Fragment(f) => {
+                    fragments.insert(f.name.clone(), f);
+                }
+                def => definitions.push(def),
+            }
+        }
+
+        let convert = |def| GraphQLProtocolAdapter::convert_definition(def, &fragments, &variables);
 
-impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document, operation: Option<String>) -> PrismaResult<Operation> {
         let mut operations: Vec<Operation> = match operation {
-            Some(ref op) => gql_doc
-                .definitions
+            Some(ref op) => definitions
                 .into_iter()
                 .find(|def| Self::matches_operation(def, op))
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
                 })
-                .and_then(Self::convert_definition),
+                .and_then(convert),
 
-            None => gql_doc
-                .definitions
+            None => definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(convert)
                 .collect::<PrismaResult<Vec<Vec<Operation>>>>()
                 .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
         }?;
@@ -50,74 +76,130 @@ impl GraphQLProtocolAdapter {
         Ok(operation)
     }
 
-    fn convert_definition(def: Definition) -> PrismaResult<Vec<Operation>> {
-        match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
-            )),
+    fn convert_definition(
+        def: Definition,
+        fragments: &'a HashMap<String, FragmentDefinition>,
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Vec<Operation>> {
+        let (variable_definitions, selection_set, is_mutation) = match def {
+            Definition::Fragment(_) => return Ok(vec![]),
             Definition::Operation(op) => match op {
-                OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
-                    "Subscription query",
-                    format!("At position {}.", s.position),
-                )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::Subscription(s) => {
+                    return Err(PrismaError::UnsupportedFeatureError(
+                        "Subscription query",
+                        format!("At position {}.", s.position),
+                    ))
+                }
+                OperationDefinition::SelectionSet(s) => (vec![], s, false),
+                OperationDefinition::Query(q) => (q.variable_definitions, q.selection_set, false),
+                OperationDefinition::Mutation(m) => (m.variable_definitions, m.selection_set, true),
             },
-        }
-    }
+        };
 
-    fn convert_query(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set)
-            .map(|fields| fields.into_iter().map(|field| Operation::Read(field)).collect())
-    }
+        let adapter = Self {
+            fragments,
+            declared_variables: variable_definitions.iter().map(|def| def.name.clone()).collect(),
+            variables: Self::convert_variables(variable_definitions, variables)?,
+        };
 
-    fn convert_mutation(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| {
-            fields
-                .into_iter()
-                .map(|selection| Operation::Write(selection))
-                .collect()
-        })
+        let selections = adapter.convert_selection_set(selection_set)?.into_iter();
+
+        if is_mutation {
+            Ok(selections.map(Operation::Write).collect())
+        } else {
+            Ok(selections.map(Operation::Read).collect())
+        }
     }
 
-    fn convert_selection_set(selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
+    fn convert_selection_set(&self, selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
+        let mut fields = Vec::new();
+        self.collect_fields(selection_set, &mut vec![], &mut fields)?;
+
+        Self::merge_fields(fields)?
             .into_iter()
-            .map(|item| match item {
-                GqlSelection::Field(f) => {
-                    let arguments: Vec<(String, QueryValue)> = f
-                        .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
-                        .collect::<PrismaResult<Vec<_>>>()?;
-
-                    let mut builder = Selection::builder(f.name);
-                    builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
-
-                    if let Some(alias) = f.alias {
-                        builder.alias(alias);
-                    };
-
-                    Ok(builder.build())
+            .map(|f| {
+                let mut arguments = Vec::with_capacity(f.arguments.len());
+
+                for (k, v) in f.arguments {
+                    if let Some(value) = self.convert_argument(v)? {
+                        arguments.push((k, value));
+                    }
                 }
 
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+                let mut builder = Selection::builder(f.name);
+                builder.set_arguments(arguments);
+                builder.nested_selections(self.convert_selection_set(f.selection_set)?);
 
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
+                if let Some(alias) = f.alias {
+                    builder.alias(alias);
+                };
+
+                Ok(builder.build())
             })
             .collect()
     }
 
+    /// Collects the fields of the selection set, with all fragments expanded in place.
+    /// `spreads` are the names of the fragments currently being expanded, to detect cycles.
+    fn collect_fields(
+        &self,
+        selection_set: SelectionSet,
+        spreads: &mut Vec<String>,
+        fields: &mut Vec<Field>,
+    ) -> PrismaResult<()> {
+        for item in selection_set.items {
+            match item {
+                GqlSelection::Field(f) => fields.push(f),
+                GqlSelection::InlineFragment(i) => self.collect_fields(i.selection_set, spreads, fields)?,
+                GqlSelection::FragmentSpread(fs) => {
+                    if spreads.contains(&fs.fragment_name) {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Fragment '{}' spreads itself, at position {}.",
+                            fs.fragment_name, fs.position
+                        )));
+                    }
+
+                    let fragment = self.fragments.get(&fs.fragment_name).ok_or_else(|| {
+                        PrismaError::QueryConversionError(format!(
+                            "Unknown fragment '{}', at position {}.",
+                            fs.fragment_name, fs.position
+                        ))
+                    })?;
+
+                    spreads.push(fs.fragment_name);
+                    self.collect_fields(fragment.selection_set.clone(), spreads, fields)?;
+                    spreads.pop();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Merges fields with the same response key (alias or name) into one field selecting all of their sub fields.
+    fn merge_fields(fields: Vec<Field>) -> PrismaResult<Vec<Field>> {
+        let mut merged: IndexMap<String, Field> = IndexMap::new();
+
+        for field in fields {
+            let key = field.alias.clone().unwrap_or_else(|| field.name.clone());
+
+            match merged.get_mut(&key) {
+                Some(existing) if existing.name != field.name || existing.arguments != field.arguments => {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Fields '{}' conflict because they select different fields or arguments, at position {}.",
+                        key, field.position
+                    )));
+                }
+                Some(existing) => existing.selection_set.items.extend(field.selection_set.items),
+                None => {
+                    merged.insert(key, field);
+                }
+            }
+        }
+
+        Ok(merged.into_iter().map(|(_, field)| field).collect())
+    }
+
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
     fn matches_operation(def: &Definition, operation: &str) -> bool {
         let check = |n: Option<&String>| n.filter(|name| name.as_str() == operation).is_some();
@@ -132,12 +214,141 @@ impl GraphQLProtocolAdapter {
         }
     }
 
+    /// Converts a value, `None` if it is a variable without a value.
+    fn convert_argument(&self, value: Value) -> PrismaResult<Option<QueryValue>> {
+        match value {
+            Value::Variable(name) if !self.declared_variables.contains(&name) => Err(
+                PrismaError::QueryConversionError(format!("Variable '${}' is not defined.", name)),
+            ),
+            Value::Variable(name) => Ok(self.variables.get(&name).cloned()),
+            Value::List(values) => {
+                let values = values
+                    .into_iter()
+                    .map(|v| Ok(self.convert_argument(v)?.unwrap_or(QueryValue::Null)))
+                    .collect::<PrismaResult<Vec<QueryValue>>>()?;
+
+                Ok(Some(QueryValue::List(values)))
+            }
+            Value::Object(map) => {
+                let mut values = BTreeMap::new();
+
+                for (k, v) in map {
+                    if let Some(value) = self.convert_argument(v)? {
+                        values.insert(k, value);
+                    }
+                }
+
+                Ok(Some(QueryValue::Object(values)))
+            }
+            value => Self::convert_value(value).map(Some),
+        }
+    }
+
+    /// Resolves the values of the declared variables, either from the given JSON values or their default values.
+    /// Variables without any value are left out.
+    fn convert_variables(
+        definitions: Vec<VariableDefinition>,
+        values: &HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<HashMap<String, QueryValue>> {
+        let mut variables = HashMap::with_capacity(definitions.len());
+
+        for def in definitions {
+            let value = match (values.get(&def.name), def.default_value) {
+                (Some(value), _) => Self::convert_variable(&def.name, value.clone(), &def.var_type)?,
+                (None, Some(default)) => Self::convert_value(default)?,
+                (None, None) => match def.var_type {
+                    Type::NonNullType(_) => {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Variable '${}' of required type '{}' was not provided.",
+                            def.name, def.var_type
+                        )))
+                    }
+                    _ => continue,
+                },
+            };
+
+            variables.insert(def.name, value);
+        }
+
+        Ok(variables)
+    }
+
+    /// Converts the JSON value of a variable, checking it against the declared type. Only the shape and built-in
+    /// scalars are checked here, values of enums, input objects and custom scalars are left to the query schema.
+    fn convert_variable(name: &str, value: serde_json::Value, typ: &Type) -> PrismaResult<QueryValue> {
+        use serde_json::Value as Json;
+
+        let invalid = |value: &Json| {
+            PrismaError::QueryConversionError(format!(
+                "Variable '${}' of type '{}' got an invalid value: {}.",
+                name, typ, value
+            ))
+        };
+
+        match (typ, value) {
+            (Type::NonNullType(_), Json::Null) => Err(invalid(&Json::Null)),
+            (Type::NonNullType(inner), value) => Self::convert_variable(name, value, inner),
+            (_, Json::Null) => Ok(QueryValue::Null),
+
+            (Type::ListType(inner), Json::Array(values)) => values
+                .into_iter()
+                .map(|value| Self::convert_variable(name, value, inner))
+                .collect::<PrismaResult<Vec<_>>>()
+                .map(QueryValue::List),
+
+            // A single value is coerced into a list of one value.
+            (Type::ListType(inner), value) => Ok(QueryValue::List(vec![Self::convert_variable(name, value, inner)?])),
+
+            (Type::NamedType(type_name), value) => match (type_name.as_str(), value) {
+                ("Int", Json::Number(n)) if n.is_i64() => Ok(QueryValue::Int(n.as_i64().unwrap())),
+                ("Float", Json::Number(n)) => Self::convert_json(Json::Number(n)),
+                ("String", Json::String(s)) | ("ID", Json::String(s)) => Ok(QueryValue::String(s)),
+                ("Boolean", Json::Bool(b)) => Ok(QueryValue::Boolean(b)),
+                ("Int", value) | ("Float", value) | ("String", value) | ("ID", value) | ("Boolean", value) => {
+                    Err(invalid(&value))
+                }
+                (_, value) => Self::convert_json(value),
+            },
+        }
+    }
+
+    fn convert_json(value: serde_json::Value) -> PrismaResult<QueryValue> {
+        use serde_json::Value as Json;
+
+        match value {
+            Json::Null => Ok(QueryValue::Null),
+            Json::Bool(b) => Ok(QueryValue::Boolean(b)),
+            Json::Number(n) => match n.as_i64() {
+                Some(i) => Ok(QueryValue::Int(i)),
+                None => match n.as_f64().and_then(Decimal::from_f64) {
+                    Some(dec) => Ok(QueryValue::Float(dec)),
+                    None => Err(PrismaError::QueryConversionError(format!(
+                        "invalid 64-bit float: {:?}",
+                        n
+                    ))),
+                },
+            },
+            Json::String(s) => Ok(QueryValue::String(s)),
+            Json::Array(values) => values
+                .into_iter()
+                .map(Self::convert_json)
+                .collect::<PrismaResult<Vec<_>>>()
+                .map(QueryValue::List),
+            Json::Object(map) => map
+                .into_iter()
+                .map(|(k, v)| Self::convert_json(v).map(|v| (k, v)))
+                .collect::<PrismaResult<BTreeMap<_, _>>>()
+                .map(QueryValue::Object),
+        }
+    }
+
+    /// Converts a constant value, i.e. one that can't contain variables.
     fn convert_value(value: Value) -> PrismaResult<QueryValue> {
         match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => Err(PrismaError::QueryConversionError(format!(
+                "Variable '${}' can't be used in a constant value.",
+                name
+            ))),
             Value::Int(i) => match i.as_i64() {
                 Some(i) => Ok(QueryValue::Int(i)),
                 None => Err(PrismaError::QueryConversionError(format!(
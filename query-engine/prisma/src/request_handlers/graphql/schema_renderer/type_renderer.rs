@@ -41,6 +41,19 @@ impl<'a> GqlTypeRenderer<'a> {
                 let (substring, subctx) = self.render_input_type(opt, ctx);
                 (substring.trim_end_matches('!').to_owned(), subctx)
             }
+            InputType::Union(ref types) => {
+                // GraphQL has no input unions, the field is rendered with the first type only.
+                let (first, rest) = types
+                    .split_first()
+                    .expect("Expected input unions to contain at least one type.");
+
+                let (string, ctx) = self.render_input_type(first, ctx);
+
+                rest.iter().fold((string, ctx), |(string, ctx), typ| {
+                    let (_, subctx) = self.render_input_type(typ, ctx);
+                    (string, subctx)
+                })
+            }
             InputType::Scalar(ScalarType::Enum(et)) => {
                 let (_, subctx) = et.into_renderer().render(ctx);
                 (format!("{}!", et.name()), subctx)
@@ -52,8 +65,10 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Boolean => "Boolean",
                     ScalarType::Float => "Float",
                     ScalarType::DateTime => "DateTime",
-                    ScalarType::Json => "DateTime",
+                    ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
@@ -95,6 +110,8 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::DateTime => "DateTime",
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
+                    ScalarType::Bytes => "Bytes",
+                    ScalarType::BigInt => "BigInt",
                     ScalarType::JsonList => "Json",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
@@ -2,18 +2,30 @@ use super::dmmf;
 use crate::{
     context::PrismaContext,
     request_handlers::{
-        graphql::{GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
+        graphql::{
+            explain_graphql_query, stream_graphql_query, GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler,
+            TRANSACTION_ID_HEADER,
+        },
         PrismaRequest, RequestHandler,
     },
-    PrismaResult,
+    transactions::TransactionRegistry,
+    PrismaError, PrismaResult,
 };
-use hyper::header;
+use futures::{channel::mpsc, SinkExt, StreamExt};
+use hyper::header::{self, HeaderMap};
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{Body, Error, Method, Request, Response, Server, StatusCode};
-use query_core::schema::QuerySchemaRenderer;
+use prometheus::{Encoder, TextEncoder};
+use query_core::{response_ir::Responses, schema::QuerySchemaRenderer, Item};
 use serde_json::json;
 use std::net::SocketAddr;
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Number of records read from the database at once when streaming a response.
+const STREAM_CHUNK_SIZE: usize = 1000;
 
 #[derive(RustEmbed)]
 #[folder = "query-engine/prisma/static_files"]
@@ -110,6 +122,7 @@ impl HttpServer {
 
                 match serde_json::from_slice(bytes.as_ref()) {
                     Ok(body) => {
+                        let stream = Self::accepts_ndjson(&parts.headers);
                         let req = PrismaRequest {
                             body,
                             path: parts.uri.path().into(),
@@ -120,18 +133,38 @@ impl HttpServer {
                                 .collect(),
                         };
 
-                        Self::http_handler(req, ctx).await
-                    }
-                    Err(_) => {
-                        let mut bad_request = Response::default();
-                        *bad_request.status_mut() = StatusCode::BAD_REQUEST;
-                        bad_request
+                        if stream && req.headers.contains_key(TRANSACTION_ID_HEADER) {
+                            Self::stream_in_transaction_error()
+                        } else if stream {
+                            Self::stream_handler(req, ctx)
+                        } else {
+                            Self::http_handler(req, ctx).await
+                        }
                     }
+                    Err(_) => Self::bad_request(),
+                }
+            }
+
+            (&Method::POST, "/explain") => {
+                let explain = Self::requests_query_plans(req.uri().query());
+                let bytes = hyper::body::to_bytes(req.into_body()).await?;
+
+                match serde_json::from_slice(bytes.as_ref()) {
+                    Ok(body) => Self::explain_handler(body, explain, ctx).await,
+                    Err(_) => Self::bad_request(),
                 }
             }
 
+            (&Method::POST, path) if path.starts_with("/transaction/") => {
+                let path = path.to_owned();
+                let bytes = hyper::body::to_bytes(req.into_body()).await?;
+
+                Self::transaction_handler(&path, bytes.as_ref(), ctx).await
+            }
+
             (&Method::GET, "/") => Self::playground_handler(),
             (&Method::GET, "/status") => Self::status_handler(),
+            (&Method::GET, "/metrics") => Self::metrics_handler(),
 
             (&Method::GET, "/sdl") => Self::sdl_handler(ctx),
             (&Method::GET, "/dmmf") => Self::dmmf_handler(ctx),
@@ -161,6 +194,158 @@ impl HttpServer {
             .unwrap()
     }
 
+    /// Streams the records of a single `findMany` query as newline delimited JSON, one record per line.
+    /// Records are written while they are read from the database, so large results are never held in memory.
+    /// An error ends the stream with a last line of the form `{"errors":[...]}`.
+    fn stream_handler(req: PrismaRequest<GraphQlBody>, cx: Arc<RequestContext>) -> Response<Body> {
+        let (sender, receiver) = mpsc::channel(STREAM_CHUNK_SIZE);
+        let mut error_sender = sender.clone();
+
+        tokio::spawn(async move {
+            let result = stream_graphql_query(req.body, cx.context(), STREAM_CHUNK_SIZE, sender).await;
+
+            if let Err(err) = result {
+                let mut responses = Responses::default();
+                responses.insert_error(err);
+
+                let error = Item::Json(serde_json::to_value(&responses).unwrap());
+                let _ = error_sender.send(error).await;
+            }
+        });
+
+        let lines = receiver.map(|item| {
+            let mut line = serde_json::to_vec(&item).unwrap();
+            line.push(b'\n');
+
+            Ok::<_, Error>(line)
+        });
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/x-ndjson")
+            .body(Body::wrap_stream(lines))
+            .unwrap()
+    }
+
+    /// Streams read on a connection of their own, so within a transaction they would silently miss its
+    /// uncommitted writes. Streamed requests carrying a transaction id are rejected instead.
+    fn stream_in_transaction_error() -> Response<Body> {
+        let mut responses = Responses::default();
+        responses.insert_error(PrismaError::TransactionError(
+            "Streamed queries can't run in a transaction. Send the query without `Accept: application/x-ndjson` instead."
+                .to_owned(),
+        ));
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&responses).unwrap()))
+            .unwrap()
+    }
+
+    /// Dry-runs a single query: Returns the query graph, the SQL statements the connector ran and the response,
+    /// after rolling back all changes. `POST /explain?explain=true` includes the query plans of the statements.
+    async fn explain_handler(body: GraphQlBody, explain: bool, cx: Arc<RequestContext>) -> Response<Body> {
+        let json = match explain_graphql_query(body, cx.context(), explain).await {
+            Ok(explanation) => serde_json::to_value(&explanation).unwrap(),
+            Err(err) => {
+                let mut responses = Responses::default();
+                responses.insert_error(err);
+
+                serde_json::to_value(&responses).unwrap()
+            }
+        };
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&json).unwrap()))
+            .unwrap()
+    }
+
+    /// Query plans are requested with the `explain` query parameter.
+    fn requests_query_plans(query: Option<&str>) -> bool {
+        query
+            .map(|query| {
+                query
+                    .split('&')
+                    .any(|param| param == "explain" || param == "explain=true")
+            })
+            .unwrap_or(false)
+    }
+
+    /// Controls interactive transactions:
+    /// - `POST /transaction/start` with an optional body `{"timeout": <ms>}` opens a transaction and returns its id.
+    /// - `POST /transaction/<id>/commit` and `POST /transaction/<id>/rollback` close it.
+    ///
+    /// Queries run within an open transaction by sending its id in the `x-transaction-id` header.
+    async fn transaction_handler(path: &str, body: &[u8], cx: Arc<RequestContext>) -> Response<Body> {
+        let segments: Vec<&str> = path.trim_start_matches("/transaction/").split('/').collect();
+        let transactions = cx.context().transactions();
+
+        let result = match segments.as_slice() {
+            ["start"] => match Self::transaction_timeout(body) {
+                Some(timeout) => TransactionRegistry::start(cx.context(), timeout)
+                    .await
+                    .map(|id| json!({ "id": id })),
+                None => return Self::bad_request(),
+            },
+            [id, "commit"] => transactions.commit(id).await.map(|_| json!({})),
+            [id, "rollback"] => transactions.rollback(id).await.map(|_| json!({})),
+            _ => {
+                let mut not_found = Response::default();
+                *not_found.status_mut() = StatusCode::NOT_FOUND;
+                return not_found;
+            }
+        };
+
+        let json = result.unwrap_or_else(|err| {
+            let mut responses = Responses::default();
+            responses.insert_error(err);
+
+            serde_json::to_value(&responses).unwrap()
+        });
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&json).unwrap()))
+            .unwrap()
+    }
+
+    /// The timeout of a started transaction, `None` if the body is invalid or the timeout is zero or
+    /// longer than `TransactionRegistry::MAX_TIMEOUT`.
+    fn transaction_timeout(body: &[u8]) -> Option<Duration> {
+        if body.is_empty() {
+            return Some(TransactionRegistry::DEFAULT_TIMEOUT);
+        }
+
+        let options: serde_json::Value = serde_json::from_slice(body).ok()?;
+
+        match options.get("timeout") {
+            Some(timeout) => timeout
+                .as_u64()
+                .map(Duration::from_millis)
+                .filter(|timeout| *timeout > Duration::from_millis(0) && *timeout <= TransactionRegistry::MAX_TIMEOUT),
+            None => Some(TransactionRegistry::DEFAULT_TIMEOUT),
+        }
+    }
+
+    fn bad_request() -> Response<Body> {
+        let mut bad_request = Response::default();
+        *bad_request.status_mut() = StatusCode::BAD_REQUEST;
+        bad_request
+    }
+
+    /// Clients request a streamed response with `Accept: application/x-ndjson`.
+    fn accepts_ndjson(headers: &HeaderMap) -> bool {
+        headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.contains("application/x-ndjson"))
+            .unwrap_or(false)
+    }
+
     fn status_handler() -> Response<Body> {
         let body_data = json!({"status": "ok"});
         let bytes = serde_json::to_vec(&body_data).unwrap();
@@ -172,6 +357,20 @@ impl HttpServer {
             .unwrap()
     }
 
+    /// Renders the metrics of the executor and the connector in the Prometheus text format.
+    fn metrics_handler() -> Response<Body> {
+        let encoder = TextEncoder::new();
+        let mut buffer = Vec::new();
+
+        encoder.encode(&prometheus::gather(), &mut buffer).unwrap();
+
+        Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, encoder.format_type())
+            .body(Body::from(buffer))
+            .unwrap()
+    }
+
     fn playground_handler() -> Response<Body> {
         let index_html = StaticFiles::get("playground.html").unwrap();
 
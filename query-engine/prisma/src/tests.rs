@@ -1,3 +1,8 @@
 mod dmmf;
 mod execute_raw;
+mod explain;
+mod interactive_transactions;
+mod metrics;
+mod stream;
 mod test_api;
+mod variables_and_fragments;
@@ -96,6 +96,28 @@ fn must_not_fail_on_missing_env_vars_in_a_datasource() {
         .expect("finding BlogCreateInput");
 }
 
+#[test]
+#[serial]
+#[should_panic(expected = "The orderBy value `author__name` of model `Book` is ambiguous")]
+fn order_by_values_colliding_with_a_field_are_rejected() {
+    let dm = r#"
+        model Author {
+            id    String @id
+            name  String
+            books Book[]
+        }
+
+        model Book {
+            id           String @id
+            author__name String
+            authorId     String
+            author       Author @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    get_query_schema(dm);
+}
+
 fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
     // this env var is read by the data_model_loader
     std::env::set_var("PRISMA_DML", datamodel_string);
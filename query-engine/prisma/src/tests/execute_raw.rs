@@ -13,11 +13,20 @@ static TODO: &str = indoc! {"
 "};
 
 fn execute_raw(query: &str, params: Vec<ParameterizedValue>) -> String {
+    raw_mutation("executeRaw", query, params)
+}
+
+fn query_raw(query: &str, params: Vec<ParameterizedValue>) -> String {
+    raw_mutation("queryRaw", query, params)
+}
+
+fn raw_mutation(name: &str, query: &str, params: Vec<ParameterizedValue>) -> String {
     let params: Vec<serde_json::Value> = params.into_iter().map(serde_json::Value::from).collect();
     let params = serde_json::to_string(&params).unwrap();
 
     format!(
-        r#"mutation {{ executeRaw(query: "{}", parameters: "{}") }}"#,
+        r#"mutation {{ {}(query: "{}", parameters: "{}") }}"#,
+        name,
         query.replace("\"", "\\\""),
         params.replace("\"", "\\\"")
     )
@@ -29,7 +38,7 @@ async fn select_1(api: &TestApi) -> anyhow::Result<()> {
 
     let query = indoc! {r#"
         mutation {
-            executeRaw(
+            queryRaw(
                 query: "SELECT 1"
             )
         }
@@ -43,7 +52,7 @@ async fn select_1(api: &TestApi) -> anyhow::Result<()> {
     assert_eq!(
         json!({
             "data": {
-                "executeRaw": [{column_name: 1}]
+                "queryRaw": [{column_name: 1}]
             }
         }),
         query_engine.request(query).await
@@ -60,7 +69,7 @@ async fn parameterized_queries(api: &TestApi) -> anyhow::Result<()> {
         ConnectionInfo::Postgres(_) => {
             indoc! {r#"
                 mutation {
-                    executeRaw(
+                    queryRaw(
                         query: "SELECT ($1)::text",
                         parameters: "[\"foo\"]"
                     )
@@ -70,7 +79,7 @@ async fn parameterized_queries(api: &TestApi) -> anyhow::Result<()> {
         _ => {
             indoc! {r#"
                 mutation {
-                    executeRaw(
+                    queryRaw(
                         query: "SELECT ?",
                         parameters: "[\"foo\"]"
                     )
@@ -87,7 +96,7 @@ async fn parameterized_queries(api: &TestApi) -> anyhow::Result<()> {
     assert_eq!(
         json!({
             "data": {
-                "executeRaw": [{column_name: "foo"}]
+                "queryRaw": [{column_name: "foo"}]
             }
         }),
         query_engine.request(query).await
@@ -114,12 +123,12 @@ async fn querying_model_tables(api: &TestApi) -> anyhow::Result<()> {
     assert_eq!(
         json!({
             "data": {
-                "executeRaw": [
+                "queryRaw": [
                     {"id": id, "title": "title1"}
                 ]
             }
         }),
-        query_engine.request(execute_raw(&query, vec![])).await
+        query_engine.request(query_raw(&query, vec![])).await
     );
 
     Ok(())
@@ -149,13 +158,13 @@ async fn inserting_into_model_table(api: &TestApi) -> anyhow::Result<()> {
     assert_eq!(
         json!({
             "data": {
-                "executeRaw": [
+                "queryRaw": [
                     {"id": "id1", "title": "title1"},
                     {"id": "id2", "title": "title2"}
                 ]
             }
         }),
-        query_engine.request(execute_raw(&query, vec![])).await
+        query_engine.request(query_raw(&query, vec![])).await
     );
 
     Ok(())
@@ -179,10 +188,10 @@ async fn querying_model_tables_with_alias(api: &TestApi) -> anyhow::Result<()> {
     assert_eq!(
         json!({
             "data": {
-                "executeRaw": [{"aliasedTitle": "title1"}]
+                "queryRaw": [{"aliasedTitle": "title1"}]
             }
         }),
-        query_engine.request(execute_raw(&query, params)).await,
+        query_engine.request(query_raw(&query, params)).await,
     );
 
     Ok(())
@@ -209,10 +218,10 @@ async fn querying_the_same_column_name_twice_with_aliasing(api: &TestApi) -> any
     assert_eq!(
         json!({
             "data": {
-                "executeRaw": [{"ALIASEDTITLE": "title1", "title": "title1"}]
+                "queryRaw": [{"ALIASEDTITLE": "title1", "title": "title1"}]
             }
         }),
-        query_engine.request(execute_raw(&query, params)).await,
+        query_engine.request(query_raw(&query, params)).await,
     );
 
     Ok(())
@@ -223,8 +232,8 @@ async fn arrays(api: &TestApi) -> anyhow::Result<()> {
     let query_engine = api.create_engine(&TODO).await?;
 
     let query = "SELECT ARRAY_AGG(columnInfos.attname) AS postgres_array FROM pg_attribute columnInfos";
-    let result = query_engine.request(execute_raw(query, vec![])).await;
-    let array = result["data"]["executeRaw"][0]["postgres_array"].as_array().unwrap();
+    let result = query_engine.request(query_raw(query, vec![])).await;
+    let array = result["data"]["queryRaw"][0]["postgres_array"].as_array().unwrap();
 
     for val in array.into_iter() {
         assert!(val.is_string());
@@ -233,6 +242,97 @@ async fn arrays(api: &TestApi) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_each_connector]
+async fn execute_raw_returns_the_number_of_affected_rows(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let insert = Insert::multi_into("Todo", vec!["id", "title"])
+        .values(vec!["id1", "title1"])
+        .values(vec!["id2", "title2"])
+        .values(vec!["id3", "title3"]);
+
+    let (query, params) = api.to_sql_string(insert);
+    query_engine.request(execute_raw(&query, params)).await;
+
+    let update = Update::table("Todo")
+        .set("title", "updated")
+        .so_that(Column::from("id").in_selection(vec!["id1", "id2"]));
+
+    let (query, params) = api.to_sql_string(update);
+
+    assert_eq!(
+        json!({
+            "data": {
+                "executeRaw": 2
+            }
+        }),
+        query_engine.request(execute_raw(&query, params)).await,
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn querying_with_common_table_expressions(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": [{"num": 1}]
+            }
+        }),
+        query_engine
+            .request(query_raw("WITH cte AS (SELECT 1 AS num) SELECT num FROM cte", vec![]))
+            .await,
+    );
+
+    Ok(())
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn executing_statements_with_common_table_expressions(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        WITH new_todos AS (SELECT 'id1' AS id, 'title1' AS title UNION ALL SELECT 'id2', 'title2')
+        INSERT INTO "Todo" (id, title) SELECT id, title FROM new_todos
+    "#};
+
+    assert_eq!(
+        json!({
+            "data": {
+                "executeRaw": 2
+            }
+        }),
+        query_engine.request(execute_raw(query.trim(), vec![])).await,
+    );
+
+    Ok(())
+}
+
+#[test_one_connector(connector = "postgres")]
+async fn query_raw_tags_values_without_a_json_equivalent(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = "SELECT '2020-01-01T00:00:00Z'::timestamptz AS datetime, 1.5::numeric AS decimal, 'foo' AS text";
+
+    assert_eq!(
+        json!({
+            "data": {
+                "queryRaw": [{
+                    "datetime": {"prisma__type": "datetime", "prisma__value": "2020-01-01T00:00:00+00:00"},
+                    "decimal": {"prisma__type": "decimal", "prisma__value": "1.5"},
+                    "text": "foo"
+                }]
+            }
+        }),
+        query_engine.request(query_raw(query, vec![])).await,
+    );
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn syntactic_errors_bubbling_through_to_the_user(api: &TestApi) -> anyhow::Result<()> {
     let query_engine = api.create_engine(&TODO).await?;
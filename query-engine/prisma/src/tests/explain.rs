@@ -0,0 +1,89 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static SCHEMA: &str = indoc! {"
+    model User {
+        id    Int    @id
+        name  String
+        posts Post[]
+    }
+
+    model Post {
+        id     Int    @id
+        title  String
+        author User
+    }
+"};
+
+const CREATE_USER_WITH_POST: &str = indoc! {r#"
+    mutation {
+        createOneUser(data: { id: 1, name: "Ada", posts: { create: { id: 1, title: "First" } } }) { id }
+    }
+"#};
+
+#[test_each_connector]
+async fn explaining_a_mutation_rolls_it_back(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCHEMA).await?;
+    let explanation = query_engine.explain(CREATE_USER_WITH_POST, false).await?;
+
+    assert_eq!(json!({"data": {"createOneUser": {"id": 1}}}), explanation["response"]);
+
+    assert_eq!(
+        json!({"data": {"findManyUser": []}}),
+        query_engine.request("{ findManyUser { id } }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn explaining_a_mutation_returns_the_graph_and_statements(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCHEMA).await?;
+    let explanation = query_engine.explain(CREATE_USER_WITH_POST, false).await?;
+
+    assert!(explanation["queryGraph"].as_str().unwrap().contains("Query Graph"));
+
+    let statements = explanation["statements"].as_array().unwrap();
+    let inserts: Vec<&str> = statements
+        .iter()
+        .map(|statement| statement["sql"].as_str().unwrap())
+        .filter(|sql| sql.starts_with("INSERT"))
+        .collect();
+
+    assert_eq!(2, inserts.len());
+    assert!(statements.iter().all(|statement| statement.get("plan").is_none()));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn explaining_a_query_can_include_the_query_plans(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCHEMA).await?;
+    let explanation = query_engine
+        .explain("{ findManyUser { id posts { id } } }", true)
+        .await?;
+
+    let statements = explanation["statements"].as_array().unwrap();
+
+    assert!(!statements.is_empty());
+    assert!(statements.iter().all(|statement| statement["plan"].is_array()));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn explaining_a_failing_mutation_returns_the_error(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&SCHEMA).await?;
+    query_engine.request(CREATE_USER_WITH_POST).await;
+
+    let explanation = query_engine.explain(CREATE_USER_WITH_POST, false).await?;
+
+    assert_eq!(
+        Some("P2002"),
+        explanation["response"]["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}
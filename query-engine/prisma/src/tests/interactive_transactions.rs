@@ -0,0 +1,106 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use std::time::Duration;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+const CREATE_TODO: &str = r#"mutation { createOneTodo(data: { id: 1, title: "title" }) { id } }"#;
+const FIND_TODOS: &str = "{ findManyTodo { id } }";
+
+#[test_each_connector]
+async fn committed_transactions_persist_their_writes(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(Duration::from_secs(5)).await?;
+
+    assert_eq!(
+        json!({"data": {"createOneTodo": {"id": 1}}}),
+        query_engine.request_in_transaction(&tx_id, CREATE_TODO).await
+    );
+
+    assert_eq!(
+        json!({"data": {"findManyTodo": [{"id": 1}]}}),
+        query_engine.request_in_transaction(&tx_id, FIND_TODOS).await
+    );
+
+    query_engine.commit_transaction(&tx_id).await?;
+
+    assert_eq!(
+        json!({"data": {"findManyTodo": [{"id": 1}]}}),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rolled_back_transactions_discard_their_writes(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(Duration::from_secs(5)).await?;
+
+    query_engine.request_in_transaction(&tx_id, CREATE_TODO).await;
+    query_engine.rollback_transaction(&tx_id).await?;
+
+    assert_eq!(
+        json!({"data": {"findManyTodo": []}}),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn closed_transactions_can_not_be_used(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(Duration::from_secs(5)).await?;
+
+    query_engine.commit_transaction(&tx_id).await?;
+
+    let response = query_engine.request_in_transaction(&tx_id, FIND_TODOS).await;
+    assert!(response["errors"][0]["error"].as_str().unwrap().contains("not found"));
+    assert!(query_engine.rollback_transaction(&tx_id).await.is_err());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn transactions_are_rolled_back_after_the_timeout(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let tx_id = query_engine.start_transaction(Duration::from_millis(100)).await?;
+
+    query_engine.request_in_transaction(&tx_id, CREATE_TODO).await;
+    tokio::time::delay_for(Duration::from_millis(300)).await;
+
+    let response = query_engine.request_in_transaction(&tx_id, FIND_TODOS).await;
+    assert!(response["errors"][0]["error"].as_str().unwrap().contains("not found"));
+
+    assert_eq!(
+        json!({"data": {"findManyTodo": []}}),
+        query_engine.request(FIND_TODOS).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn the_number_of_open_transactions_is_limited(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    for _ in 0..query_engine.max_open_transactions() {
+        query_engine.start_transaction(Duration::from_secs(5)).await?;
+    }
+
+    let error = query_engine
+        .start_transaction(Duration::from_secs(5))
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("Too many open transactions"));
+
+    Ok(())
+}
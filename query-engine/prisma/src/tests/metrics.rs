@@ -0,0 +1,47 @@
+use super::test_api::*;
+use indoc::indoc;
+use prometheus::{Encoder, TextEncoder};
+use test_macros::*;
+
+static METRICS_CAT: &str = indoc! {"
+    model MetricsCat {
+        id   Int    @id
+        name String @unique
+    }
+"};
+
+/// The metrics are global to the process, so the assertions only check for the presence of series.
+fn rendered_metrics() -> String {
+    let mut buffer = Vec::new();
+    TextEncoder::new().encode(&prometheus::gather(), &mut buffer).unwrap();
+
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test_each_connector]
+async fn requests_are_recorded_per_operation_and_model(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&METRICS_CAT).await?;
+
+    query_engine.request("{ findManyMetricsCat { id } }").await;
+
+    let metrics = rendered_metrics();
+
+    assert!(metrics.contains(r#"prisma_requests_total{model="MetricsCat",operation="findMany"}"#));
+    assert!(metrics.contains(r#"prisma_request_duration_seconds_count{model="MetricsCat",operation="findMany"}"#));
+    assert!(metrics.contains("prisma_pool_connections_busy"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn errors_are_recorded_by_error_code(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&METRICS_CAT).await?;
+    let mutation = r#"mutation { createOneMetricsCat(data: { id: 1, name: "Garfield" }) { id } }"#;
+
+    query_engine.request(mutation).await;
+    query_engine.request(mutation).await;
+
+    assert!(rendered_metrics().contains(r#"prisma_errors_total{code="P2002"}"#));
+
+    Ok(())
+}
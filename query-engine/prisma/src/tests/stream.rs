@@ -0,0 +1,69 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int    @id
+        title String
+    }
+"};
+
+async fn create_todos(query_engine: &QueryEngine, count: usize) {
+    for id in 1..=count {
+        let mutation = format!(
+            r#"mutation {{ createOneTodo(data: {{ id: {}, title: "title{}" }}) {{ id }} }}"#,
+            id, id
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn streaming_returns_all_records_across_chunks(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    create_todos(&query_engine, 5).await;
+
+    let records = query_engine
+        .stream("{ findManyTodo(orderBy: title_DESC) { id title } }", 2)
+        .await?;
+
+    assert_eq!(
+        vec![
+            json!({"id": 5, "title": "title5"}),
+            json!({"id": 4, "title": "title4"}),
+            json!({"id": 3, "title": "title3"}),
+            json!({"id": 2, "title": "title2"}),
+            json!({"id": 1, "title": "title1"}),
+        ],
+        records
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streaming_applies_first_and_skip_to_the_whole_stream(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    create_todos(&query_engine, 6).await;
+
+    let records = query_engine
+        .stream("{ findManyTodo(where: { id_gt: 1 }, skip: 1, first: 3) { id } }", 2)
+        .await?;
+
+    assert_eq!(vec![json!({"id": 3}), json!({"id": 4}), json!({"id": 5})], records);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn streaming_is_only_supported_for_find_many(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine.stream("{ findOneTodo(where: { id: 1 }) { id } }", 2).await;
+    assert!(result.is_err());
+
+    Ok(())
+}
@@ -1,8 +1,13 @@
 use crate::{
     context::PrismaContext,
-    request_handlers::{GraphQlBody, GraphQlRequestHandler, RequestHandler, SingleQuery},
-    PrismaRequest, PrismaResponse,
+    request_handlers::{
+        explain_graphql_query, stream_graphql_query, GraphQlBody, GraphQlRequestHandler, RequestHandler, SingleQuery,
+        TRANSACTION_ID_HEADER,
+    },
+    transactions::TransactionRegistry,
+    PrismaRequest, PrismaResponse, PrismaResult,
 };
+use futures::{channel::mpsc, StreamExt};
 use migration_connector::*;
 use migration_core::{
     api::{GenericApi, MigrationApi},
@@ -13,8 +18,9 @@ use quaint::{
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use serde_json::json;
 use sql_migration_connector::SqlMigrationConnector;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use test_setup::*;
 
 pub struct QueryEngine {
@@ -23,9 +29,46 @@ pub struct QueryEngine {
 
 impl QueryEngine {
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_headers(body, HashMap::new()).await
+    }
+
+    pub async fn request_with_variables(&self, query: &str, variables: serde_json::Value) -> serde_json::Value {
+        let body: SingleQuery = serde_json::from_value(json!({ "query": query, "variables": variables })).unwrap();
+        self.request(body).await
+    }
+
+    /// Runs the request within the open interactive transaction.
+    pub async fn request_in_transaction(&self, tx_id: &str, body: impl Into<SingleQuery>) -> serde_json::Value {
+        let mut headers = HashMap::new();
+        headers.insert(TRANSACTION_ID_HEADER.to_owned(), tx_id.to_owned());
+
+        self.request_with_headers(body, headers).await
+    }
+
+    pub async fn start_transaction(&self, timeout: Duration) -> PrismaResult<String> {
+        TransactionRegistry::start(&self.context, timeout).await
+    }
+
+    pub fn max_open_transactions(&self) -> usize {
+        self.context.transactions().max_open()
+    }
+
+    pub async fn commit_transaction(&self, tx_id: &str) -> PrismaResult<()> {
+        self.context.transactions().commit(tx_id).await
+    }
+
+    pub async fn rollback_transaction(&self, tx_id: &str) -> PrismaResult<()> {
+        self.context.transactions().rollback(tx_id).await
+    }
+
+    async fn request_with_headers(
+        &self,
+        body: impl Into<SingleQuery>,
+        headers: HashMap<String, String>,
+    ) -> serde_json::Value {
         let request = PrismaRequest {
             body: GraphQlBody::Single(body.into()),
-            headers: HashMap::new(),
+            headers,
             path: String::new(),
         };
 
@@ -34,6 +77,36 @@ impl QueryEngine {
             _ => unreachable!(),
         }
     }
+
+    /// Streams the records of a `findMany` query, reading `chunk_size` records at once.
+    pub async fn stream(
+        &self,
+        body: impl Into<SingleQuery>,
+        chunk_size: usize,
+    ) -> PrismaResult<Vec<serde_json::Value>> {
+        let (sender, receiver) = mpsc::channel(chunk_size);
+        let body = GraphQlBody::Single(body.into());
+
+        let (result, records) = futures::join!(
+            stream_graphql_query(body, &self.context, chunk_size, sender),
+            receiver.collect::<Vec<_>>()
+        );
+
+        result?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| serde_json::to_value(record).unwrap())
+            .collect())
+    }
+
+    /// Dry-runs the query, returning the serialized explanation.
+    pub async fn explain(&self, body: impl Into<SingleQuery>, explain: bool) -> PrismaResult<serde_json::Value> {
+        let body = GraphQlBody::Single(body.into());
+        let explanation = explain_graphql_query(body, &self.context, explain).await?;
+
+        Ok(serde_json::to_value(explanation).unwrap())
+    }
 }
 
 pub struct TestApi {
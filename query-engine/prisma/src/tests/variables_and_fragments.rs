@@ -0,0 +1,126 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id    Int     @id
+        title String
+        done  Boolean
+    }
+"};
+
+async fn create_todos(query_engine: &QueryEngine) {
+    for (id, title, done) in &[(1, "a", true), (2, "b", false), (3, "c", false)] {
+        let mutation = format!(
+            r#"mutation {{ createOneTodo(data: {{ id: {}, title: "{}", done: {} }}) {{ id }} }}"#,
+            id, title, done
+        );
+
+        query_engine.request(mutation).await;
+    }
+}
+
+#[test_each_connector]
+async fn variables_are_substituted(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    create_todos(&query_engine).await;
+
+    let query = indoc! {"
+        query Todos($where: TodoWhereInput, $first: Int!, $orderBy: TodoOrderByInput = id_DESC) {
+            findManyTodo(where: $where, first: $first, orderBy: $orderBy) { id }
+        }
+    "};
+
+    let response = query_engine
+        .request_with_variables(query, json!({ "where": { "done": false }, "first": 1 }))
+        .await;
+
+    assert_eq!(json!({"data": {"findManyTodo": [{"id": 3}]}}), response);
+
+    // Variables without a value leave out their argument.
+    let response = query_engine
+        .request_with_variables(query, json!({ "first": 2, "orderBy": "id_ASC" }))
+        .await;
+
+    assert_eq!(json!({"data": {"findManyTodo": [{"id": 1}, {"id": 2}]}}), response);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn variables_are_validated(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let query = "query Todos($first: Int!) { findManyTodo(first: $first) { id } }";
+
+    let missing = query_engine.request_with_variables(query, json!({})).await;
+    let invalid = query_engine
+        .request_with_variables(query, json!({ "first": "one" }))
+        .await;
+    let undefined = query_engine
+        .request_with_variables("{ findManyTodo(first: $first) { id } }", json!({ "first": 1 }))
+        .await;
+
+    assert!(missing["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("was not provided"));
+    assert!(invalid["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("got an invalid value"));
+    assert!(undefined["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("is not defined"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn fragments_are_expanded(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    create_todos(&query_engine).await;
+
+    let query = indoc! {"
+        query {
+            findOneTodo(where: { id: 1 }) {
+                id
+                ...TodoFields
+                ... on Todo { done }
+            }
+        }
+
+        fragment TodoFields on Todo {
+            id
+            title
+        }
+    "};
+
+    assert_eq!(
+        json!({"data": {"findOneTodo": {"id": 1, "title": "a", "done": true}}}),
+        query_engine.request(query).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cyclic_fragments_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {"
+        query { findManyTodo { ...A } }
+        fragment A on Todo { id ...B }
+        fragment B on Todo { title ...A }
+    "};
+
+    let response = query_engine.request(query).await;
+    assert!(response["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("spreads itself"));
+
+    Ok(())
+}
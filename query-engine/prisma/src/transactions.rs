@@ -0,0 +1,189 @@
+use crate::{context::PrismaContext, PrismaError, PrismaResult};
+use datamodel::Source;
+use futures::{
+    channel::{mpsc, oneshot},
+    SinkExt,
+};
+use query_core::{response_ir::Responses, Operation, TransactionMessage};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use url::Url;
+use uuid::Uuid;
+
+/// Interactive transactions spanning several requests.
+///
+/// Every open transaction is run by its own task (see `QueryExecutor::run_transaction`), which owns the connection
+/// and the `Transaction` for as long as the transaction is open. Requests address a transaction by its id, which maps
+/// to the channel of that task.
+///
+/// Transactions time out: The channel is dropped from the registry when the timeout expires, which makes the task
+/// roll back the transaction once no request is using it anymore.
+///
+/// Every open transaction holds a connection of the pool, so only `max_open` transactions can be open at once,
+/// leaving connections to the requests outside of transactions.
+pub struct TransactionRegistry {
+    transactions: Mutex<HashMap<String, mpsc::Sender<TransactionMessage>>>,
+    max_open: usize,
+}
+
+impl Default for TransactionRegistry {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_OPEN)
+    }
+}
+
+impl TransactionRegistry {
+    /// Default time after which an open transaction is rolled back.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Longest timeout a transaction can be started with.
+    pub const MAX_TIMEOUT: Duration = Duration::from_secs(60);
+
+    /// Open transactions allowed if the data source doesn't set a `connection_limit`.
+    /// Keeps a connection of the smallest default pool (three connections) free.
+    pub const DEFAULT_MAX_OPEN: usize = 2;
+
+    pub fn new(max_open: usize) -> Self {
+        Self {
+            transactions: Mutex::new(HashMap::new()),
+            max_open,
+        }
+    }
+
+    /// A registry allowing one transaction less than the `connection_limit` of the data source.
+    pub fn for_source(source: &(dyn Source + Send + Sync)) -> Self {
+        let connection_limit = Url::parse(&source.url().value).ok().and_then(|url| {
+            url.query_pairs()
+                .find(|(key, _)| key == "connection_limit")
+                .and_then(|(_, value)| value.parse::<usize>().ok())
+        });
+
+        match connection_limit {
+            Some(limit) => Self::new(std::cmp::max(1, limit.saturating_sub(1))),
+            None => Self::default(),
+        }
+    }
+
+    /// The number of transactions that can be open at the same time.
+    pub fn max_open(&self) -> usize {
+        self.max_open
+    }
+
+    /// Starts a new transaction and returns its id.
+    pub async fn start(ctx: &Arc<PrismaContext>, timeout: Duration) -> PrismaResult<String> {
+        let id = Uuid::new_v4().to_string();
+        let (sender, receiver) = mpsc::channel(1);
+        let (started_sender, started) = oneshot::channel();
+
+        // The transaction takes its place in the registry before it acquires a connection,
+        // so concurrent starts can't exceed the limit.
+        ctx.transactions().insert(id.clone(), sender)?;
+
+        let tx_ctx = Arc::clone(ctx);
+        tokio::spawn(async move {
+            let query_schema = Arc::clone(tx_ctx.query_schema());
+            tx_ctx
+                .executor
+                .run_transaction(query_schema, started_sender, receiver)
+                .await;
+        });
+
+        let started: PrismaResult<()> = match started.await {
+            Ok(result) => result.map_err(PrismaError::from),
+            Err(_) => Err(Self::closed(&id)),
+        };
+
+        if let Err(err) = started {
+            ctx.transactions().remove(&id);
+            return Err(err);
+        }
+
+        let timeout_ctx = Arc::clone(ctx);
+        let timeout_id = id.clone();
+
+        tokio::spawn(async move {
+            tokio::time::delay_for(timeout).await;
+
+            if timeout_ctx.transactions().remove(&timeout_id).is_some() {
+                debug!("Transaction {} timed out.", timeout_id);
+            }
+        });
+
+        Ok(id)
+    }
+
+    /// Executes the operation within the transaction.
+    pub async fn execute(&self, id: &str, operation: Operation) -> PrismaResult<Responses> {
+        let (reply, response) = oneshot::channel();
+        let mut sender = self.sender(id)?;
+
+        sender
+            .send(TransactionMessage::Execute { operation, reply })
+            .await
+            .map_err(|_| Self::closed(id))?;
+
+        Ok(response.await.map_err(|_| Self::closed(id))??)
+    }
+
+    /// Commits and closes the transaction.
+    pub async fn commit(&self, id: &str) -> PrismaResult<()> {
+        self.close(id, TransactionMessage::Commit).await
+    }
+
+    /// Rolls back and closes the transaction.
+    pub async fn rollback(&self, id: &str) -> PrismaResult<()> {
+        self.close(id, TransactionMessage::Rollback).await
+    }
+
+    async fn close<F>(&self, id: &str, message: F) -> PrismaResult<()>
+    where
+        F: FnOnce(oneshot::Sender<query_core::Result<()>>) -> TransactionMessage,
+    {
+        let mut sender = self.remove(id).ok_or_else(|| Self::not_found(id))?;
+        let (reply, response) = oneshot::channel();
+
+        sender.send(message(reply)).await.map_err(|_| Self::closed(id))?;
+
+        Ok(response.await.map_err(|_| Self::closed(id))??)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, mpsc::Sender<TransactionMessage>>> {
+        self.transactions.lock().unwrap()
+    }
+
+    fn sender(&self, id: &str) -> PrismaResult<mpsc::Sender<TransactionMessage>> {
+        self.lock().get(id).cloned().ok_or_else(|| Self::not_found(id))
+    }
+
+    fn insert(&self, id: String, sender: mpsc::Sender<TransactionMessage>) -> PrismaResult<()> {
+        let mut transactions = self.lock();
+
+        if transactions.len() >= self.max_open {
+            return Err(PrismaError::TransactionError(format!(
+                "Too many open transactions, at most {} can be open at the same time.",
+                self.max_open
+            )));
+        }
+
+        transactions.insert(id, sender);
+        Ok(())
+    }
+
+    fn remove(&self, id: &str) -> Option<mpsc::Sender<TransactionMessage>> {
+        self.lock().remove(id)
+    }
+
+    fn not_found(id: &str) -> PrismaError {
+        PrismaError::TransactionError(format!(
+            "Transaction '{}' not found. It was either closed or timed out.",
+            id
+        ))
+    }
+
+    fn closed(id: &str) -> PrismaError {
+        PrismaError::TransactionError(format!("Transaction '{}' is already closed.", id))
+    }
+}